@@ -1,5 +1,5 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use kernels::ops::{cosine_similarity_float_kernel, dot_product1_kernel};
+use kernels::ops::{cosine_similarity_float_kernel, dot_product1_kernel, matmul_kernel};
 use rand::Rng;
 
 fn bench_product1_float_kernel(c: &mut Criterion) {
@@ -47,9 +47,30 @@ fn bench_cosine_similarity_kernel(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_matmul_kernel(c: &mut Criterion) {
+    let mut group = c.benchmark_group("matmul_kernel");
+    let mut rng = rand::rng();
+
+    for size in [16, 64, 256, 512] {
+        let a: Vec<f32> = (0..size * size).map(|_| rng.random::<f32>()).collect();
+        let b: Vec<f32> = (0..size * size).map(|_| rng.random::<f32>()).collect();
+        let mut out = vec![0.0f32; size * size];
+
+        group.bench_function(format!("f32_size_{}", size), |bencher| {
+            bencher.iter(|| {
+                matmul_kernel(&a, &b, &mut out, size, size, size).unwrap();
+                black_box(&out);
+            })
+        });
+    }
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_product1_float_kernel,
-    bench_cosine_similarity_kernel
+    bench_cosine_similarity_kernel,
+    bench_matmul_kernel
 );
 criterion_main!(benches);
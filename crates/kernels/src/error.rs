@@ -6,4 +6,8 @@ pub enum KernelError {
     /// Length mismatch for vector operations
     #[error("Length mismatch: expected equal length vectors, got {0} and {1}")]
     LengthMismatch(usize, usize),
+
+    /// The length of a matrix buffer does not match its shape
+    #[error("Invalid matrix buffer length: expected {0}, got {1}")]
+    InvalidMatrixLength(usize, usize),
 }
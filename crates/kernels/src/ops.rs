@@ -102,6 +102,84 @@ where
     Ok(dot_product / denominator)
 }
 
+/// The size of the square blocks processed by [`matmul_kernel`].
+///
+/// A block of 64x64 `f32` values takes 16KB, so the blocks of the three matrices fit in the
+/// L1/L2 caches of most CPUs.
+const MATMUL_BLOCK_SIZE: usize = 64;
+
+/// Computes the matrix product of two row-major matrices.
+///
+/// The product `c = a * b` is computed with a cache-blocked loop over tiles of the matrices,
+/// where the innermost loop runs over contiguous rows of `b` and `c` so that it can be
+/// auto-vectorized.
+///
+/// # Arguments
+///
+/// * `a` - The left matrix with shape `m x k`.
+/// * `b` - The right matrix with shape `k x n`.
+/// * `c` - The output matrix with shape `m x n`. Its previous content is overwritten.
+/// * `m` - The number of rows of `a` and `c`.
+/// * `k` - The number of columns of `a` and rows of `b`.
+/// * `n` - The number of columns of `b` and `c`.
+///
+/// # Errors
+///
+/// If the length of a slice does not match its shape, an `InvalidMatrixLength` error is returned.
+///
+/// Example:
+/// ```
+/// use kernels::ops::matmul_kernel;
+///
+/// let a = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+/// let b = [1.0, 0.0, 0.0, 1.0, 1.0, 1.0];
+/// let mut c = [0.0; 4];
+/// matmul_kernel(&a, &b, &mut c, 2, 3, 2).unwrap();
+/// assert_eq!(c, [4.0, 5.0, 10.0, 11.0]);
+/// ```
+pub fn matmul_kernel<T>(
+    a: &[T],
+    b: &[T],
+    c: &mut [T],
+    m: usize,
+    k: usize,
+    n: usize,
+) -> Result<(), KernelError>
+where
+    T: Zero + Copy + std::ops::Add<Output = T> + std::ops::Mul<Output = T>,
+{
+    for (buf_len, expected) in [(a.len(), m * k), (b.len(), k * n), (c.len(), m * n)] {
+        if buf_len != expected {
+            return Err(KernelError::InvalidMatrixLength(expected, buf_len));
+        }
+    }
+
+    c.iter_mut().for_each(|x| *x = T::zero());
+
+    for i0 in (0..m).step_by(MATMUL_BLOCK_SIZE) {
+        let i1 = (i0 + MATMUL_BLOCK_SIZE).min(m);
+        for p0 in (0..k).step_by(MATMUL_BLOCK_SIZE) {
+            let p1 = (p0 + MATMUL_BLOCK_SIZE).min(k);
+            for j0 in (0..n).step_by(MATMUL_BLOCK_SIZE) {
+                let j1 = (j0 + MATMUL_BLOCK_SIZE).min(n);
+                for i in i0..i1 {
+                    let c_row = &mut c[i * n + j0..i * n + j1];
+                    for p in p0..p1 {
+                        let a_ip = a[i * k + p];
+                        let b_row = &b[p * n + j0..p * n + j1];
+                        c_row
+                            .iter_mut()
+                            .zip(b_row.iter())
+                            .for_each(|(c_ij, &b_pj)| *c_ij = *c_ij + a_ip * b_pj);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -178,4 +256,33 @@ mod tests {
         let result = cosine_similarity_float_kernel(&a, &b);
         assert!(matches!(result, Err(KernelError::LengthMismatch(3, 2))));
     }
+
+    #[test]
+    fn test_matmul_kernel_blocks() {
+        // larger than a block in every dimension to exercise the tiling
+        let (m, k, n) = (70, 130, 65);
+        let a = (0..m * k).map(|x| (x % 7) as i64 - 3).collect::<Vec<_>>();
+        let b = (0..k * n).map(|x| (x % 5) as i64 - 2).collect::<Vec<_>>();
+        let mut c = vec![1; m * n];
+        matmul_kernel(&a, &b, &mut c, m, k, n).unwrap();
+
+        for i in 0..m {
+            for j in 0..n {
+                let expected = (0..k).map(|p| a[i * k + p] * b[p * n + j]).sum::<i64>();
+                assert_eq!(c[i * n + j], expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_matmul_kernel_length_mismatch() {
+        let a = [1.0f32; 6];
+        let b = [1.0f32; 6];
+        let mut c = [0.0f32; 4];
+        let result = matmul_kernel(&a, &b, &mut c, 2, 3, 3);
+        assert!(matches!(
+            result,
+            Err(KernelError::InvalidMatrixLength(9, 6))
+        ));
+    }
 }
@@ -0,0 +1,81 @@
+/// The color space of the pixel data of an image.
+///
+/// The color space is runtime metadata attached to an [`crate::Image`] which describes how
+/// its channels have to be interpreted. Images are created with [`ColorSpace::Unknown`], which
+/// is accepted by every operation, and can be tagged with [`crate::Image::with_color_space`].
+///
+/// # Examples
+///
+/// ```
+/// use kornia_image::{ColorSpace, Image, ImageSize};
+///
+/// let image = Image::<u8, 3>::from_size_val(ImageSize { width: 4, height: 2 }, 0)
+///     .unwrap()
+///     .with_color_space(ColorSpace::Bgr)
+///     .unwrap();
+///
+/// assert_eq!(image.color_space(), ColorSpace::Bgr);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ColorSpace {
+    /// The color space is not known.
+    #[default]
+    Unknown,
+    /// Single channel luminance.
+    Gray,
+    /// Red, green and blue channels.
+    Rgb,
+    /// Blue, green and red channels.
+    Bgr,
+    /// Red, green, blue and alpha channels.
+    Rgba,
+    /// Blue, green, red and alpha channels.
+    Bgra,
+    /// Hue, saturation and value channels.
+    Hsv,
+    /// CIE L*a*b* channels.
+    Lab,
+    /// Luma, blue-difference and red-difference chroma channels.
+    YCbCr,
+    /// CIE XYZ channels.
+    Xyz,
+}
+
+impl ColorSpace {
+    /// Get the number of channels of the color space.
+    ///
+    /// # Returns
+    ///
+    /// The number of channels or `None` if the color space is unknown.
+    pub fn num_channels(&self) -> Option<usize> {
+        match self {
+            ColorSpace::Unknown => None,
+            ColorSpace::Gray => Some(1),
+            ColorSpace::Rgb
+            | ColorSpace::Bgr
+            | ColorSpace::Hsv
+            | ColorSpace::Lab
+            | ColorSpace::YCbCr
+            | ColorSpace::Xyz => Some(3),
+            ColorSpace::Rgba | ColorSpace::Bgra => Some(4),
+        }
+    }
+}
+
+impl std::fmt::Display for ColorSpace {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let name = match self {
+            ColorSpace::Unknown => "Unknown",
+            ColorSpace::Gray => "Gray",
+            ColorSpace::Rgb => "RGB",
+            ColorSpace::Bgr => "BGR",
+            ColorSpace::Rgba => "RGBA",
+            ColorSpace::Bgra => "BGRA",
+            ColorSpace::Hsv => "HSV",
+            ColorSpace::Lab => "Lab",
+            ColorSpace::YCbCr => "YCbCr",
+            ColorSpace::Xyz => "XYZ",
+        };
+        write!(f, "{name}")
+    }
+}
@@ -1,3 +1,5 @@
+use crate::color_space::ColorSpace;
+
 /// An error typr for the io module.
 #[derive(thiserror::Error, Debug)]
 pub enum ImageError {
@@ -41,6 +43,10 @@ pub enum ImageError {
     #[error("Invalid number of bins {0}")]
     InvalidHistogramBins(usize),
 
+    /// Error when the number of levels of a pyramid is invalid.
+    #[error("Invalid number of pyramid levels {0}")]
+    InvalidPyramidLevels(usize),
+
     /// Error when the cannot compute the determinant.
     #[error("Cannot compute the determinant: matrix is singular")]
     CannotComputeDeterminant,
@@ -48,4 +54,28 @@ pub enum ImageError {
     /// Error when the kernel length is invalid.
     #[error("Invalid kernel length {0} and {1}")]
     InvalidKernelLength(usize, usize),
+
+    /// Error when the mask of a kernel does not match its size.
+    #[error("Kernel mask length {0} does not match the kernel size {1}")]
+    InvalidKernelMask(usize, usize),
+
+    /// Error when the sigma of a gaussian is not positive.
+    #[error("Invalid sigma values {0} and {1}")]
+    InvalidSigmaValue(f32, f32),
+
+    /// Error when the color space of an image is not the expected one.
+    #[error("Expected an image in the {0} color space, got {1}")]
+    ColorSpaceMismatch(ColorSpace, ColorSpace),
+
+    /// Error when the color space does not match the number of channels.
+    #[error("Color space {0} is not valid for an image with {1} channels")]
+    InvalidColorSpace(ColorSpace, usize),
+
+    /// Error when the row stride is smaller than the length of a row.
+    #[error("Row stride {0} is smaller than the row length {1}")]
+    InvalidRowStride(usize, usize),
+
+    /// Error when a region of interest is out of the image bounds.
+    #[error("Region ({0}, {1}) of size ({2}, {3}) is out of the image bounds ({4}, {5})")]
+    RoiOutOfBounds(usize, usize, usize, usize, usize, usize),
 }
@@ -1,6 +1,6 @@
-use kornia_tensor::{CpuAllocator, Tensor, Tensor2, Tensor3};
+use kornia_tensor::{CpuAllocator, Tensor, Tensor2, Tensor3, TensorAllocator};
 
-use crate::error::ImageError;
+use crate::{color_space::ColorSpace, error::ImageError};
 
 /// Image size in pixels
 ///
@@ -52,15 +52,32 @@ impl From<ImageSize> for [u32; 2] {
     }
 }
 
-#[derive(Clone)]
 /// Represents an image with pixel data.
 ///
 /// The image is represented as a 3D Tensor with shape (H, W, C), where H is the height of the image,
-pub struct Image<T, const C: usize>(pub Tensor3<T, CpuAllocator>);
+///
+/// The pixel data is allocated with the allocator `A`, which defaults to the [`CpuAllocator`].
+///
+/// The image also carries its [`ColorSpace`], which is [`ColorSpace::Unknown`] unless set with
+/// [`Image::with_color_space`].
+pub struct Image<T, const C: usize, A: TensorAllocator = CpuAllocator>(
+    pub Tensor3<T, A>,
+    pub(crate) ColorSpace,
+);
+
+impl<T, const C: usize, A> Clone for Image<T, C, A>
+where
+    T: Clone,
+    A: TensorAllocator + 'static,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1)
+    }
+}
 
 /// helper to deference the inner tensor
-impl<T, const C: usize> std::ops::Deref for Image<T, C> {
-    type Target = Tensor3<T, CpuAllocator>;
+impl<T, const C: usize, A: TensorAllocator> std::ops::Deref for Image<T, C, A> {
+    type Target = Tensor3<T, A>;
 
     // Define the deref method to return a reference to the inner Tensor3<T>.
     fn deref(&self) -> &Self::Target {
@@ -69,7 +86,7 @@ impl<T, const C: usize> std::ops::Deref for Image<T, C> {
 }
 
 /// helper to deference the inner tensor
-impl<T, const C: usize> std::ops::DerefMut for Image<T, C> {
+impl<T, const C: usize, A: TensorAllocator> std::ops::DerefMut for Image<T, C, A> {
     // Define the deref_mut method to return a mutable reference to the inner Tensor3<T>.
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.0
@@ -122,11 +139,10 @@ impl<T, const C: usize> Image<T, C> {
         }
 
         // allocate the image data
-        Ok(Self(Tensor3::from_shape_vec(
-            [size.height, size.width, C],
-            data,
-            CpuAllocator,
-        )?))
+        Ok(Self(
+            Tensor3::from_shape_vec([size.height, size.width, C], data, CpuAllocator)?,
+            ColorSpace::Unknown,
+        ))
     }
 
     /// Create a new image with the given size and default pixel data.
@@ -195,6 +211,81 @@ impl<T, const C: usize> Image<T, C> {
         Tensor::from_raw_parts([size.height, size.width, C], data, len, CpuAllocator)?.try_into()
     }
 
+    /// Create a new image from pixel data owned by `owner` without copying it.
+    ///
+    /// The owner is dropped together with the image, e.g. a memory mapped file or a frame
+    /// buffer handed over by a camera driver.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The size of the image in pixels.
+    /// * `owner` - The owner of the pixel data.
+    ///
+    /// # Returns
+    ///
+    /// A new image pointing to the pixel data of the owner.
+    ///
+    /// # Errors
+    ///
+    /// If the length of the pixel data does not match the image size, an error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    ///
+    /// let frame: Box<[u8]> = vec![0u8; 4 * 2 * 3].into_boxed_slice();
+    /// let ptr = frame.as_ptr();
+    ///
+    /// let image = Image::<u8, 3>::from_owner(
+    ///   ImageSize {
+    ///     width: 4,
+    ///     height: 2,
+    ///   },
+    ///   frame,
+    /// ).unwrap();
+    ///
+    /// assert_eq!(image.as_ptr(), ptr);
+    /// ```
+    pub fn from_owner<O>(size: ImageSize, owner: O) -> Result<Self, ImageError>
+    where
+        O: AsMut<[T]> + Send + 'static,
+    {
+        Tensor::from_owner([size.height, size.width, C], owner, CpuAllocator)?.try_into()
+    }
+
+    /// Create a new image from external pixel data and a drop callback without copying it.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The size of the image in pixels.
+    /// * `data` - A pointer to the pixel data.
+    /// * `on_drop` - The callback releasing the pixel data once the image is dropped.
+    ///
+    /// # Returns
+    ///
+    /// A new image pointing to the given pixel data.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be non-null, aligned and valid for `width * height * C` pixel values
+    /// until `on_drop` is called. If the memory is read-only, the image must not be mutated.
+    pub unsafe fn from_raw_parts_with_drop(
+        size: ImageSize,
+        data: *mut T,
+        on_drop: impl FnOnce() + Send + 'static,
+    ) -> Self {
+        Self(
+            Tensor::from_raw_parts_with_drop(
+                [size.height, size.width, C],
+                data,
+                CpuAllocator,
+                on_drop,
+            ),
+            ColorSpace::Unknown,
+        )
+    }
+
     /// Create a new image from a slice of pixel data.
     ///
     /// # Arguments
@@ -218,6 +309,77 @@ impl<T, const C: usize> Image<T, C> {
             Tensor::from_shape_slice([size.height, size.width, C], data, CpuAllocator)?;
         Image::try_from(tensor)
     }
+}
+
+impl<T, const C: usize, A: TensorAllocator + 'static> Image<T, C, A> {
+    /// Create a new image from pixel data using a custom allocator.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The size of the image in pixels.
+    /// * `data` - The pixel data of the image.
+    /// * `alloc` - The allocator of the pixel data.
+    ///
+    /// # Returns
+    ///
+    /// A new image with the given pixel data.
+    ///
+    /// # Errors
+    ///
+    /// If the length of the pixel data does not match the image size, an error is returned.
+    pub fn new_in(size: ImageSize, data: Vec<T>, alloc: A) -> Result<Self, ImageError> {
+        if data.len() != size.width * size.height * C {
+            return Err(ImageError::InvalidChannelShape(
+                data.len(),
+                size.width * size.height * C,
+            ));
+        }
+
+        Ok(Self(
+            Tensor3::from_shape_vec([size.height, size.width, C], data, alloc)?,
+            ColorSpace::Unknown,
+        ))
+    }
+
+    /// Create a new image with the given size and default pixel data using a custom allocator.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The size of the image in pixels.
+    /// * `val` - The default value of the pixel data.
+    /// * `alloc` - The allocator of the pixel data.
+    ///
+    /// # Returns
+    ///
+    /// A new image with the given size and default pixel data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    /// use kornia_tensor::AlignedAllocator;
+    ///
+    /// let image = Image::<u8, 3, _>::from_size_val_in(
+    ///   ImageSize {
+    ///     width: 10,
+    ///     height: 20,
+    ///   },
+    ///   0u8,
+    ///   AlignedAllocator::<64>,
+    /// );
+    ///
+    /// assert_eq!(image.size().width, 10);
+    /// assert_eq!(image.as_ptr() as usize % 64, 0);
+    /// ```
+    pub fn from_size_val_in(size: ImageSize, val: T, alloc: A) -> Self
+    where
+        T: Clone,
+    {
+        Self(
+            Tensor3::from_shape_val([size.height, size.width, C], val, alloc),
+            ColorSpace::Unknown,
+        )
+    }
 
     /// Map the pixel data of the image to a different type.
     ///
@@ -233,7 +395,7 @@ impl<T, const C: usize> Image<T, C> {
         U: Clone,
     {
         let data = self.as_slice().iter().map(f).collect::<Vec<U>>();
-        Image::<U, C>::new(self.size(), data)
+        Image::new(self.size(), data)?.with_color_space(self.1)
     }
 
     /// Cast the pixel data of the image to a different type.
@@ -256,7 +418,7 @@ impl<T, const C: usize> Image<T, C> {
             })
             .collect::<Result<Vec<U>, ImageError>>()?;
 
-        Image::new(self.size(), casted_data)
+        Image::new(self.size(), casted_data)?.with_color_space(self.1)
     }
 
     /// Get a channel of the image.
@@ -324,6 +486,69 @@ impl<T, const C: usize> Image<T, C> {
         Ok(channels)
     }
 
+    /// Get the color space of the image.
+    pub fn color_space(&self) -> ColorSpace {
+        self.1
+    }
+
+    /// Set the color space of the image.
+    ///
+    /// # Arguments
+    ///
+    /// * `color_space` - The color space of the pixel data.
+    ///
+    /// # Errors
+    ///
+    /// If the color space does not have `C` channels, an error is returned.
+    pub fn set_color_space(&mut self, color_space: ColorSpace) -> Result<(), ImageError> {
+        match color_space.num_channels() {
+            Some(num_channels) if num_channels != C => {
+                Err(ImageError::InvalidColorSpace(color_space, C))
+            }
+            _ => {
+                self.1 = color_space;
+                Ok(())
+            }
+        }
+    }
+
+    /// Tag the image with a color space.
+    ///
+    /// # Arguments
+    ///
+    /// * `color_space` - The color space of the pixel data.
+    ///
+    /// # Returns
+    ///
+    /// The same image tagged with the given color space.
+    ///
+    /// # Errors
+    ///
+    /// If the color space does not have `C` channels, an error is returned.
+    pub fn with_color_space(mut self, color_space: ColorSpace) -> Result<Self, ImageError> {
+        self.set_color_space(color_space)?;
+        Ok(self)
+    }
+
+    /// Check that the image is in the expected color space.
+    ///
+    /// Images with an unknown color space are accepted.
+    ///
+    /// # Arguments
+    ///
+    /// * `expected` - The expected color space.
+    ///
+    /// # Errors
+    ///
+    /// If the image is tagged with a different color space, an error is returned.
+    pub fn check_color_space(&self, expected: ColorSpace) -> Result<(), ImageError> {
+        match self.1 {
+            ColorSpace::Unknown => Ok(()),
+            color_space if color_space == expected => Ok(()),
+            color_space => Err(ImageError::ColorSpaceMismatch(expected, color_space)),
+        }
+    }
+
     /// Get the size of the image in pixels.
     pub fn size(&self) -> ImageSize {
         ImageSize {
@@ -404,7 +629,7 @@ impl<T, const C: usize> Image<T, C> {
             })
             .collect::<Result<Vec<U>, ImageError>>()?;
 
-        Image::new(self.size(), casted_data)
+        Image::new(self.size(), casted_data)?.with_color_space(self.1)
     }
 
     /// Cast the pixel data to a different type and scale it.
@@ -430,7 +655,7 @@ impl<T, const C: usize> Image<T, C> {
             })
             .collect::<Result<Vec<U>, ImageError>>()?;
 
-        Image::new(self.size(), casted_data)
+        Image::new(self.size(), casted_data)?.with_color_space(self.1)
     }
 
     /// Get the pixel data of the image.
@@ -524,21 +749,21 @@ where
 }
 
 /// helper to convert an multi channel tensor to a kornia image with try into
-impl<T, const C: usize> TryFrom<Tensor3<T, CpuAllocator>> for Image<T, C> {
+impl<T, const C: usize, A: TensorAllocator> TryFrom<Tensor3<T, A>> for Image<T, C, A> {
     type Error = ImageError;
 
-    fn try_from(value: Tensor3<T, CpuAllocator>) -> Result<Self, Self::Error> {
+    fn try_from(value: Tensor3<T, A>) -> Result<Self, Self::Error> {
         if value.shape[2] != C {
             return Err(ImageError::InvalidChannelShape(value.shape[2], C));
         }
-        Ok(Self(value))
+        Ok(Self(value, ColorSpace::Unknown))
     }
 }
 
-impl<T, const C: usize> TryInto<Tensor3<T, CpuAllocator>> for Image<T, C> {
+impl<T, const C: usize, A: TensorAllocator> TryInto<Tensor3<T, A>> for Image<T, C, A> {
     type Error = ImageError;
 
-    fn try_into(self) -> Result<Tensor3<T, CpuAllocator>, Self::Error> {
+    fn try_into(self) -> Result<Tensor3<T, A>, Self::Error> {
         Ok(self.0)
     }
 }
@@ -546,7 +771,7 @@ impl<T, const C: usize> TryInto<Tensor3<T, CpuAllocator>> for Image<T, C> {
 #[cfg(test)]
 mod tests {
     use crate::image::{Image, ImageError, ImageSize};
-    use kornia_tensor::{CpuAllocator, Tensor};
+    use kornia_tensor::{AlignedAllocator, CpuAllocator, PoolAllocator, Tensor};
 
     #[test]
     fn test_image_size() {
@@ -799,4 +1024,98 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_image_allocators() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 3,
+            height: 5,
+        };
+
+        let image = Image::<f32, 3, _>::new_in(size, vec![1.0; 45], AlignedAllocator::<64>)?;
+        assert_eq!(image.as_ptr() as usize % 64, 0);
+        assert_eq!(image.get_pixel(2, 4, 2)?, &1.0);
+
+        let pool = PoolAllocator::default();
+        let frame = Image::<u8, 3, _>::from_size_val_in(size, 0, pool.clone());
+        let ptr = frame.as_ptr();
+        drop(frame);
+
+        let frame = Image::<u8, 3, _>::from_size_val_in(size, 255, pool.clone());
+        assert_eq!(frame.as_ptr(), ptr);
+        assert_eq!(frame.cast::<f32>()?.get_pixel(1, 1, 1)?, &255.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_image_from_owner() -> Result<(), ImageError> {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+
+        let size = ImageSize {
+            width: 2,
+            height: 3,
+        };
+
+        let data = vec![0u8, 1, 2, 3, 4, 5];
+        let ptr = data.as_ptr();
+        let image = Image::<_, 1>::from_owner(size, data)?;
+        assert_eq!(image.as_ptr(), ptr);
+        assert_eq!(image.get_pixel(1, 2, 0)?, &5);
+
+        assert!(Image::<u8, 3>::from_owner(size, vec![0u8; 6]).is_err());
+
+        let mut frame = vec![7u16; 6];
+        let released = Arc::new(AtomicBool::new(false));
+        let image = unsafe {
+            let released = released.clone();
+            Image::<_, 1>::from_raw_parts_with_drop(size, frame.as_mut_ptr(), move || {
+                released.store(true, Ordering::SeqCst)
+            })
+        };
+        assert_eq!(image.as_ptr(), frame.as_ptr());
+        assert_eq!(image.get_pixel(0, 1, 0)?, &7);
+
+        drop(image);
+        assert!(released.load(Ordering::SeqCst));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_image_color_space() -> Result<(), ImageError> {
+        use crate::ColorSpace;
+
+        let size = ImageSize {
+            width: 2,
+            height: 1,
+        };
+
+        let image = Image::<u8, 3>::from_size_val(size, 0)?;
+        assert_eq!(image.color_space(), ColorSpace::Unknown);
+        assert!(image.check_color_space(ColorSpace::Hsv).is_ok());
+
+        let image = image.with_color_space(ColorSpace::Bgr)?;
+        assert_eq!(image.color_space(), ColorSpace::Bgr);
+        assert_eq!(image.clone().color_space(), ColorSpace::Bgr);
+        assert_eq!(image.cast::<f32>()?.color_space(), ColorSpace::Bgr);
+        assert_eq!(image.channel(0)?.color_space(), ColorSpace::Unknown);
+
+        assert!(image.check_color_space(ColorSpace::Bgr).is_ok());
+        assert!(matches!(
+            image.check_color_space(ColorSpace::Rgb),
+            Err(ImageError::ColorSpaceMismatch(
+                ColorSpace::Rgb,
+                ColorSpace::Bgr
+            ))
+        ));
+
+        assert!(matches!(
+            image.with_color_space(ColorSpace::Gray),
+            Err(ImageError::InvalidColorSpace(ColorSpace::Gray, 3))
+        ));
+
+        Ok(())
+    }
 }
@@ -4,11 +4,19 @@
 /// image representation for computer vision purposes.
 pub mod image;
 
+/// color space metadata of the images.
+pub mod color_space;
+
 /// Error types for the image module.
 pub mod error;
 
 /// module containing ops implementations.
 pub mod ops;
 
+/// borrowed strided views of images.
+pub mod view;
+
+pub use crate::color_space::ColorSpace;
 pub use crate::error::ImageError;
 pub use crate::image::{Image, ImageSize};
+pub use crate::view::{AsImageView, AsImageViewMut, ImageView, ImageViewMut};
@@ -0,0 +1,732 @@
+use kornia_tensor::TensorAllocator;
+
+use crate::{
+    color_space::ColorSpace,
+    error::ImageError,
+    image::{Image, ImageSize},
+};
+
+/// Returns the number of elements spanned by `rows` rows of `row_len` elements `stride` apart.
+fn strided_len(rows: usize, row_len: usize, stride: usize) -> usize {
+    match rows {
+        0 => 0,
+        _ => (rows - 1) * stride + row_len,
+    }
+}
+
+/// Returns the range of elements of a region of interest of a strided image.
+fn roi_range<const C: usize>(
+    image_size: ImageSize,
+    stride: usize,
+    x: usize,
+    y: usize,
+    size: ImageSize,
+) -> Result<std::ops::Range<usize>, ImageError> {
+    if x + size.width > image_size.width || y + size.height > image_size.height {
+        return Err(ImageError::RoiOutOfBounds(
+            x,
+            y,
+            size.width,
+            size.height,
+            image_size.width,
+            image_size.height,
+        ));
+    }
+
+    let start = y * stride + x * C;
+    Ok(start..start + strided_len(size.height, size.width * C, stride))
+}
+
+/// A borrowed view of the pixels of an image with an explicit row stride.
+///
+/// The rows of the view are `stride` elements apart in memory, which allows to represent a
+/// region of interest of an [`Image`] or an external buffer with padded rows without copying.
+///
+/// # Examples
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+///
+/// let image = Image::<u8, 1>::new(ImageSize { width: 4, height: 3 }, (0..12).collect()).unwrap();
+///
+/// let roi = image.roi(1, 1, ImageSize { width: 2, height: 2 }).unwrap();
+///
+/// assert_eq!(roi.stride(), 4);
+/// assert_eq!(roi.row(0), &[5, 6]);
+/// assert_eq!(roi.row(1), &[9, 10]);
+/// ```
+pub struct ImageView<'a, T, const C: usize> {
+    data: &'a [T],
+    size: ImageSize,
+    stride: usize,
+    color_space: ColorSpace,
+}
+
+impl<T, const C: usize> Clone for ImageView<'_, T, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const C: usize> Copy for ImageView<'_, T, C> {}
+
+impl<'a, T, const C: usize> ImageView<'a, T, C> {
+    /// Create a new view from strided pixel data.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The pixel data starting at the first pixel of the view.
+    /// * `size` - The size of the view in pixels.
+    /// * `stride` - The number of elements between the start of two consecutive rows.
+    ///
+    /// # Returns
+    ///
+    /// A new view of the pixel data.
+    ///
+    /// # Errors
+    ///
+    /// If the stride is smaller than a row or the data is too short, an error is returned.
+    pub fn new(data: &'a [T], size: ImageSize, stride: usize) -> Result<Self, ImageError> {
+        if stride < size.width * C {
+            return Err(ImageError::InvalidRowStride(stride, size.width * C));
+        }
+
+        let len = strided_len(size.height, size.width * C, stride);
+        if data.len() < len {
+            return Err(ImageError::InvalidChannelShape(data.len(), len));
+        }
+
+        Ok(Self {
+            data: &data[..len],
+            size,
+            stride,
+            color_space: ColorSpace::Unknown,
+        })
+    }
+
+    /// Tag the view with a color space.
+    ///
+    /// # Errors
+    ///
+    /// If the color space does not have `C` channels, an error is returned.
+    pub fn with_color_space(mut self, color_space: ColorSpace) -> Result<Self, ImageError> {
+        match color_space.num_channels() {
+            Some(num_channels) if num_channels != C => {
+                Err(ImageError::InvalidColorSpace(color_space, C))
+            }
+            _ => {
+                self.color_space = color_space;
+                Ok(self)
+            }
+        }
+    }
+
+    /// Get the color space of the view.
+    pub fn color_space(&self) -> ColorSpace {
+        self.color_space
+    }
+
+    /// Check that the view is in the expected color space.
+    ///
+    /// Views with an unknown color space are accepted.
+    ///
+    /// # Errors
+    ///
+    /// If the view is tagged with a different color space, an error is returned.
+    pub fn check_color_space(&self, expected: ColorSpace) -> Result<(), ImageError> {
+        match self.color_space {
+            ColorSpace::Unknown => Ok(()),
+            color_space if color_space == expected => Ok(()),
+            color_space => Err(ImageError::ColorSpaceMismatch(expected, color_space)),
+        }
+    }
+
+    /// Get the size of the view in pixels.
+    pub fn size(&self) -> ImageSize {
+        self.size
+    }
+
+    /// Get the number of columns of the view.
+    pub fn cols(&self) -> usize {
+        self.size.width
+    }
+
+    /// Get the number of rows of the view.
+    pub fn rows(&self) -> usize {
+        self.size.height
+    }
+
+    /// Get the width of the view in pixels.
+    pub fn width(&self) -> usize {
+        self.size.width
+    }
+
+    /// Get the height of the view in pixels.
+    pub fn height(&self) -> usize {
+        self.size.height
+    }
+
+    /// Get the number of channels of the view.
+    pub fn num_channels(&self) -> usize {
+        C
+    }
+
+    /// Get the number of elements between the start of two consecutive rows.
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Check if the rows of the view are contiguous in memory.
+    pub fn is_contiguous(&self) -> bool {
+        self.stride == self.size.width * C
+    }
+
+    /// Get the underlying pixel data, including the padding between the rows.
+    pub fn as_slice(&self) -> &'a [T] {
+        self.data
+    }
+
+    /// Get the pixel data of a row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row is out of bounds.
+    pub fn row(&self, row: usize) -> &'a [T] {
+        assert!(row < self.rows(), "row {row} is out of bounds");
+        let start = row * self.stride;
+        &self.data[start..start + self.size.width * C]
+    }
+
+    /// Iterate over the rows of the view.
+    pub fn row_iter(&self) -> impl Iterator<Item = &'a [T]> + 'a {
+        let row_len = self.size.width * C;
+        self.data
+            .chunks(self.stride.max(1))
+            .map(move |row| &row[..row_len])
+    }
+
+    /// Get the element at the given index.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The row, column and channel of the element.
+    ///
+    /// # Returns
+    ///
+    /// The element or `None` if the index is out of bounds.
+    pub fn get(&self, index: [usize; 3]) -> Option<&'a T> {
+        let [row, col, ch] = index;
+        if row >= self.rows() || col >= self.cols() || ch >= C {
+            return None;
+        }
+        Some(self.get_unchecked(index))
+    }
+
+    /// Get the element at the given index without checking the bounds of each dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The row, column and channel of the element.
+    pub fn get_unchecked(&self, index: [usize; 3]) -> &'a T {
+        let [row, col, ch] = index;
+        &self.data[row * self.stride + col * C + ch]
+    }
+
+    /// Get a view of a region of interest of the view.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x-coordinate of the top-left corner of the region.
+    /// * `y` - The y-coordinate of the top-left corner of the region.
+    /// * `size` - The size of the region in pixels.
+    ///
+    /// # Errors
+    ///
+    /// If the region is out of the bounds of the view, an error is returned.
+    pub fn roi(&self, x: usize, y: usize, size: ImageSize) -> Result<Self, ImageError> {
+        let range = roi_range::<C>(self.size, self.stride, x, y, size)?;
+        Ok(Self {
+            data: &self.data[range],
+            size,
+            stride: self.stride,
+            color_space: self.color_space,
+        })
+    }
+
+    /// Copy the pixels of the view into a new contiguous image.
+    pub fn to_image(&self) -> Result<Image<T, C>, ImageError>
+    where
+        T: Clone,
+    {
+        let mut data = Vec::with_capacity(self.size.width * self.size.height * C);
+        self.row_iter().for_each(|row| data.extend_from_slice(row));
+        Image::new(self.size, data)?.with_color_space(self.color_space)
+    }
+}
+
+/// A mutable borrowed view of the pixels of an image with an explicit row stride.
+///
+/// See [`ImageView`] for the memory layout. Setting the color space of a view covering a
+/// whole [`Image`] also sets the color space of the image, while views of a region of
+/// interest only keep it for themselves.
+///
+/// # Examples
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+///
+/// let mut image = Image::<u8, 1>::from_size_val(ImageSize { width: 4, height: 3 }, 0).unwrap();
+///
+/// let mut roi = image.roi_mut(1, 1, ImageSize { width: 2, height: 2 }).unwrap();
+/// roi.row_mut(1).fill(1);
+///
+/// assert_eq!(image.as_slice(), &[0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0]);
+/// ```
+pub struct ImageViewMut<'a, T, const C: usize> {
+    data: &'a mut [T],
+    size: ImageSize,
+    stride: usize,
+    color_space: ColorSpace,
+    parent_color_space: Option<&'a mut ColorSpace>,
+}
+
+impl<'a, T, const C: usize> ImageViewMut<'a, T, C> {
+    /// Create a new mutable view from strided pixel data.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The pixel data starting at the first pixel of the view.
+    /// * `size` - The size of the view in pixels.
+    /// * `stride` - The number of elements between the start of two consecutive rows.
+    ///
+    /// # Returns
+    ///
+    /// A new mutable view of the pixel data.
+    ///
+    /// # Errors
+    ///
+    /// If the stride is smaller than a row or the data is too short, an error is returned.
+    pub fn new(data: &'a mut [T], size: ImageSize, stride: usize) -> Result<Self, ImageError> {
+        if stride < size.width * C {
+            return Err(ImageError::InvalidRowStride(stride, size.width * C));
+        }
+
+        let len = strided_len(size.height, size.width * C, stride);
+        if data.len() < len {
+            return Err(ImageError::InvalidChannelShape(data.len(), len));
+        }
+
+        Ok(Self {
+            data: &mut data[..len],
+            size,
+            stride,
+            color_space: ColorSpace::Unknown,
+            parent_color_space: None,
+        })
+    }
+
+    /// Get an immutable view of the pixels.
+    pub fn as_view(&self) -> ImageView<'_, T, C> {
+        ImageView {
+            data: &*self.data,
+            size: self.size,
+            stride: self.stride,
+            color_space: self.color_space,
+        }
+    }
+
+    /// Get the color space of the view.
+    pub fn color_space(&self) -> ColorSpace {
+        self.color_space
+    }
+
+    /// Set the color space of the view.
+    ///
+    /// # Errors
+    ///
+    /// If the color space does not have `C` channels, an error is returned.
+    pub fn set_color_space(&mut self, color_space: ColorSpace) -> Result<(), ImageError> {
+        if let Some(num_channels) = color_space.num_channels() {
+            if num_channels != C {
+                return Err(ImageError::InvalidColorSpace(color_space, C));
+            }
+        }
+
+        self.color_space = color_space;
+        if let Some(parent_color_space) = self.parent_color_space.as_deref_mut() {
+            *parent_color_space = color_space;
+        }
+
+        Ok(())
+    }
+
+    /// Get the size of the view in pixels.
+    pub fn size(&self) -> ImageSize {
+        self.size
+    }
+
+    /// Get the number of columns of the view.
+    pub fn cols(&self) -> usize {
+        self.size.width
+    }
+
+    /// Get the number of rows of the view.
+    pub fn rows(&self) -> usize {
+        self.size.height
+    }
+
+    /// Get the width of the view in pixels.
+    pub fn width(&self) -> usize {
+        self.size.width
+    }
+
+    /// Get the height of the view in pixels.
+    pub fn height(&self) -> usize {
+        self.size.height
+    }
+
+    /// Get the number of channels of the view.
+    pub fn num_channels(&self) -> usize {
+        C
+    }
+
+    /// Get the number of elements between the start of two consecutive rows.
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Check if the rows of the view are contiguous in memory.
+    pub fn is_contiguous(&self) -> bool {
+        self.stride == self.size.width * C
+    }
+
+    /// Get the underlying pixel data, including the padding between the rows.
+    pub fn as_slice(&self) -> &[T] {
+        &*self.data
+    }
+
+    /// Get the underlying mutable pixel data, including the padding between the rows.
+    pub fn as_slice_mut(&mut self) -> &mut [T] {
+        &mut *self.data
+    }
+
+    /// Get the pixel data of a row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row is out of bounds.
+    pub fn row(&self, row: usize) -> &[T] {
+        self.as_view().row(row)
+    }
+
+    /// Get the mutable pixel data of a row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row is out of bounds.
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        assert!(row < self.rows(), "row {row} is out of bounds");
+        let start = row * self.stride;
+        &mut self.data[start..start + self.size.width * C]
+    }
+
+    /// Iterate over the mutable rows of the view.
+    pub fn row_iter_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        let row_len = self.size.width * C;
+        self.data
+            .chunks_mut(self.stride.max(1))
+            .map(move |row| &mut row[..row_len])
+    }
+
+    /// Get a mutable view of a region of interest of the view.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x-coordinate of the top-left corner of the region.
+    /// * `y` - The y-coordinate of the top-left corner of the region.
+    /// * `size` - The size of the region in pixels.
+    ///
+    /// # Errors
+    ///
+    /// If the region is out of the bounds of the view, an error is returned.
+    pub fn roi_mut(
+        &mut self,
+        x: usize,
+        y: usize,
+        size: ImageSize,
+    ) -> Result<ImageViewMut<'_, T, C>, ImageError> {
+        let range = roi_range::<C>(self.size, self.stride, x, y, size)?;
+        Ok(ImageViewMut {
+            data: &mut self.data[range],
+            size,
+            stride: self.stride,
+            color_space: self.color_space,
+            parent_color_space: None,
+        })
+    }
+
+    /// Copy the pixels of another view into the view.
+    ///
+    /// # Errors
+    ///
+    /// If the views do not have the same size, an error is returned.
+    pub fn copy_from(&mut self, src: &ImageView<'_, T, C>) -> Result<(), ImageError>
+    where
+        T: Copy,
+    {
+        if src.size() != self.size() {
+            return Err(ImageError::InvalidImageSize(
+                src.cols(),
+                src.rows(),
+                self.cols(),
+                self.rows(),
+            ));
+        }
+
+        self.row_iter_mut()
+            .zip(src.row_iter())
+            .for_each(|(dst_row, src_row)| dst_row.copy_from_slice(src_row));
+
+        Ok(())
+    }
+}
+
+/// A trait for the containers that can be viewed as an [`ImageView`].
+///
+/// The image processing operators take their inputs through this trait to accept both
+/// images and views.
+pub trait AsImageView<T, const C: usize> {
+    /// Get a view of the pixels.
+    fn as_view(&self) -> ImageView<'_, T, C>;
+}
+
+/// A trait for the containers that can be viewed as an [`ImageViewMut`].
+///
+/// The image processing operators take their outputs through this trait to accept both
+/// images and views.
+pub trait AsImageViewMut<T, const C: usize>: AsImageView<T, C> {
+    /// Get a mutable view of the pixels.
+    fn as_view_mut(&mut self) -> ImageViewMut<'_, T, C>;
+}
+
+impl<T, const C: usize, A: TensorAllocator + 'static> AsImageView<T, C> for Image<T, C, A> {
+    fn as_view(&self) -> ImageView<'_, T, C> {
+        self.view()
+    }
+}
+
+impl<T, const C: usize, A: TensorAllocator + 'static> AsImageViewMut<T, C> for Image<T, C, A> {
+    fn as_view_mut(&mut self) -> ImageViewMut<'_, T, C> {
+        self.view_mut()
+    }
+}
+
+impl<T, const C: usize> AsImageView<T, C> for ImageView<'_, T, C> {
+    fn as_view(&self) -> ImageView<'_, T, C> {
+        *self
+    }
+}
+
+impl<T, const C: usize> AsImageView<T, C> for ImageViewMut<'_, T, C> {
+    fn as_view(&self) -> ImageView<'_, T, C> {
+        ImageViewMut::as_view(self)
+    }
+}
+
+impl<T, const C: usize> AsImageViewMut<T, C> for ImageViewMut<'_, T, C> {
+    fn as_view_mut(&mut self) -> ImageViewMut<'_, T, C> {
+        ImageViewMut {
+            data: &mut *self.data,
+            size: self.size,
+            stride: self.stride,
+            color_space: self.color_space,
+            parent_color_space: self.parent_color_space.as_deref_mut(),
+        }
+    }
+}
+
+impl<T, const C: usize, A: TensorAllocator + 'static> Image<T, C, A> {
+    /// Get a view of the whole image.
+    pub fn view(&self) -> ImageView<'_, T, C> {
+        ImageView {
+            data: self.as_slice(),
+            size: self.size(),
+            stride: self.cols() * C,
+            color_space: self.1,
+        }
+    }
+
+    /// Get a mutable view of the whole image.
+    pub fn view_mut(&mut self) -> ImageViewMut<'_, T, C> {
+        let (size, stride) = (self.size(), self.cols() * C);
+        ImageViewMut {
+            data: self.0.as_slice_mut(),
+            size,
+            stride,
+            color_space: self.1,
+            parent_color_space: Some(&mut self.1),
+        }
+    }
+
+    /// Get a view of a region of interest of the image without copying it.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x-coordinate of the top-left corner of the region.
+    /// * `y` - The y-coordinate of the top-left corner of the region.
+    /// * `size` - The size of the region in pixels.
+    ///
+    /// # Errors
+    ///
+    /// If the region is out of the bounds of the image, an error is returned.
+    pub fn roi(
+        &self,
+        x: usize,
+        y: usize,
+        size: ImageSize,
+    ) -> Result<ImageView<'_, T, C>, ImageError> {
+        self.view().roi(x, y, size)
+    }
+
+    /// Get a mutable view of a region of interest of the image without copying it.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x-coordinate of the top-left corner of the region.
+    /// * `y` - The y-coordinate of the top-left corner of the region.
+    /// * `size` - The size of the region in pixels.
+    ///
+    /// # Errors
+    ///
+    /// If the region is out of the bounds of the image, an error is returned.
+    pub fn roi_mut(
+        &mut self,
+        x: usize,
+        y: usize,
+        size: ImageSize,
+    ) -> Result<ImageViewMut<'_, T, C>, ImageError> {
+        let range = roi_range::<C>(self.size(), self.cols() * C, x, y, size)?;
+        let stride = self.cols() * C;
+        Ok(ImageViewMut {
+            data: &mut self.0.as_slice_mut()[range],
+            size,
+            stride,
+            color_space: self.1,
+            parent_color_space: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_image_view() -> Result<(), ImageError> {
+        let image = Image::<u8, 2>::new(
+            ImageSize {
+                width: 3,
+                height: 3,
+            },
+            (0..18).collect(),
+        )?;
+
+        let view = image.view();
+        assert!(view.is_contiguous());
+        assert_eq!(view.get([2, 1, 1]), Some(&15));
+
+        let roi = image.roi(
+            1,
+            1,
+            ImageSize {
+                width: 2,
+                height: 2,
+            },
+        )?;
+        assert!(!roi.is_contiguous());
+        assert_eq!(roi.stride(), 6);
+        assert_eq!(roi.as_slice().len(), 10);
+        assert_eq!(
+            roi.row_iter().collect::<Vec<_>>(),
+            [[8, 9, 10, 11], [14, 15, 16, 17]]
+        );
+        assert_eq!(roi.get([1, 0, 1]), Some(&15));
+        assert_eq!(roi.get([2, 0, 0]), None);
+
+        let sub = roi.roi(
+            1,
+            0,
+            ImageSize {
+                width: 1,
+                height: 2,
+            },
+        )?;
+        assert_eq!(sub.to_image()?.as_slice(), &[10, 11, 16, 17]);
+
+        assert!(matches!(
+            image.roi(
+                2,
+                0,
+                ImageSize {
+                    width: 2,
+                    height: 1
+                }
+            ),
+            Err(ImageError::RoiOutOfBounds(2, 0, 2, 1, 3, 3))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_image_view_from_padded_buffer() -> Result<(), ImageError> {
+        // rows of 3 pixels padded to 4 elements
+        let data = [1u8, 2, 3, 0, 4, 5, 6, 0, 7, 8, 9];
+        let size = ImageSize {
+            width: 3,
+            height: 3,
+        };
+
+        let view = ImageView::<_, 1>::new(&data, size, 4)?;
+        assert_eq!(view.to_image()?.as_slice(), &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        assert!(matches!(
+            ImageView::<_, 1>::new(&data, size, 2),
+            Err(ImageError::InvalidRowStride(2, 3))
+        ));
+        assert!(ImageView::<_, 1>::new(&data[..10], size, 4).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_image_view_mut() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 4,
+            height: 2,
+        };
+        let mut image = Image::<u8, 1>::from_size_val(size, 0)?;
+
+        let tile = Image::<u8, 1>::new(
+            ImageSize {
+                width: 2,
+                height: 2,
+            },
+            vec![1, 2, 3, 4],
+        )?;
+        image.roi_mut(2, 0, tile.size())?.copy_from(&tile.view())?;
+        assert_eq!(image.as_slice(), &[0, 0, 1, 2, 0, 0, 3, 4]);
+
+        // only views of the whole image set the color space of the image
+        image
+            .roi_mut(0, 0, tile.size())?
+            .set_color_space(ColorSpace::Gray)?;
+        assert_eq!(image.color_space(), ColorSpace::Unknown);
+
+        image.view_mut().set_color_space(ColorSpace::Gray)?;
+        assert_eq!(image.color_space(), ColorSpace::Gray);
+        assert!(image.view_mut().set_color_space(ColorSpace::Rgb).is_err());
+
+        Ok(())
+    }
+}
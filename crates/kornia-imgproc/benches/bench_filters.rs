@@ -1,6 +1,7 @@
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
 
 use kornia_image::{Image, ImageError};
+use kornia_imgproc::border::BorderMode;
 use kornia_imgproc::filter::{box_blur_fast, gaussian_blur, kernels, separable_filter};
 
 use image::RgbImage;
@@ -14,7 +15,7 @@ fn gaussian_blur_u8<const C: usize>(
 ) -> Result<(), ImageError> {
     let kernel_x = kernels::gaussian_kernel_1d(kernel_size, sigma);
     let kernel_y = kernels::gaussian_kernel_1d(kernel_size, sigma);
-    separable_filter(src, dst, &kernel_x, &kernel_y)
+    separable_filter(src, dst, &kernel_x, &kernel_y, BorderMode::Reflect101)
 }
 
 fn bench_filters(c: &mut Criterion) {
@@ -50,6 +51,7 @@ fn bench_filters(c: &mut Criterion) {
                             &mut dst,
                             (*kernel_size, *kernel_size),
                             (1.5, 1.5),
+                            BorderMode::Reflect101,
                         ))
                     })
                 },
@@ -79,7 +81,14 @@ fn bench_filters(c: &mut Criterion) {
                 &(&image_f32, &output_f32),
                 |b, i| {
                     let (src, mut dst) = (i.0, i.1.clone());
-                    b.iter(|| black_box(box_blur_fast(src, &mut dst, (1.5, 1.5))))
+                    b.iter(|| {
+                        black_box(box_blur_fast(
+                            src,
+                            &mut dst,
+                            (1.5, 1.5),
+                            BorderMode::Replicate,
+                        ))
+                    })
                 },
             );
         }
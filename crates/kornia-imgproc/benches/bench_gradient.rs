@@ -1,6 +1,7 @@
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
 
 use kornia_image::Image;
+use kornia_imgproc::border::BorderMode;
 use kornia_imgproc::filter::{
     spatial_gradient_float, spatial_gradient_float_parallel, spatial_gradient_float_parallel_row,
 };
@@ -28,7 +29,14 @@ fn bench_gradient(c: &mut Criterion) {
             &(&image, &output_dx, &output_dy),
             |b, i| {
                 let (src, mut dx, mut dy) = (i.0, i.1.clone(), i.2.clone());
-                b.iter(|| black_box(spatial_gradient_float(src, &mut dx, &mut dy)))
+                b.iter(|| {
+                    black_box(spatial_gradient_float(
+                        src,
+                        &mut dx,
+                        &mut dy,
+                        BorderMode::Replicate,
+                    ))
+                })
             },
         );
 
@@ -37,7 +45,14 @@ fn bench_gradient(c: &mut Criterion) {
             &(&image, &output_dx, &output_dy),
             |b, i| {
                 let (src, mut dx, mut dy) = (i.0, i.1.clone(), i.2.clone());
-                b.iter(|| black_box(spatial_gradient_float_parallel_row(src, &mut dx, &mut dy)))
+                b.iter(|| {
+                    black_box(spatial_gradient_float_parallel_row(
+                        src,
+                        &mut dx,
+                        &mut dy,
+                        BorderMode::Replicate,
+                    ))
+                })
             },
         );
 
@@ -46,7 +61,14 @@ fn bench_gradient(c: &mut Criterion) {
             &(&image, &output_dx, &output_dy),
             |b, i| {
                 let (src, mut dx, mut dy) = (i.0, i.1.clone(), i.2.clone());
-                b.iter(|| black_box(spatial_gradient_float_parallel(src, &mut dx, &mut dy)))
+                b.iter(|| {
+                    black_box(spatial_gradient_float_parallel(
+                        src,
+                        &mut dx,
+                        &mut dy,
+                        BorderMode::Replicate,
+                    ))
+                })
             },
         );
     }
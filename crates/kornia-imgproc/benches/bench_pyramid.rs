@@ -1,7 +1,8 @@
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
 
 use kornia_image::Image;
-use kornia_imgproc::pyramid::pyrup;
+use kornia_imgproc::border::BorderMode;
+use kornia_imgproc::pyramid::{pyrdown, pyrup};
 
 fn bench_pyramid(c: &mut Criterion) {
     let mut group = c.benchmark_group("Pyramid Operations");
@@ -27,7 +28,18 @@ fn bench_pyramid(c: &mut Criterion) {
             |b, i| {
                 let (src, mut dst) = (i.0, i.1.clone());
                 b.iter(|| {
-                    black_box(pyrup(src, &mut dst)).unwrap();
+                    black_box(pyrup(src, &mut dst, BorderMode::Reflect101)).unwrap();
+                })
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("pyrdown", &parameter_string),
+            &(&up_image, &small_image),
+            |b, i| {
+                let (src, mut dst) = (i.0, i.1.clone());
+                b.iter(|| {
+                    black_box(pyrdown(src, &mut dst, BorderMode::Reflect101)).unwrap();
                 })
             },
         );
@@ -45,7 +57,7 @@ fn bench_pyramid(c: &mut Criterion) {
             |b, i| {
                 let (src, mut dst) = (i.0, i.1.clone());
                 b.iter(|| {
-                    black_box(pyrup(src, &mut dst)).unwrap();
+                    black_box(pyrup(src, &mut dst, BorderMode::Reflect101)).unwrap();
                 })
             },
         );
@@ -66,6 +66,7 @@ fn resize_ndarray_zip(src: &Image<f32, 3>, dst: &mut Image<f32, 3>) {
                     v,
                     k,
                     InterpolationMode::Nearest,
+                    kornia_imgproc::border::BorderMode::Replicate,
                 )
             });
 
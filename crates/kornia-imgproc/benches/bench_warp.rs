@@ -2,6 +2,7 @@ use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criteri
 
 use kornia_image::Image;
 use kornia_imgproc::{
+    border::BorderMode,
     interpolation::InterpolationMode,
     warp::{get_rotation_matrix2d, warp_affine, warp_perspective},
 };
@@ -34,6 +35,7 @@ fn bench_warp_affine(c: &mut Criterion) {
                         black_box(&mut dst),
                         black_box(&m),
                         black_box(InterpolationMode::Bilinear),
+                        black_box(BorderMode::Constant(0.0)),
                     )
                 })
             },
@@ -70,6 +72,7 @@ fn bench_warp_perspective(c: &mut Criterion) {
                         black_box(&mut dst),
                         black_box(&m),
                         black_box(InterpolationMode::Bilinear),
+                        black_box(BorderMode::Constant(0.0)),
                     )
                 })
             },
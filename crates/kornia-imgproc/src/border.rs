@@ -0,0 +1,112 @@
+/// The extrapolation of the pixels outside the image.
+///
+/// The examples show the pixels `abcdefgh` of a row extended by three pixels on each side.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BorderMode {
+    /// Fill with a constant value: `vvv|abcdefgh|vvv`.
+    Constant(f32),
+    /// Repeat the pixels at the border: `aaa|abcdefgh|hhh`.
+    Replicate,
+    /// Mirror the image including the pixels at the border: `cba|abcdefgh|hgf`.
+    Reflect,
+    /// Mirror the image around the pixels at the border: `dcb|abcdefgh|gfe`.
+    Reflect101,
+    /// Tile the image: `fgh|abcdefgh|abc`.
+    Wrap,
+}
+
+impl BorderMode {
+    /// Map a coordinate to a pixel of a row or column of the image.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The coordinate, possibly outside the image.
+    /// * `len` - The number of pixels of the row or column.
+    ///
+    /// # Returns
+    ///
+    /// The index of the pixel, or `None` if the coordinate takes the constant value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_imgproc::border::BorderMode;
+    ///
+    /// assert_eq!(BorderMode::Reflect.index(-1, 8), Some(0));
+    /// assert_eq!(BorderMode::Reflect101.index(-1, 8), Some(1));
+    /// assert_eq!(BorderMode::Wrap.index(9, 8), Some(1));
+    /// assert_eq!(BorderMode::Constant(0.0).index(9, 8), None);
+    /// ```
+    pub fn index(&self, i: isize, len: usize) -> Option<usize> {
+        let n = len as isize;
+        if (0..n).contains(&i) {
+            return Some(i as usize);
+        }
+
+        if n == 0 {
+            return None;
+        }
+
+        let i = match self {
+            BorderMode::Constant(_) => return None,
+            BorderMode::Replicate => i.clamp(0, n - 1),
+            BorderMode::Reflect => {
+                let i = i.rem_euclid(2 * n);
+                if i < n {
+                    i
+                } else {
+                    2 * n - 1 - i
+                }
+            }
+            BorderMode::Reflect101 if n == 1 => 0,
+            BorderMode::Reflect101 => {
+                let i = i.rem_euclid(2 * (n - 1));
+                if i < n {
+                    i
+                } else {
+                    2 * (n - 1) - i
+                }
+            }
+            BorderMode::Wrap => i.rem_euclid(n),
+        };
+
+        Some(i as usize)
+    }
+
+    /// Get the value of the pixels outside the image in constant mode.
+    pub fn constant_value(&self) -> f32 {
+        match self {
+            BorderMode::Constant(v) => *v,
+            _ => 0.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BorderMode;
+
+    #[test]
+    fn border_index() {
+        let row = |mode: BorderMode| {
+            (-3..11)
+                .map(|i| {
+                    mode.index(i, 8)
+                        .map(|i| (b'a' + i as u8) as char)
+                        .unwrap_or('v')
+                })
+                .collect::<String>()
+        };
+
+        assert_eq!(row(BorderMode::Constant(1.0)), "vvvabcdefghvvv");
+        assert_eq!(row(BorderMode::Replicate), "aaaabcdefghhhh");
+        assert_eq!(row(BorderMode::Reflect), "cbaabcdefghhgf");
+        assert_eq!(row(BorderMode::Reflect101), "dcbabcdefghgfe");
+        assert_eq!(row(BorderMode::Wrap), "fghabcdefghabc");
+
+        // the coordinates far from the image are mapped periodically
+        assert_eq!(BorderMode::Reflect.index(-17, 8), Some(0));
+        assert_eq!(BorderMode::Reflect101.index(15, 8), Some(1));
+        assert_eq!(BorderMode::Reflect101.index(-5, 1), Some(0));
+    }
+}
@@ -0,0 +1,290 @@
+use super::ColorChannel;
+use crate::{border::BorderMode, parallel};
+use kornia_image::{AsImageView, AsImageViewMut, ColorSpace, ImageError};
+
+/// The arrangement of the color filters of a Bayer mosaic.
+///
+/// The name lists the colors of the top-left 2x2 block in row-major order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BayerPattern {
+    /// Red and green on even rows, green and blue on odd rows.
+    Rggb,
+    /// Blue and green on even rows, green and red on odd rows.
+    Bggr,
+    /// Green and red on even rows, blue and green on odd rows.
+    Grbg,
+    /// Green and blue on even rows, red and green on odd rows.
+    Gbrg,
+}
+
+impl BayerPattern {
+    /// Get the row and column parity of the red pixels.
+    fn red_offset(&self) -> (usize, usize) {
+        match self {
+            BayerPattern::Rggb => (0, 0),
+            BayerPattern::Bggr => (1, 1),
+            BayerPattern::Grbg => (0, 1),
+            BayerPattern::Gbrg => (1, 0),
+        }
+    }
+}
+
+/// The interpolation method used to demosaic a Bayer image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DemosaicMethod {
+    /// Average of the nearest pixels of each color.
+    Bilinear,
+    /// Gradient-corrected linear interpolation with 5x5 kernels.
+    ///
+    /// Reference: Malvar, He and Cutler, "High-quality linear interpolation for demosaicing
+    /// of Bayer-patterned color images", ICASSP 2004.
+    MalvarHeCutler,
+}
+
+/// Convert a Bayer mosaic image to RGB.
+///
+/// # Arguments
+///
+/// * `src` - The input single channel image with the raw sensor values.
+/// * `dst` - The output RGB image.
+/// * `pattern` - The arrangement of the color filters of the sensor.
+/// * `method` - The interpolation method.
+///
+/// The borders are handled by reflecting the mosaic, which keeps the pattern.
+///
+/// # Errors
+///
+/// If the images do not have the same size or are smaller than 2x2, an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::color::{rgb_from_bayer, BayerPattern, DemosaicMethod};
+///
+/// let raw = Image::<u16, 1>::new(
+///     ImageSize { width: 2, height: 2 },
+///     vec![4000, 2000, 2000, 1000],
+/// ).unwrap();
+///
+/// let mut rgb = Image::<u16, 3>::from_size_val(raw.size(), 0).unwrap();
+///
+/// rgb_from_bayer(&raw, &mut rgb, BayerPattern::Rggb, DemosaicMethod::Bilinear).unwrap();
+///
+/// assert_eq!(rgb.as_slice()[..3], [4000, 2000, 1000]);
+/// ```
+pub fn rgb_from_bayer<T: ColorChannel>(
+    src: &impl AsImageView<T, 1>,
+    dst: &mut impl AsImageViewMut<T, 3>,
+    pattern: BayerPattern,
+    method: DemosaicMethod,
+) -> Result<(), ImageError> {
+    let src = src.as_view();
+    let mut dst = dst.as_view_mut();
+
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    if src.cols() < 2 || src.rows() < 2 {
+        return Err(ImageError::InvalidImageSize(src.cols(), src.rows(), 2, 2));
+    }
+
+    let (rows, cols) = (src.rows(), src.cols());
+    let (red_row, red_col) = pattern.red_offset();
+
+    // reflecting without repeating the border preserves the parity of the index, so it keeps
+    // the Bayer pattern
+    let reflect = |i: isize, n: usize| {
+        BorderMode::Reflect101
+            .index(i, n)
+            .expect("the image is at least 2x2")
+    };
+
+    parallel::par_iter_rows_indexed_mut(&mut dst, |r, dst_row| {
+        // the rows of the 5x5 neighbourhood
+        let src_rows = [-2, -1, 0, 1, 2].map(|dy| src.row(reflect(r as isize + dy, rows)));
+        let is_red_row = r % 2 == red_row;
+
+        dst_row
+            .chunks_exact_mut(3)
+            .enumerate()
+            .for_each(|(c, dst_pixel)| {
+                let p = |dy: isize, dx: isize| {
+                    src_rows[(dy + 2) as usize][reflect(c as isize + dx, cols)].to_f32()
+                };
+                let is_red_col = c % 2 == red_col;
+
+                let rgb = match method {
+                    DemosaicMethod::Bilinear => bilinear(p, is_red_row, is_red_col),
+                    DemosaicMethod::MalvarHeCutler => malvar_he_cutler(p, is_red_row, is_red_col),
+                };
+
+                dst_pixel
+                    .iter_mut()
+                    .zip(rgb)
+                    .for_each(|(d, v)| *d = T::from_f32(v.clamp(0.0, 255.0)));
+            });
+    });
+
+    dst.set_color_space(ColorSpace::Rgb)
+}
+
+/// Interpolate the RGB value of a pixel averaging the nearest pixels of each color.
+fn bilinear(p: impl Fn(isize, isize) -> f32, is_red_row: bool, is_red_col: bool) -> [f32; 3] {
+    let v = p(0, 0);
+    let horizontal = (p(0, -1) + p(0, 1)) / 2.0;
+    let vertical = (p(-1, 0) + p(1, 0)) / 2.0;
+
+    match (is_red_row, is_red_col) {
+        // red or blue pixels, green on the cross and the other color on the diagonals
+        (true, true) | (false, false) => {
+            let cross = (horizontal + vertical) / 2.0;
+            let diagonal = (p(-1, -1) + p(-1, 1) + p(1, -1) + p(1, 1)) / 4.0;
+            match is_red_row {
+                true => [v, cross, diagonal],
+                false => [diagonal, cross, v],
+            }
+        }
+        // green pixels in a red row
+        (true, false) => [horizontal, v, vertical],
+        // green pixels in a blue row
+        (false, true) => [vertical, v, horizontal],
+    }
+}
+
+/// Interpolate the RGB value of a pixel with the gradient-corrected kernels of Malvar et al.
+fn malvar_he_cutler(
+    p: impl Fn(isize, isize) -> f32,
+    is_red_row: bool,
+    is_red_col: bool,
+) -> [f32; 3] {
+    let v = p(0, 0);
+    let horizontal1 = p(0, -1) + p(0, 1);
+    let horizontal2 = p(0, -2) + p(0, 2);
+    let vertical1 = p(-1, 0) + p(1, 0);
+    let vertical2 = p(-2, 0) + p(2, 0);
+    let diagonal1 = p(-1, -1) + p(-1, 1) + p(1, -1) + p(1, 1);
+
+    match (is_red_row, is_red_col) {
+        (true, true) | (false, false) => {
+            let green = (4.0 * v + 2.0 * (horizontal1 + vertical1) - horizontal2 - vertical2) / 8.0;
+            let other = (6.0 * v + 2.0 * diagonal1 - 1.5 * (horizontal2 + vertical2)) / 8.0;
+            match is_red_row {
+                true => [v, green, other],
+                false => [other, green, v],
+            }
+        }
+        _ => {
+            // the colors of the horizontal and vertical neighbours of a green pixel
+            let from_horizontal =
+                (5.0 * v + 4.0 * horizontal1 - diagonal1 - horizontal2 + 0.5 * vertical2) / 8.0;
+            let from_vertical =
+                (5.0 * v + 4.0 * vertical1 - diagonal1 - vertical2 + 0.5 * horizontal2) / 8.0;
+            match is_red_row {
+                true => [from_horizontal, v, from_vertical],
+                false => [from_vertical, v, from_horizontal],
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BayerPattern, DemosaicMethod};
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    /// Sample an RGB image with a Bayer pattern.
+    fn mosaic(rgb: &Image<u8, 3>, pattern: BayerPattern) -> Result<Image<u8, 1>, ImageError> {
+        let (red_row, red_col) = pattern.red_offset();
+        let data = (0..rgb.rows())
+            .flat_map(|r| (0..rgb.cols()).map(move |c| (r, c)))
+            .map(|(r, c)| {
+                let ch = match (r % 2 == red_row, c % 2 == red_col) {
+                    (true, true) => 0,
+                    (false, false) => 2,
+                    _ => 1,
+                };
+                *rgb.get_pixel(c, r, ch).unwrap_or(&0)
+            })
+            .collect();
+        Image::new(rgb.size(), data)
+    }
+
+    #[test]
+    fn demosaic_constant_color() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 6,
+            height: 5,
+        };
+        let rgb = Image::<u8, 3>::new(size, [200, 100, 50].repeat(30))?;
+
+        for pattern in [
+            BayerPattern::Rggb,
+            BayerPattern::Bggr,
+            BayerPattern::Grbg,
+            BayerPattern::Gbrg,
+        ] {
+            let raw = mosaic(&rgb, pattern)?;
+            for method in [DemosaicMethod::Bilinear, DemosaicMethod::MalvarHeCutler] {
+                let mut out = Image::<u8, 3>::from_size_val(size, 0)?;
+                super::rgb_from_bayer(&raw, &mut out, pattern, method)?;
+                assert_eq!(out.as_slice(), rgb.as_slice(), "{pattern:?} {method:?}");
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn demosaic_gradient() -> Result<(), ImageError> {
+        // a horizontal ramp in all channels is interpolated exactly away from the borders
+        let size = ImageSize {
+            width: 8,
+            height: 6,
+        };
+        let data = (0..size.height)
+            .flat_map(|_| (0..size.width).flat_map(|c| [c as u8 * 20 + 10; 3]))
+            .collect();
+        let rgb = Image::<u8, 3>::new(size, data)?;
+        let raw = mosaic(&rgb, BayerPattern::Grbg)?;
+
+        for method in [DemosaicMethod::Bilinear, DemosaicMethod::MalvarHeCutler] {
+            let mut out = Image::<u8, 3>::from_size_val(size, 0)?;
+            super::rgb_from_bayer(&raw, &mut out, BayerPattern::Grbg, method)?;
+            for r in 0..size.height {
+                for c in 2..size.width - 2 {
+                    for ch in 0..3 {
+                        assert_eq!(out.get_pixel(c, r, ch)?, rgb.get_pixel(c, r, ch)?);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn demosaic_invalid_size() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 1,
+            height: 4,
+        };
+        let raw = Image::<u16, 1>::from_size_val(size, 0)?;
+        let mut rgb = Image::<u16, 3>::from_size_val(size, 0)?;
+        assert!(super::rgb_from_bayer(
+            &raw,
+            &mut rgb,
+            BayerPattern::Bggr,
+            DemosaicMethod::Bilinear
+        )
+        .is_err());
+
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use crate::parallel;
-use kornia_image::{Image, ImageError};
+use kornia_image::{AsImageView, AsImageViewMut, ColorSpace, ImageError};
 
 /// Define the RGB weights for the grayscale conversion.
 const RW: f64 = 0.299;
@@ -41,10 +41,16 @@ const BW: f64 = 0.114;
 /// assert_eq!(gray.size().width, 4);
 /// assert_eq!(gray.size().height, 5);
 /// ```
-pub fn gray_from_rgb<T>(src: &Image<T, 3>, dst: &mut Image<T, 1>) -> Result<(), ImageError>
+pub fn gray_from_rgb<T>(
+    src: &impl AsImageView<T, 3>,
+    dst: &mut impl AsImageViewMut<T, 1>,
+) -> Result<(), ImageError>
 where
     T: Send + Sync + num_traits::Float,
 {
+    let src = src.as_view();
+    let mut dst = dst.as_view_mut();
+
     if src.size() != dst.size() {
         return Err(ImageError::InvalidImageSize(
             src.cols(),
@@ -54,18 +60,22 @@ where
         ));
     }
 
+    src.check_color_space(ColorSpace::Rgb)?;
+
     let rw = T::from(RW).ok_or(ImageError::CastError)?;
     let gw = T::from(GW).ok_or(ImageError::CastError)?;
     let bw = T::from(BW).ok_or(ImageError::CastError)?;
 
     // parallelize the grayscale conversion by rows
-    parallel::par_iter_rows(src, dst, |src_pixel, dst_pixel| {
+    parallel::par_iter_rows(&src, &mut dst, |src_pixel, dst_pixel| {
         let r = src_pixel[0];
         let g = src_pixel[1];
         let b = src_pixel[2];
         dst_pixel[0] = rw * r + gw * g + bw * b;
     });
 
+    dst.set_color_space(ColorSpace::Gray)?;
+
     Ok(())
 }
 
@@ -81,7 +91,13 @@ where
 /// Precondition: the input image must have 3 channels.
 /// Precondition: the output image must have 1 channel.
 /// Precondition: the input and output images must have the same size.
-pub fn gray_from_rgb_u8(src: &Image<u8, 3>, dst: &mut Image<u8, 1>) -> Result<(), ImageError> {
+pub fn gray_from_rgb_u8(
+    src: &impl AsImageView<u8, 3>,
+    dst: &mut impl AsImageViewMut<u8, 1>,
+) -> Result<(), ImageError> {
+    let src = src.as_view();
+    let mut dst = dst.as_view_mut();
+
     if src.size() != dst.size() {
         return Err(ImageError::InvalidImageSize(
             src.cols(),
@@ -91,13 +107,17 @@ pub fn gray_from_rgb_u8(src: &Image<u8, 3>, dst: &mut Image<u8, 1>) -> Result<()
         ));
     }
 
-    parallel::par_iter_rows(src, dst, |src_pixel, dst_pixel| {
+    src.check_color_space(ColorSpace::Rgb)?;
+
+    parallel::par_iter_rows(&src, &mut dst, |src_pixel, dst_pixel| {
         let r = src_pixel[0] as u16;
         let g = src_pixel[1] as u16;
         let b = src_pixel[2] as u16;
         dst_pixel[0] = ((r * 77 + g * 150 + b * 29) >> 8) as u8;
     });
 
+    dst.set_color_space(ColorSpace::Gray)?;
+
     Ok(())
 }
 
@@ -131,10 +151,16 @@ pub fn gray_from_rgb_u8(src: &Image<u8, 3>, dst: &mut Image<u8, 1>) -> Result<()
 ///
 /// rgb_from_gray(&image, &mut rgb).unwrap();
 /// ```
-pub fn rgb_from_gray<T>(src: &Image<T, 1>, dst: &mut Image<T, 3>) -> Result<(), ImageError>
+pub fn rgb_from_gray<T>(
+    src: &impl AsImageView<T, 1>,
+    dst: &mut impl AsImageViewMut<T, 3>,
+) -> Result<(), ImageError>
 where
     T: Copy + Send + Sync,
 {
+    let src = src.as_view();
+    let mut dst = dst.as_view_mut();
+
     if src.size() != dst.size() {
         return Err(ImageError::InvalidImageSize(
             src.cols(),
@@ -144,13 +170,17 @@ where
         ));
     }
 
+    src.check_color_space(ColorSpace::Gray)?;
+
     // parallelize the grayscale conversion by rows
-    parallel::par_iter_rows(src, dst, |src_pixel, dst_pixel| {
+    parallel::par_iter_rows(&src, &mut dst, |src_pixel, dst_pixel| {
         dst_pixel[0] = src_pixel[0];
         dst_pixel[1] = src_pixel[0];
         dst_pixel[2] = src_pixel[0];
     });
 
+    dst.set_color_space(ColorSpace::Rgb)?;
+
     Ok(())
 }
 
@@ -161,11 +191,20 @@ where
 /// * `src` - The input RGB image.
 /// * `dst` - The output BGR image.
 ///
+/// The conversion is symmetric, so an image tagged as [`ColorSpace::Bgr`] is converted back
+/// to RGB and the output is tagged as [`ColorSpace::Rgb`].
+///
 /// Precondition: the input and output images must have the same size.
-pub fn bgr_from_rgb<T>(src: &Image<T, 3>, dst: &mut Image<T, 3>) -> Result<(), ImageError>
+pub fn bgr_from_rgb<T>(
+    src: &impl AsImageView<T, 3>,
+    dst: &mut impl AsImageViewMut<T, 3>,
+) -> Result<(), ImageError>
 where
     T: Copy + Send + Sync,
 {
+    let src = src.as_view();
+    let mut dst = dst.as_view_mut();
+
     if src.size() != dst.size() {
         return Err(ImageError::InvalidImageSize(
             src.cols(),
@@ -175,7 +214,15 @@ where
         ));
     }
 
-    parallel::par_iter_rows(src, dst, |src_pixel, dst_pixel| {
+    let color_space = match src.color_space() {
+        ColorSpace::Bgr => ColorSpace::Rgb,
+        _ => {
+            src.check_color_space(ColorSpace::Rgb)?;
+            ColorSpace::Bgr
+        }
+    };
+
+    parallel::par_iter_rows(&src, &mut dst, |src_pixel, dst_pixel| {
         dst_pixel
             .iter_mut()
             .zip(src_pixel.iter().rev())
@@ -184,6 +231,8 @@ where
             });
     });
 
+    dst.set_color_space(color_space)?;
+
     Ok(())
 }
 
@@ -336,4 +385,41 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn color_space_propagation() -> Result<(), Box<dyn std::error::Error>> {
+        use kornia_image::{ColorSpace, ImageError};
+
+        let size = ImageSize {
+            width: 2,
+            height: 1,
+        };
+
+        let rgb = Image::<u8, 3>::from_size_val(size, 0)?.with_color_space(ColorSpace::Rgb)?;
+
+        let mut bgr = Image::<u8, 3>::from_size_val(size, 0)?;
+        super::bgr_from_rgb(&rgb, &mut bgr)?;
+        assert_eq!(bgr.color_space(), ColorSpace::Bgr);
+
+        let mut gray = Image::<u8, 1>::from_size_val(size, 0)?;
+        assert!(matches!(
+            super::gray_from_rgb_u8(&bgr, &mut gray),
+            Err(ImageError::ColorSpaceMismatch(
+                ColorSpace::Rgb,
+                ColorSpace::Bgr
+            ))
+        ));
+
+        let mut rgb2 = Image::<u8, 3>::from_size_val(size, 0)?;
+        super::bgr_from_rgb(&bgr, &mut rgb2)?;
+        assert_eq!(rgb2.color_space(), ColorSpace::Rgb);
+
+        super::gray_from_rgb_u8(&rgb2, &mut gray)?;
+        assert_eq!(gray.color_space(), ColorSpace::Gray);
+
+        super::rgb_from_gray(&gray, &mut rgb2)?;
+        assert_eq!(rgb2.color_space(), ColorSpace::Rgb);
+
+        Ok(())
+    }
 }
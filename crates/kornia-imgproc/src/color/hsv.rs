@@ -1,5 +1,5 @@
-use crate::parallel;
-use kornia_image::{Image, ImageError};
+use super::{convert_pixels, ColorChannel};
+use kornia_image::{AsImageView, AsImageViewMut, ColorSpace, ImageError};
 
 /// Convert an RGB image to an HSV image.
 ///
@@ -7,7 +7,7 @@ use kornia_image::{Image, ImageError};
 ///
 /// # Arguments
 ///
-/// * `src` - The input RGB image assumed to have 3 channels.
+/// * `src` - The input RGB image assumed to have 3 channels in the range [0, 255].
 /// * `dst` - The output HSV image.
 ///
 /// # Returns
@@ -45,22 +45,15 @@ use kornia_image::{Image, ImageError};
 /// assert_eq!(hsv.size().width, 4);
 /// assert_eq!(hsv.size().height, 5);
 /// ```
-pub fn hsv_from_rgb(src: &Image<f32, 3>, dst: &mut Image<f32, 3>) -> Result<(), ImageError> {
-    if src.size() != dst.size() {
-        return Err(ImageError::InvalidImageSize(
-            src.cols(),
-            src.rows(),
-            dst.cols(),
-            dst.rows(),
-        ));
-    }
-
-    // compute the HSV values
-    parallel::par_iter_rows(src, dst, |src_pixel, dst_pixel| {
+pub fn hsv_from_rgb<T: ColorChannel>(
+    src: &impl AsImageView<T, 3>,
+    dst: &mut impl AsImageViewMut<T, 3>,
+) -> Result<(), ImageError> {
+    convert_pixels(src, dst, ColorSpace::Rgb, ColorSpace::Hsv, |[r, g, b]| {
         // Normalize the input to the range [0, 1]
-        let r = src_pixel[0] / 255.;
-        let g = src_pixel[1] / 255.;
-        let b = src_pixel[2] / 255.;
+        let r = r / 255.;
+        let g = g / 255.;
+        let b = b / 255.;
 
         let max = r.max(g).max(b);
         let min = r.min(g).min(b);
@@ -92,12 +85,60 @@ pub fn hsv_from_rgb(src: &Image<f32, 3>, dst: &mut Image<f32, 3>) -> Result<(),
 
         let v = max * 255.0;
 
-        dst_pixel[0] = h;
-        dst_pixel[1] = s;
-        dst_pixel[2] = v;
-    });
+        [h, s, v]
+    })
+}
+
+/// Convert an HSV image to an RGB image.
+///
+/// This is the inverse of [`hsv_from_rgb`], the H, S and V channels are in the range [0, 255].
+///
+/// # Arguments
+///
+/// * `src` - The input HSV image.
+/// * `dst` - The output RGB image.
+///
+/// Precondition: the input and output images must have the same size.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::color::rgb_from_hsv;
+///
+/// let hsv = Image::<u8, 3>::new(ImageSize { width: 1, height: 1 }, vec![85, 255, 255]).unwrap();
+///
+/// let mut rgb = Image::<u8, 3>::from_size_val(hsv.size(), 0).unwrap();
+///
+/// rgb_from_hsv(&hsv, &mut rgb).unwrap();
+///
+/// assert_eq!(rgb.as_slice(), &[0, 255, 0]);
+/// ```
+pub fn rgb_from_hsv<T: ColorChannel>(
+    src: &impl AsImageView<T, 3>,
+    dst: &mut impl AsImageViewMut<T, 3>,
+) -> Result<(), ImageError> {
+    convert_pixels(src, dst, ColorSpace::Hsv, ColorSpace::Rgb, |[h, s, v]| {
+        // scale h to [0, 6) sectors of 60 degrees and s, v to [0, 1]
+        let h = (h / 255.0 * 6.0) % 6.0;
+        let s = s / 255.0;
+        let v = v / 255.0;
+
+        let c = v * s;
+        let x = c * (1.0 - ((h % 2.0) - 1.0).abs());
+        let m = v - c;
+
+        let (r, g, b) = match h as u32 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
 
-    Ok(())
+        [(r + m) * 255.0, (g + m) * 255.0, (b + m) * 255.0]
+    })
 }
 
 #[cfg(test)]
@@ -137,4 +178,42 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn rgb_from_hsv() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 3,
+            height: 2,
+        };
+        let rgb = Image::<u8, 3>::new(
+            size,
+            vec![
+                255, 0, 0, 0, 255, 0, 0, 0, 255, 10, 200, 90, 255, 255, 255, 30, 30, 30,
+            ],
+        )?;
+
+        let mut hsv = Image::<u8, 3>::from_size_val(size, 0)?;
+        super::hsv_from_rgb(&rgb, &mut hsv)?;
+        assert_eq!(hsv.get_pixel(0, 0, 0)?, &0);
+        assert_eq!(hsv.get_pixel(2, 0, 0)?, &170);
+        assert_eq!(hsv.get_pixel(1, 1, 1)?, &0);
+
+        let mut rgb2 = Image::<u8, 3>::from_size_val(size, 0)?;
+        super::rgb_from_hsv(&hsv, &mut rgb2)?;
+        for (a, b) in rgb.as_slice().iter().zip(rgb2.as_slice()) {
+            assert!(a.abs_diff(*b) <= 2, "{a} != {b}");
+        }
+
+        // f32 images round trip without quantization
+        let rgb = rgb.cast::<f32>()?;
+        let mut hsv = Image::<f32, 3>::from_size_val(size, 0.0)?;
+        let mut rgb2 = Image::<f32, 3>::from_size_val(size, 0.0)?;
+        super::hsv_from_rgb(&rgb, &mut hsv)?;
+        super::rgb_from_hsv(&hsv, &mut rgb2)?;
+        for (a, b) in rgb.as_slice().iter().zip(rgb2.as_slice()) {
+            assert!((a - b).pow(2) < 1e-6f32);
+        }
+
+        Ok(())
+    }
 }
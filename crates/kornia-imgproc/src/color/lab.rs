@@ -0,0 +1,148 @@
+use super::{
+    convert_pixels,
+    xyz::{linear_to_srgb, mat3_mul, srgb_to_linear, RGB_TO_XYZ, XYZ_TO_RGB},
+    ColorChannel,
+};
+use kornia_image::{AsImageView, AsImageViewMut, ColorSpace, ImageError};
+
+/// The D65 reference white point in the CIE XYZ color space.
+const WHITE_D65: [f32; 3] = [0.950456, 1.0, 1.088754];
+
+/// The threshold of the linear segment of the L*a*b* transfer function, (6/29)^3.
+const LAB_EPSILON: f32 = 0.008856;
+
+/// Convert an RGB image to the CIE L*a*b* color space.
+///
+/// The input is assumed to be sRGB with the D65 white point. The output is encoded as for
+/// 8-bit images, L is scaled from [0, 100] to [0, 255] and a, b are offset by 128.
+///
+/// # Arguments
+///
+/// * `src` - The input RGB image.
+/// * `dst` - The output Lab image.
+///
+/// Precondition: the input and output images must have the same size.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::color::lab_from_rgb;
+///
+/// let rgb = Image::<u8, 3>::new(ImageSize { width: 1, height: 1 }, vec![255, 255, 255]).unwrap();
+///
+/// let mut lab = Image::<u8, 3>::from_size_val(rgb.size(), 0).unwrap();
+///
+/// lab_from_rgb(&rgb, &mut lab).unwrap();
+///
+/// assert_eq!(lab.as_slice(), &[255, 128, 128]);
+/// ```
+pub fn lab_from_rgb<T: ColorChannel>(
+    src: &impl AsImageView<T, 3>,
+    dst: &mut impl AsImageViewMut<T, 3>,
+) -> Result<(), ImageError> {
+    convert_pixels(src, dst, ColorSpace::Rgb, ColorSpace::Lab, |rgb| {
+        let rgb = rgb.map(|c| srgb_to_linear(c / 255.0));
+        let xyz = mat3_mul(&RGB_TO_XYZ, rgb);
+
+        let [fx, fy, fz] = [0, 1, 2].map(|i| {
+            let t = xyz[i] / WHITE_D65[i];
+            if t > LAB_EPSILON {
+                t.cbrt()
+            } else {
+                7.787 * t + 16.0 / 116.0
+            }
+        });
+
+        let l = 116.0 * fy - 16.0;
+        let a = 500.0 * (fx - fy);
+        let b = 200.0 * (fy - fz);
+
+        [l * 255.0 / 100.0, a + 128.0, b + 128.0]
+    })
+}
+
+/// Convert a CIE L*a*b* image to the RGB color space.
+///
+/// This is the inverse of [`lab_from_rgb`], the input is encoded as for 8-bit images.
+///
+/// # Arguments
+///
+/// * `src` - The input Lab image.
+/// * `dst` - The output RGB image.
+///
+/// Precondition: the input and output images must have the same size.
+pub fn rgb_from_lab<T: ColorChannel>(
+    src: &impl AsImageView<T, 3>,
+    dst: &mut impl AsImageViewMut<T, 3>,
+) -> Result<(), ImageError> {
+    convert_pixels(src, dst, ColorSpace::Lab, ColorSpace::Rgb, |[l, a, b]| {
+        let l = l * 100.0 / 255.0;
+        let (a, b) = (a - 128.0, b - 128.0);
+
+        let fy = (l + 16.0) / 116.0;
+        let f = [fy + a / 500.0, fy, fy - b / 200.0];
+
+        let xyz = [0, 1, 2].map(|i| {
+            let t = f[i].powi(3);
+            let t = if t > LAB_EPSILON {
+                t
+            } else {
+                (f[i] - 16.0 / 116.0) / 7.787
+            };
+            t * WHITE_D65[i]
+        });
+
+        mat3_mul(&XYZ_TO_RGB, xyz).map(|c| linear_to_srgb(c.clamp(0.0, 1.0)) * 255.0)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    #[test]
+    fn lab_from_rgb() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 3,
+            height: 1,
+        };
+        let rgb = Image::<f32, 3>::new(
+            size,
+            vec![255.0, 0.0, 0.0, 0.0, 0.0, 0.0, 40.0, 180.0, 90.0],
+        )?;
+
+        let mut lab = Image::<f32, 3>::from_size_val(size, 0.0)?;
+        super::lab_from_rgb(&rgb, &mut lab)?;
+
+        // red is L=53.24, a=80.09, b=67.20
+        let expected = [53.24 * 2.55, 80.09 + 128.0, 67.20 + 128.0];
+        for (a, b) in lab.as_slice()[..3].iter().zip(expected) {
+            assert!((a - b).abs() < 0.1, "{a} != {b}");
+        }
+        assert_eq!(&lab.as_slice()[3..6], &[0.0, 128.0, 128.0]);
+
+        let mut rgb2 = Image::<f32, 3>::from_size_val(size, 0.0)?;
+        super::rgb_from_lab(&lab, &mut rgb2)?;
+        for (a, b) in rgb.as_slice().iter().zip(rgb2.as_slice()) {
+            assert!((a - b).abs() < 0.1, "{a} != {b}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn lab_from_rgb_u16() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 1,
+            height: 1,
+        };
+        let rgb = Image::<u16, 3>::new(size, vec![65535, 65535, 65535])?;
+
+        let mut lab = Image::<u16, 3>::from_size_val(size, 0)?;
+        super::lab_from_rgb(&rgb, &mut lab)?;
+        assert_eq!(lab.as_slice(), &[65535, 32896, 32896]);
+
+        Ok(())
+    }
+}
@@ -1,5 +1,102 @@
+//! Color space conversions.
+//!
+//! The conversions between 3-channel color spaces are implemented for `u8`, `u16` and `f32`
+//! images. The values are processed in the range of 8-bit images, so `f32` images are
+//! expected in the range [0, 255] and `u16` images are rescaled from [0, 65535].
+
+mod bayer;
 mod gray;
 mod hsv;
+mod lab;
+mod xyz;
+mod ycbcr;
+mod yuv;
 
+pub use bayer::{rgb_from_bayer, BayerPattern, DemosaicMethod};
 pub use gray::{bgr_from_rgb, gray_from_rgb, gray_from_rgb_u8, rgb_from_gray};
-pub use hsv::hsv_from_rgb;
+pub use hsv::{hsv_from_rgb, rgb_from_hsv};
+pub use lab::{lab_from_rgb, rgb_from_lab};
+pub use xyz::{rgb_from_xyz, xyz_from_rgb};
+pub use ycbcr::{rgb_from_ycbcr, ycbcr_from_rgb};
+pub use yuv::{rgb_from_i420, rgb_from_i422, rgb_from_nv12, rgb_from_nv16, rgb_from_yuyv};
+
+use crate::parallel;
+use kornia_image::{AsImageView, AsImageViewMut, ColorSpace, ImageError};
+
+/// A trait for the pixel types supported by the color conversions.
+pub trait ColorChannel: Copy + Send + Sync {
+    /// Convert the value to `f32` in the range [0, 255].
+    fn to_f32(self) -> f32;
+
+    /// Convert a value in the range [0, 255] back to the pixel type.
+    fn from_f32(value: f32) -> Self;
+}
+
+impl ColorChannel for u8 {
+    fn to_f32(self) -> f32 {
+        self as f32
+    }
+
+    fn from_f32(value: f32) -> Self {
+        value.round().clamp(0.0, 255.0) as u8
+    }
+}
+
+impl ColorChannel for u16 {
+    fn to_f32(self) -> f32 {
+        self as f32 / 257.0
+    }
+
+    fn from_f32(value: f32) -> Self {
+        (value * 257.0).round().clamp(0.0, 65535.0) as u16
+    }
+}
+
+impl ColorChannel for f32 {
+    fn to_f32(self) -> f32 {
+        self
+    }
+
+    fn from_f32(value: f32) -> Self {
+        value
+    }
+}
+
+/// Convert the pixels of a 3-channel image between two color spaces.
+///
+/// Checks the sizes and the color space of `src`, applies `f` to each pixel and tags `dst`.
+fn convert_pixels<T: ColorChannel>(
+    src: &impl AsImageView<T, 3>,
+    dst: &mut impl AsImageViewMut<T, 3>,
+    from: ColorSpace,
+    to: ColorSpace,
+    f: impl Fn([f32; 3]) -> [f32; 3] + Send + Sync,
+) -> Result<(), ImageError> {
+    let src = src.as_view();
+    let mut dst = dst.as_view_mut();
+
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    src.check_color_space(from)?;
+
+    parallel::par_iter_rows(&src, &mut dst, |src_pixel, dst_pixel| {
+        let out = f([
+            src_pixel[0].to_f32(),
+            src_pixel[1].to_f32(),
+            src_pixel[2].to_f32(),
+        ]);
+        dst_pixel
+            .iter_mut()
+            .zip(out)
+            .for_each(|(d, v)| *d = T::from_f32(v));
+    });
+
+    dst.set_color_space(to)
+}
@@ -0,0 +1,153 @@
+use super::{convert_pixels, ColorChannel};
+use kornia_image::{AsImageView, AsImageViewMut, ColorSpace, ImageError};
+
+/// The linear RGB to CIE XYZ matrix for the sRGB primaries and the D65 white point.
+pub(crate) const RGB_TO_XYZ: [[f32; 3]; 3] = [
+    [0.412453, 0.357580, 0.180423],
+    [0.212671, 0.715160, 0.072169],
+    [0.019334, 0.119193, 0.950227],
+];
+
+/// The CIE XYZ to linear RGB matrix, the inverse of [`RGB_TO_XYZ`].
+pub(crate) const XYZ_TO_RGB: [[f32; 3]; 3] = [
+    [3.240479, -1.53715, -0.498535],
+    [-0.969256, 1.875991, 0.041556],
+    [0.055648, -0.204043, 1.057311],
+];
+
+/// Multiply a 3x3 matrix by a vector.
+pub(crate) fn mat3_mul(m: &[[f32; 3]; 3], v: [f32; 3]) -> [f32; 3] {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
+/// Convert a gamma encoded sRGB value in [0, 1] to linear RGB.
+pub(crate) fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Convert a linear RGB value in [0, 1] to gamma encoded sRGB.
+pub(crate) fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.0031308 {
+        12.92 * c
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Convert an RGB image to the CIE XYZ color space.
+///
+/// The input is assumed to be gamma encoded sRGB with the D65 white point, as in
+/// [`lab_from_rgb`](super::lab_from_rgb). The output has the same scale as the input, so the
+/// Y channel of white is 255. The Z channel of white is 277.6, which saturates at 255 for
+/// 8-bit output images; use `f32` images to keep the full range.
+///
+/// # Arguments
+///
+/// * `src` - The input RGB image.
+/// * `dst` - The output XYZ image.
+///
+/// Precondition: the input and output images must have the same size.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::color::xyz_from_rgb;
+///
+/// let rgb = Image::<f32, 3>::new(ImageSize { width: 1, height: 1 }, vec![255.0; 3]).unwrap();
+///
+/// let mut xyz = Image::<f32, 3>::from_size_val(rgb.size(), 0.0).unwrap();
+///
+/// xyz_from_rgb(&rgb, &mut xyz).unwrap();
+///
+/// assert!((xyz.get_pixel(0, 0, 1).unwrap() - 255.0).abs() < 1e-3);
+/// ```
+pub fn xyz_from_rgb<T: ColorChannel>(
+    src: &impl AsImageView<T, 3>,
+    dst: &mut impl AsImageViewMut<T, 3>,
+) -> Result<(), ImageError> {
+    convert_pixels(src, dst, ColorSpace::Rgb, ColorSpace::Xyz, |rgb| {
+        let rgb = rgb.map(|c| srgb_to_linear(c / 255.0));
+        mat3_mul(&RGB_TO_XYZ, rgb).map(|c| c * 255.0)
+    })
+}
+
+/// Convert a CIE XYZ image to the RGB color space.
+///
+/// This is the inverse of [`xyz_from_rgb`].
+///
+/// # Arguments
+///
+/// * `src` - The input XYZ image.
+/// * `dst` - The output RGB image.
+///
+/// Precondition: the input and output images must have the same size.
+pub fn rgb_from_xyz<T: ColorChannel>(
+    src: &impl AsImageView<T, 3>,
+    dst: &mut impl AsImageViewMut<T, 3>,
+) -> Result<(), ImageError> {
+    convert_pixels(src, dst, ColorSpace::Xyz, ColorSpace::Rgb, |xyz| {
+        let xyz = xyz.map(|c| c / 255.0);
+        mat3_mul(&XYZ_TO_RGB, xyz).map(|c| linear_to_srgb(c.clamp(0.0, 1.0)) * 255.0)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    #[test]
+    fn xyz_roundtrip() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 2,
+            height: 2,
+        };
+        let rgb = Image::<f32, 3>::new(
+            size,
+            vec![
+                255.0, 0.0, 0.0, 0.0, 255.0, 0.0, 0.0, 0.0, 255.0, 12.0, 130.0, 250.0,
+            ],
+        )?;
+
+        let mut xyz = Image::<f32, 3>::from_size_val(size, 0.0)?;
+        super::xyz_from_rgb(&rgb, &mut xyz)?;
+
+        let expected_red = [105.175, 54.231, 4.930];
+        for (a, b) in xyz.as_slice()[..3].iter().zip(expected_red) {
+            assert!((a - b).abs() < 1e-2, "{a} != {b}");
+        }
+
+        let mut rgb2 = Image::<f32, 3>::from_size_val(size, 0.0)?;
+        super::rgb_from_xyz(&xyz, &mut rgb2)?;
+        for (a, b) in rgb.as_slice().iter().zip(rgb2.as_slice()) {
+            assert!((a - b).abs() < 1e-2, "{a} != {b}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn xyz_from_rgb_u8() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 2,
+            height: 1,
+        };
+        let rgb = Image::<u8, 3>::new(size, vec![255, 255, 255, 128, 128, 128])?;
+
+        let mut xyz = Image::<u8, 3>::from_size_val(size, 0)?;
+        super::xyz_from_rgb(&rgb, &mut xyz)?;
+
+        // the Z channel of white saturates, mid gray is linearized to 21.6% of white
+        assert_eq!(xyz.as_slice(), &[242, 255, 255, 52, 55, 60]);
+
+        Ok(())
+    }
+}
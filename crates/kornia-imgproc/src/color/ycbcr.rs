@@ -0,0 +1,106 @@
+use super::{convert_pixels, ColorChannel};
+use kornia_image::{AsImageView, AsImageViewMut, ColorSpace, ImageError};
+
+/// The offset of the chroma channels.
+const DELTA: f32 = 128.0;
+
+/// Convert an RGB image to the YCbCr color space.
+///
+/// Uses the full range BT.601 conversion of JPEG:
+///
+/// Y = 0.299 * R + 0.587 * G + 0.114 * B
+/// Cb = (B - Y) * 0.564 + 128
+/// Cr = (R - Y) * 0.713 + 128
+///
+/// # Arguments
+///
+/// * `src` - The input RGB image.
+/// * `dst` - The output YCbCr image.
+///
+/// Precondition: the input and output images must have the same size.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::color::ycbcr_from_rgb;
+///
+/// let rgb = Image::<u8, 3>::new(ImageSize { width: 1, height: 1 }, vec![128, 128, 128]).unwrap();
+///
+/// let mut ycbcr = Image::<u8, 3>::from_size_val(rgb.size(), 0).unwrap();
+///
+/// ycbcr_from_rgb(&rgb, &mut ycbcr).unwrap();
+///
+/// assert_eq!(ycbcr.as_slice(), &[128, 128, 128]);
+/// ```
+pub fn ycbcr_from_rgb<T: ColorChannel>(
+    src: &impl AsImageView<T, 3>,
+    dst: &mut impl AsImageViewMut<T, 3>,
+) -> Result<(), ImageError> {
+    convert_pixels(src, dst, ColorSpace::Rgb, ColorSpace::YCbCr, |[r, g, b]| {
+        let y = 0.299 * r + 0.587 * g + 0.114 * b;
+        let cb = (b - y) * 0.564 + DELTA;
+        let cr = (r - y) * 0.713 + DELTA;
+        [y, cb, cr]
+    })
+}
+
+/// Convert a YCbCr image to the RGB color space.
+///
+/// This is the inverse of [`ycbcr_from_rgb`]:
+///
+/// R = Y + 1.403 * (Cr - 128)
+/// G = Y - 0.714 * (Cr - 128) - 0.344 * (Cb - 128)
+/// B = Y + 1.773 * (Cb - 128)
+///
+/// # Arguments
+///
+/// * `src` - The input YCbCr image.
+/// * `dst` - The output RGB image.
+///
+/// Precondition: the input and output images must have the same size.
+pub fn rgb_from_ycbcr<T: ColorChannel>(
+    src: &impl AsImageView<T, 3>,
+    dst: &mut impl AsImageViewMut<T, 3>,
+) -> Result<(), ImageError> {
+    convert_pixels(
+        src,
+        dst,
+        ColorSpace::YCbCr,
+        ColorSpace::Rgb,
+        |[y, cb, cr]| {
+            let (cb, cr) = (cb - DELTA, cr - DELTA);
+            [y + 1.403 * cr, y - 0.714 * cr - 0.344 * cb, y + 1.773 * cb]
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use kornia_image::{ColorSpace, Image, ImageError, ImageSize};
+
+    #[test]
+    fn ycbcr_roundtrip() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 2,
+            height: 2,
+        };
+        let rgb = Image::<u8, 3>::new(size, vec![255, 0, 0, 0, 255, 0, 0, 0, 255, 20, 140, 230])?;
+
+        let mut ycbcr = Image::<u8, 3>::from_size_val(size, 0)?;
+        super::ycbcr_from_rgb(&rgb, &mut ycbcr)?;
+        assert_eq!(&ycbcr.as_slice()[..3], &[76, 85, 255]);
+        assert_eq!(ycbcr.color_space(), ColorSpace::YCbCr);
+
+        let mut rgb2 = Image::<u8, 3>::from_size_val(size, 0)?;
+        super::rgb_from_ycbcr(&ycbcr, &mut rgb2)?;
+        for (a, b) in rgb.as_slice().iter().zip(rgb2.as_slice()) {
+            assert!(a.abs_diff(*b) <= 2, "{a} != {b}");
+        }
+
+        // the input must be RGB
+        assert!(super::ycbcr_from_rgb(&ycbcr, &mut rgb2).is_err());
+
+        Ok(())
+    }
+}
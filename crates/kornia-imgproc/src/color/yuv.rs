@@ -0,0 +1,370 @@
+use super::ColorChannel;
+use crate::parallel;
+use kornia_image::{AsImageView, AsImageViewMut, ColorSpace, ImageError, ImageSize};
+
+/// Convert a limited range BT.601 YUV value to RGB in the range [0, 255].
+fn rgb_from_yuv_pixel(y: u8, u: u8, v: u8) -> [f32; 3] {
+    let c = 1.164383 * (y as f32 - 16.0);
+    let d = u as f32 - 128.0;
+    let e = v as f32 - 128.0;
+    [
+        c + 1.596027 * e,
+        c - 0.391762 * d - 0.812968 * e,
+        c + 2.017232 * d,
+    ]
+}
+
+/// Write an RGB value to a pixel of the output image.
+fn write_rgb<T: ColorChannel>(dst_pixel: &mut [T], rgb: [f32; 3]) {
+    dst_pixel
+        .iter_mut()
+        .zip(rgb)
+        .for_each(|(d, v)| *d = T::from_f32(v.clamp(0.0, 255.0)));
+}
+
+/// Check that a plane has the expected size.
+fn check_plane_size(plane: ImageSize, expected: ImageSize) -> Result<(), ImageError> {
+    if plane != expected {
+        return Err(ImageError::InvalidImageSize(
+            plane.width,
+            plane.height,
+            expected.width,
+            expected.height,
+        ));
+    }
+    Ok(())
+}
+
+/// Get the size of the chroma planes of an image subsampled horizontally and optionally
+/// vertically.
+fn chroma_size(size: ImageSize, vertical_subsampling: bool) -> ImageSize {
+    ImageSize {
+        width: size.width.div_ceil(2),
+        height: match vertical_subsampling {
+            true => size.height.div_ceil(2),
+            false => size.height,
+        },
+    }
+}
+
+/// Convert a YUV image with an interleaved chroma plane to RGB.
+fn rgb_from_semi_planar<T: ColorChannel>(
+    y: &impl AsImageView<u8, 1>,
+    uv: &impl AsImageView<u8, 2>,
+    dst: &mut impl AsImageViewMut<T, 3>,
+    vertical_subsampling: bool,
+) -> Result<(), ImageError> {
+    let (y, uv) = (y.as_view(), uv.as_view());
+    let mut dst = dst.as_view_mut();
+
+    check_plane_size(y.size(), dst.size())?;
+    check_plane_size(uv.size(), chroma_size(dst.size(), vertical_subsampling))?;
+
+    let chroma_shift = vertical_subsampling as usize;
+    parallel::par_iter_rows_indexed_mut(&mut dst, |r, dst_row| {
+        let (y_row, uv_row) = (y.row(r), uv.row(r >> chroma_shift));
+        dst_row
+            .chunks_exact_mut(3)
+            .zip(y_row)
+            .enumerate()
+            .for_each(|(x, (dst_pixel, &luma))| {
+                let uv = &uv_row[(x / 2) * 2..(x / 2) * 2 + 2];
+                write_rgb(dst_pixel, rgb_from_yuv_pixel(luma, uv[0], uv[1]));
+            });
+    });
+
+    dst.set_color_space(ColorSpace::Rgb)
+}
+
+/// Convert a YUV image with separate chroma planes to RGB.
+fn rgb_from_planar<T: ColorChannel>(
+    y: &impl AsImageView<u8, 1>,
+    u: &impl AsImageView<u8, 1>,
+    v: &impl AsImageView<u8, 1>,
+    dst: &mut impl AsImageViewMut<T, 3>,
+    vertical_subsampling: bool,
+) -> Result<(), ImageError> {
+    let (y, u, v) = (y.as_view(), u.as_view(), v.as_view());
+    let mut dst = dst.as_view_mut();
+
+    check_plane_size(y.size(), dst.size())?;
+    check_plane_size(u.size(), chroma_size(dst.size(), vertical_subsampling))?;
+    check_plane_size(v.size(), chroma_size(dst.size(), vertical_subsampling))?;
+
+    let chroma_shift = vertical_subsampling as usize;
+    parallel::par_iter_rows_indexed_mut(&mut dst, |r, dst_row| {
+        let y_row = y.row(r);
+        let (u_row, v_row) = (u.row(r >> chroma_shift), v.row(r >> chroma_shift));
+        dst_row
+            .chunks_exact_mut(3)
+            .zip(y_row)
+            .enumerate()
+            .for_each(|(x, (dst_pixel, &luma))| {
+                write_rgb(
+                    dst_pixel,
+                    rgb_from_yuv_pixel(luma, u_row[x / 2], v_row[x / 2]),
+                );
+            });
+    });
+
+    dst.set_color_space(ColorSpace::Rgb)
+}
+
+/// Convert an NV12 image to RGB.
+///
+/// NV12 is a semi-planar YUV 4:2:0 format: a full resolution Y plane followed by an
+/// interleaved UV plane subsampled by two in both directions. The values are assumed to be
+/// limited range BT.601, as output by most cameras.
+///
+/// # Arguments
+///
+/// * `y` - The luma plane with the size of the image.
+/// * `uv` - The interleaved chroma plane with half the width and height of the image, rounded up.
+/// * `dst` - The output RGB image.
+///
+/// # Errors
+///
+/// If the planes do not have the expected sizes, an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize, ImageView};
+/// use kornia_imgproc::color::rgb_from_nv12;
+///
+/// // a 4x2 frame with rows padded to 8 bytes
+/// let frame = vec![128u8; 8 * 3];
+/// let (y, uv) = frame.split_at(8 * 2);
+///
+/// let y = ImageView::<_, 1>::new(y, ImageSize { width: 4, height: 2 }, 8).unwrap();
+/// let uv = ImageView::<_, 2>::new(uv, ImageSize { width: 2, height: 1 }, 8).unwrap();
+///
+/// let mut rgb = Image::<u8, 3>::from_size_val(y.size(), 0).unwrap();
+///
+/// rgb_from_nv12(&y, &uv, &mut rgb).unwrap();
+///
+/// assert_eq!(rgb.get_pixel(3, 1, 0).unwrap(), &130);
+/// ```
+pub fn rgb_from_nv12<T: ColorChannel>(
+    y: &impl AsImageView<u8, 1>,
+    uv: &impl AsImageView<u8, 2>,
+    dst: &mut impl AsImageViewMut<T, 3>,
+) -> Result<(), ImageError> {
+    rgb_from_semi_planar(y, uv, dst, true)
+}
+
+/// Convert an NV16 image to RGB.
+///
+/// NV16 is a semi-planar YUV 4:2:2 format: a full resolution Y plane followed by an
+/// interleaved UV plane subsampled by two horizontally. The values are assumed to be limited
+/// range BT.601.
+///
+/// # Arguments
+///
+/// * `y` - The luma plane with the size of the image.
+/// * `uv` - The interleaved chroma plane with half the width of the image, rounded up.
+/// * `dst` - The output RGB image.
+///
+/// # Errors
+///
+/// If the planes do not have the expected sizes, an error is returned.
+pub fn rgb_from_nv16<T: ColorChannel>(
+    y: &impl AsImageView<u8, 1>,
+    uv: &impl AsImageView<u8, 2>,
+    dst: &mut impl AsImageViewMut<T, 3>,
+) -> Result<(), ImageError> {
+    rgb_from_semi_planar(y, uv, dst, false)
+}
+
+/// Convert an I420 image to RGB.
+///
+/// I420 is a planar YUV 4:2:0 format: a full resolution Y plane followed by U and V planes
+/// subsampled by two in both directions. The values are assumed to be limited range BT.601.
+///
+/// # Arguments
+///
+/// * `y` - The luma plane with the size of the image.
+/// * `u` - The U plane with half the width and height of the image, rounded up.
+/// * `v` - The V plane with half the width and height of the image, rounded up.
+/// * `dst` - The output RGB image.
+///
+/// # Errors
+///
+/// If the planes do not have the expected sizes, an error is returned.
+pub fn rgb_from_i420<T: ColorChannel>(
+    y: &impl AsImageView<u8, 1>,
+    u: &impl AsImageView<u8, 1>,
+    v: &impl AsImageView<u8, 1>,
+    dst: &mut impl AsImageViewMut<T, 3>,
+) -> Result<(), ImageError> {
+    rgb_from_planar(y, u, v, dst, true)
+}
+
+/// Convert an I422 image to RGB.
+///
+/// I422 is a planar YUV 4:2:2 format: a full resolution Y plane followed by U and V planes
+/// subsampled by two horizontally. The values are assumed to be limited range BT.601.
+///
+/// # Arguments
+///
+/// * `y` - The luma plane with the size of the image.
+/// * `u` - The U plane with half the width of the image, rounded up.
+/// * `v` - The V plane with half the width of the image, rounded up.
+/// * `dst` - The output RGB image.
+///
+/// # Errors
+///
+/// If the planes do not have the expected sizes, an error is returned.
+pub fn rgb_from_i422<T: ColorChannel>(
+    y: &impl AsImageView<u8, 1>,
+    u: &impl AsImageView<u8, 1>,
+    v: &impl AsImageView<u8, 1>,
+    dst: &mut impl AsImageViewMut<T, 3>,
+) -> Result<(), ImageError> {
+    rgb_from_planar(y, u, v, dst, false)
+}
+
+/// Convert a YUYV image to RGB.
+///
+/// YUYV (also known as YUY2) is a packed YUV 4:2:2 format where each pair of pixels is stored
+/// as `Y0 U Y1 V`. The values are assumed to be limited range BT.601.
+///
+/// # Arguments
+///
+/// * `src` - The packed image viewed as a 2-channel image with the size of the output image.
+/// * `dst` - The output RGB image.
+///
+/// # Errors
+///
+/// If the images do not have the same size or the width is odd, an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::color::rgb_from_yuyv;
+///
+/// let yuyv = Image::<u8, 2>::new(
+///     ImageSize { width: 2, height: 1 },
+///     vec![16, 128, 235, 128],
+/// ).unwrap();
+///
+/// let mut rgb = Image::<u8, 3>::from_size_val(yuyv.size(), 0).unwrap();
+///
+/// rgb_from_yuyv(&yuyv, &mut rgb).unwrap();
+///
+/// assert_eq!(rgb.as_slice(), &[0, 0, 0, 255, 255, 255]);
+/// ```
+pub fn rgb_from_yuyv<T: ColorChannel>(
+    src: &impl AsImageView<u8, 2>,
+    dst: &mut impl AsImageViewMut<T, 3>,
+) -> Result<(), ImageError> {
+    let src = src.as_view();
+    let mut dst = dst.as_view_mut();
+
+    check_plane_size(src.size(), dst.size())?;
+    if src.cols() % 2 != 0 {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            src.cols() + 1,
+            src.rows(),
+        ));
+    }
+
+    parallel::par_iter_rows_indexed_mut(&mut dst, |r, dst_row| {
+        dst_row
+            .chunks_exact_mut(6)
+            .zip(src.row(r).chunks_exact(4))
+            .for_each(|(dst_pixels, yuyv)| {
+                let (y0, u, y1, v) = (yuyv[0], yuyv[1], yuyv[2], yuyv[3]);
+                write_rgb(&mut dst_pixels[..3], rgb_from_yuv_pixel(y0, u, v));
+                write_rgb(&mut dst_pixels[3..], rgb_from_yuv_pixel(y1, u, v));
+            });
+    });
+
+    dst.set_color_space(ColorSpace::Rgb)
+}
+
+#[cfg(test)]
+mod tests {
+    use kornia_image::{ColorSpace, Image, ImageError, ImageSize, ImageView};
+
+    /// Encode an RGB value as limited range BT.601 YUV.
+    fn yuv_from_rgb(rgb: [f32; 3]) -> [u8; 3] {
+        let [r, g, b] = rgb;
+        let y = 16.0 + 0.256788 * r + 0.504129 * g + 0.097906 * b;
+        let u = 128.0 - 0.148223 * r - 0.290993 * g + 0.439216 * b;
+        let v = 128.0 + 0.439216 * r - 0.367788 * g - 0.071427 * b;
+        [y, u, v].map(|c| c.round() as u8)
+    }
+
+    #[test]
+    fn rgb_from_nv12_and_i420() -> Result<(), ImageError> {
+        // a 3x3 image with two colors in the left and right chroma blocks
+        let size = ImageSize {
+            width: 3,
+            height: 3,
+        };
+        let left = yuv_from_rgb([200.0, 40.0, 40.0]);
+        let right = yuv_from_rgb([20.0, 60.0, 220.0]);
+
+        let y_plane = [left[0], left[0], right[0]].repeat(3);
+        let uv_plane = [left[1], left[2], right[1], right[2]].repeat(2);
+        let u_plane = [left[1], right[1]].repeat(2);
+        let v_plane = [left[2], right[2]].repeat(2);
+
+        let chroma_size = ImageSize {
+            width: 2,
+            height: 2,
+        };
+        let y = ImageView::<_, 1>::new(&y_plane, size, 3)?;
+        let uv = ImageView::<_, 2>::new(&uv_plane, chroma_size, 4)?;
+        let u = ImageView::<_, 1>::new(&u_plane, chroma_size, 2)?;
+        let v = ImageView::<_, 1>::new(&v_plane, chroma_size, 2)?;
+
+        let mut rgb_nv12 = Image::<f32, 3>::from_size_val(size, 0.0)?;
+        super::rgb_from_nv12(&y, &uv, &mut rgb_nv12)?;
+        assert_eq!(rgb_nv12.color_space(), ColorSpace::Rgb);
+
+        let mut rgb_i420 = Image::<f32, 3>::from_size_val(size, 0.0)?;
+        super::rgb_from_i420(&y, &u, &v, &mut rgb_i420)?;
+        assert_eq!(rgb_nv12.as_slice(), rgb_i420.as_slice());
+
+        for (i, pixel) in rgb_nv12.as_slice().chunks_exact(3).enumerate() {
+            let expected = if i % 3 == 2 {
+                [20.0, 60.0, 220.0]
+            } else {
+                [200.0, 40.0, 40.0]
+            };
+            for (a, b) in pixel.iter().zip(expected) {
+                assert!((a - b).abs() < 2.0, "{a} != {b}");
+            }
+        }
+
+        // the chroma planes of 4:2:2 images have the full height
+        let mut rgb = Image::<u8, 3>::from_size_val(size, 0)?;
+        assert!(super::rgb_from_nv16(&y, &uv, &mut rgb).is_err());
+        assert!(super::rgb_from_i422(&y, &u, &v, &mut rgb).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn rgb_from_yuyv() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 4,
+            height: 1,
+        };
+        let [y0, u, v] = yuv_from_rgb([255.0, 0.0, 0.0]);
+        let [y1, ..] = yuv_from_rgb([0.0, 0.0, 0.0]);
+        let yuyv = Image::<u8, 2>::new(size, vec![y0, u, y0, v, y1, 128, y1, 128])?;
+
+        let mut rgb = Image::<u8, 3>::from_size_val(size, 0)?;
+        super::rgb_from_yuyv(&yuyv, &mut rgb)?;
+
+        assert_eq!(rgb.as_slice(), &[254, 0, 0, 254, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,356 @@
+use rayon::prelude::*;
+
+use kornia_image::{AsImageView, AsImageViewMut, ImageError, ImageView};
+
+use crate::parallel;
+
+/// The pixel neighbourhood used to connect the components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Pixels are connected to their horizontal and vertical neighbours.
+    Four,
+    /// Pixels are also connected to their diagonal neighbours.
+    Eight,
+}
+
+/// The statistics of a connected component.
+///
+/// # Fields
+///
+/// * `area` - The number of pixels of the component
+/// * `x` - The left coordinate of the bounding box
+/// * `y` - The top coordinate of the bounding box
+/// * `width` - The width of the bounding box
+/// * `height` - The height of the bounding box
+/// * `centroid` - The mean (x, y) coordinate of the pixels of the component
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComponentStats {
+    /// The number of pixels of the component
+    pub area: usize,
+    /// The left coordinate of the bounding box
+    pub x: usize,
+    /// The top coordinate of the bounding box
+    pub y: usize,
+    /// The width of the bounding box
+    pub width: usize,
+    /// The height of the bounding box
+    pub height: usize,
+    /// The mean (x, y) coordinate of the pixels of the component
+    pub centroid: [f64; 2],
+}
+
+/// Marks the background pixels in the union-find forest.
+const BACKGROUND: usize = usize::MAX;
+
+/// Label the connected components of a binary image.
+///
+/// The non-zero pixels are the foreground. The background is labeled 0 and the components
+/// are labeled from 1 in the raster order of their first pixel.
+///
+/// The image is split in strips of rows that are labeled in parallel with a union-find,
+/// then the components touching the strip borders are merged.
+///
+/// # Arguments
+///
+/// * `src` - The input binary image.
+/// * `labels` - The output label image.
+/// * `connectivity` - The pixel neighbourhood.
+///
+/// # Returns
+///
+/// The statistics of the components, where the element `i` describes the label `i + 1`.
+///
+/// # Errors
+///
+/// If the images do not have the same size, an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::connected_components::{connected_components, Connectivity};
+///
+/// let mask = Image::<u8, 1>::new(
+///     ImageSize { width: 4, height: 2 },
+///     vec![255, 0, 0, 255, 255, 0, 255, 255],
+/// ).unwrap();
+///
+/// let mut labels = Image::<u32, 1>::from_size_val(mask.size(), 0).unwrap();
+///
+/// let stats = connected_components(&mask, &mut labels, Connectivity::Four).unwrap();
+///
+/// assert_eq!(labels.as_slice(), &[1, 0, 0, 2, 1, 0, 2, 2]);
+/// assert_eq!(stats.len(), 2);
+/// assert_eq!(stats[1].area, 3);
+/// ```
+pub fn connected_components(
+    src: &impl AsImageView<u8, 1>,
+    labels: &mut impl AsImageViewMut<u32, 1>,
+    connectivity: Connectivity,
+) -> Result<Vec<ComponentStats>, ImageError> {
+    let src = src.as_view();
+    let strip_rows = src.rows().div_ceil(rayon::current_num_threads()).max(1);
+    label_strips(&src, labels, connectivity, strip_rows)
+}
+
+fn label_strips(
+    src: &ImageView<u8, 1>,
+    labels: &mut impl AsImageViewMut<u32, 1>,
+    connectivity: Connectivity,
+    strip_rows: usize,
+) -> Result<Vec<ComponentStats>, ImageError> {
+    let mut labels = labels.as_view_mut();
+
+    if src.size() != labels.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            labels.cols(),
+            labels.rows(),
+        ));
+    }
+
+    let (rows, cols) = (src.rows(), src.cols());
+    if rows == 0 || cols == 0 {
+        return Ok(Vec::new());
+    }
+
+    // the parent of each pixel in the union-find forest, indexed by the pixel position
+    let mut parent = vec![BACKGROUND; rows * cols];
+
+    // label each strip independently, its pixels only point to pixels of the same strip
+    parent
+        .par_chunks_mut(strip_rows * cols)
+        .enumerate()
+        .for_each(|(k, strip)| {
+            let offset = k * strip_rows * cols;
+            let r0 = k * strip_rows;
+            for r in r0..r0 + strip.len() / cols {
+                let row = src.row(r);
+                for (c, &v) in row.iter().enumerate() {
+                    if v == 0 {
+                        continue;
+                    }
+                    let i = r * cols + c;
+                    strip[i - offset] = i;
+                    for j in neighbours(r, c, cols, connectivity) {
+                        if j >= offset && strip[j - offset] != BACKGROUND {
+                            union(strip, offset, i, j);
+                        }
+                    }
+                }
+            }
+        });
+
+    // merge the components across the strip borders
+    for r in (strip_rows..rows).step_by(strip_rows) {
+        for c in 0..cols {
+            let i = r * cols + c;
+            if parent[i] == BACKGROUND {
+                continue;
+            }
+            for j in neighbours(r, c, cols, connectivity) {
+                if j < r * cols && parent[j] != BACKGROUND {
+                    union(&mut parent, 0, i, j);
+                }
+            }
+        }
+    }
+
+    // the roots are the first pixel of each component, assign them consecutive labels
+    let mut stats = Vec::new();
+    let mut root_labels = vec![0u32; rows * cols];
+    for i in 0..rows * cols {
+        if parent[i] == BACKGROUND {
+            continue;
+        }
+        let root = find(&mut parent, 0, i);
+        if root == i {
+            stats.push(ComponentStats {
+                area: 0,
+                x: usize::MAX,
+                y: usize::MAX,
+                width: 0,
+                height: 0,
+                centroid: [0.0, 0.0],
+            });
+            root_labels[i] = stats.len() as u32;
+        } else {
+            parent[i] = root;
+        }
+    }
+
+    // write the labels, every foreground pixel points now directly to its root
+    parallel::par_iter_rows_indexed_mut(&mut labels, |r, labels_row| {
+        labels_row.iter_mut().enumerate().for_each(|(c, label)| {
+            *label = match parent[r * cols + c] {
+                BACKGROUND => 0,
+                root => root_labels[root],
+            };
+        });
+    });
+
+    // accumulate the statistics, the bounding box is stored as (min, max) until the end
+    for (r, row) in labels.as_view().row_iter().enumerate() {
+        for (c, &label) in row.iter().enumerate() {
+            if label == 0 {
+                continue;
+            }
+            let s = &mut stats[label as usize - 1];
+            s.area += 1;
+            s.x = s.x.min(c);
+            s.y = s.y.min(r);
+            s.width = s.width.max(c);
+            s.height = s.height.max(r);
+            s.centroid[0] += c as f64;
+            s.centroid[1] += r as f64;
+        }
+    }
+
+    stats.iter_mut().for_each(|s| {
+        s.width = s.width - s.x + 1;
+        s.height = s.height - s.y + 1;
+        s.centroid[0] /= s.area as f64;
+        s.centroid[1] /= s.area as f64;
+    });
+
+    Ok(stats)
+}
+
+/// Get the indices of the already visited neighbours of a pixel in raster order.
+fn neighbours(
+    r: usize,
+    c: usize,
+    cols: usize,
+    connectivity: Connectivity,
+) -> impl Iterator<Item = usize> {
+    let i = r * cols + c;
+    let left = (c > 0).then(|| i - 1);
+    let up = (r > 0).then(|| i - cols);
+    let (up_left, up_right) = match connectivity {
+        Connectivity::Four => (None, None),
+        Connectivity::Eight => (
+            (r > 0 && c > 0).then(|| i - cols - 1),
+            (r > 0 && c + 1 < cols).then(|| i - cols + 1),
+        ),
+    };
+    [left, up_left, up, up_right].into_iter().flatten()
+}
+
+/// Find the root of a pixel, compressing the path on the way.
+///
+/// The `parent` slice holds the pixels starting at the index `offset`.
+fn find(parent: &mut [usize], offset: usize, mut i: usize) -> usize {
+    while parent[i - offset] != i {
+        let grandparent = parent[parent[i - offset] - offset];
+        parent[i - offset] = grandparent;
+        i = grandparent;
+    }
+    i
+}
+
+/// Merge the components of two pixels, keeping the smallest index as the root.
+fn union(parent: &mut [usize], offset: usize, i: usize, j: usize) {
+    let (ri, rj) = (find(parent, offset, i), find(parent, offset, j));
+    match ri.cmp(&rj) {
+        std::cmp::Ordering::Less => parent[rj - offset] = ri,
+        std::cmp::Ordering::Greater => parent[ri - offset] = rj,
+        std::cmp::Ordering::Equal => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ComponentStats, Connectivity};
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    fn mask(width: usize, height: usize, data: &[u8]) -> Result<Image<u8, 1>, ImageError> {
+        Image::new(
+            ImageSize { width, height },
+            data.iter().map(|&v| v * 255).collect(),
+        )
+    }
+
+    #[test]
+    fn connectivity_four_eight() -> Result<(), ImageError> {
+        #[rustfmt::skip]
+        let src = mask(5, 3, &[
+            1, 0, 0, 0, 1,
+            0, 1, 0, 0, 1,
+            0, 0, 1, 0, 0,
+        ])?;
+        let mut labels = Image::<u32, 1>::from_size_val(src.size(), 0)?;
+
+        let stats = super::connected_components(&src, &mut labels, Connectivity::Four)?;
+        assert_eq!(stats.len(), 4);
+        #[rustfmt::skip]
+        assert_eq!(labels.as_slice(), &[
+            1, 0, 0, 0, 2,
+            0, 3, 0, 0, 2,
+            0, 0, 4, 0, 0,
+        ]);
+
+        let stats = super::connected_components(&src, &mut labels, Connectivity::Eight)?;
+        #[rustfmt::skip]
+        assert_eq!(labels.as_slice(), &[
+            1, 0, 0, 0, 2,
+            0, 1, 0, 0, 2,
+            0, 0, 1, 0, 0,
+        ]);
+        assert_eq!(
+            stats,
+            vec![
+                ComponentStats {
+                    area: 3,
+                    x: 0,
+                    y: 0,
+                    width: 3,
+                    height: 3,
+                    centroid: [1.0, 1.0],
+                },
+                ComponentStats {
+                    area: 2,
+                    x: 4,
+                    y: 0,
+                    width: 1,
+                    height: 2,
+                    centroid: [4.0, 0.5],
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn merge_strips() -> Result<(), ImageError> {
+        // a spiral crossing every strip border many times
+        #[rustfmt::skip]
+        let src = mask(7, 7, &[
+            1, 1, 1, 1, 1, 1, 1,
+            0, 0, 0, 0, 0, 0, 1,
+            1, 1, 1, 1, 1, 0, 1,
+            1, 0, 0, 0, 1, 0, 1,
+            1, 0, 1, 1, 1, 0, 1,
+            1, 0, 0, 0, 0, 0, 1,
+            1, 1, 1, 1, 1, 1, 1,
+        ])?;
+
+        for connectivity in [Connectivity::Four, Connectivity::Eight] {
+            let mut expected = Image::<u32, 1>::from_size_val(src.size(), 0)?;
+            let stats = super::label_strips(&src.view(), &mut expected, connectivity, src.rows())?;
+            assert_eq!(stats.len(), 1);
+            assert_eq!(stats[0].area, 31);
+
+            for strip_rows in 1..src.rows() {
+                let mut labels = Image::<u32, 1>::from_size_val(src.size(), 0)?;
+                let strip_stats =
+                    super::label_strips(&src.view(), &mut labels, connectivity, strip_rows)?;
+                assert_eq!(labels.as_slice(), expected.as_slice());
+                assert_eq!(strip_stats, stats);
+            }
+        }
+
+        Ok(())
+    }
+}
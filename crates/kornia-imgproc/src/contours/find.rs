@@ -0,0 +1,268 @@
+use kornia_image::AsImageView;
+
+/// The 8 neighbours of a pixel as (row, col) offsets, clockwise starting from the east.
+const NEIGHBOURS: [(isize, isize); 8] = [
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+];
+
+/// A border of a binary image.
+///
+/// # Fields
+///
+/// * `points` - The (x, y) coordinates of the border pixels in the order they are followed
+/// * `is_hole` - Whether the border surrounds a hole instead of a component
+/// * `parent` - The index of the border enclosing this one, if any
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Contour {
+    /// The (x, y) coordinates of the border pixels in the order they are followed
+    pub points: Vec<[i32; 2]>,
+    /// Whether the border surrounds a hole instead of a component
+    pub is_hole: bool,
+    /// The index of the border enclosing this one, if any
+    pub parent: Option<usize>,
+}
+
+/// Find the borders of a binary image and their hierarchy.
+///
+/// Implements the border following algorithm of Suzuki and Abe, "Topological structural
+/// analysis of digitized binary images by border following", CVGIP 1985. The components
+/// are 8-connected and the holes 4-connected.
+///
+/// The outer borders of the components without a parent are the external contours. The
+/// holes have the outer border of their component as parent, and the components inside a
+/// hole have that hole as parent.
+///
+/// # Arguments
+///
+/// * `src` - The input binary image, where the non-zero pixels are the foreground.
+///
+/// # Returns
+///
+/// The borders in the raster order of their first pixel.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::contours::find_contours;
+///
+/// let image = Image::<u8, 1>::new(
+///     ImageSize { width: 5, height: 5 },
+///     vec![
+///         0, 0, 0, 0, 0,
+///         0, 1, 1, 1, 0,
+///         0, 1, 0, 1, 0,
+///         0, 1, 1, 1, 0,
+///         0, 0, 0, 0, 0,
+///     ],
+/// ).unwrap();
+///
+/// let contours = find_contours(&image);
+///
+/// assert_eq!(contours.len(), 2);
+/// assert_eq!(contours[0].points.len(), 8);
+/// assert!(contours[1].is_hole);
+/// assert_eq!(contours[1].parent, Some(0));
+/// ```
+pub fn find_contours(src: &impl AsImageView<u8, 1>) -> Vec<Contour> {
+    let src = src.as_view();
+
+    // label map with a frame of background pixels, so that the neighbours are always valid
+    let (h, w) = (src.rows() + 2, src.cols() + 2);
+    let mut f = vec![0i32; h * w];
+    for (r, row) in src.row_iter().enumerate() {
+        for (c, &v) in row.iter().enumerate() {
+            if v != 0 {
+                f[(r + 1) * w + c + 1] = 1;
+            }
+        }
+    }
+
+    let mut contours: Vec<Contour> = Vec::new();
+
+    // the frame is the border 1, the border n is stored at the index n - 2
+    let mut nbd = 1;
+
+    for i in 1..h - 1 {
+        let mut lnbd = 1;
+        for j in 1..w - 1 {
+            let p = i * w + j;
+
+            // the pixel where the border starts and the background pixel next to it
+            let start = if f[p] == 1 && f[p - 1] == 0 {
+                Some((false, p - 1))
+            } else if f[p] >= 1 && f[p + 1] == 0 {
+                if f[p] > 1 {
+                    lnbd = f[p];
+                }
+                Some((true, p + 1))
+            } else {
+                None
+            };
+
+            if let Some((is_hole, background)) = start {
+                nbd += 1;
+
+                // the parent depends on the type of the last border met in this row
+                let (lnbd_is_hole, lnbd_parent) = match lnbd {
+                    1 => (true, None),
+                    n => {
+                        let last = &contours[n as usize - 2];
+                        (last.is_hole, last.parent)
+                    }
+                };
+                let parent = match is_hole == lnbd_is_hole {
+                    true => lnbd_parent,
+                    false => (lnbd > 1).then(|| lnbd as usize - 2),
+                };
+
+                let points = follow_border(&mut f, w, p, background, nbd);
+                contours.push(Contour {
+                    points,
+                    is_hole,
+                    parent,
+                });
+            }
+
+            if f[p] != 0 && f[p] != 1 {
+                lnbd = f[p].abs();
+            }
+        }
+    }
+
+    contours
+}
+
+/// Follow a border from its first pixel, marking its pixels with the border number.
+fn follow_border(f: &mut [i32], w: usize, p0: usize, background: usize, nbd: i32) -> Vec<[i32; 2]> {
+    let offsets = NEIGHBOURS.map(|(di, dj)| di * w as isize + dj);
+    let neighbour = |p: usize, k: usize| (p as isize + offsets[k]) as usize;
+    let direction = |from: usize, to: usize| {
+        offsets
+            .iter()
+            .position(|&o| from as isize + o == to as isize)
+            .unwrap_or(0)
+    };
+    let point = |p: usize| [(p % w) as i32 - 1, (p / w) as i32 - 1];
+
+    // search clockwise for the last pixel of the border
+    let k0 = direction(p0, background);
+    let Some(p1) = (0..8)
+        .map(|n| neighbour(p0, (k0 + n) % 8))
+        .find(|&q| f[q] != 0)
+    else {
+        // isolated pixel
+        f[p0] = -nbd;
+        return vec![point(p0)];
+    };
+
+    let mut points = Vec::new();
+    let (mut p2, mut p3) = (p1, p0);
+    loop {
+        points.push(point(p3));
+
+        // search counter-clockwise for the next pixel of the border
+        let k2 = direction(p3, p2);
+        let mut p4 = p2;
+        let mut east_is_background = false;
+        for n in 1..=8 {
+            let k = (k2 + 8 - n) % 8;
+            let q = neighbour(p3, k);
+            if f[q] != 0 {
+                p4 = q;
+                break;
+            }
+            if k == 0 {
+                east_is_background = true;
+            }
+        }
+
+        if east_is_background {
+            f[p3] = -nbd;
+        } else if f[p3] == 1 {
+            f[p3] = nbd;
+        }
+
+        if p4 == p0 && p3 == p1 {
+            break;
+        }
+
+        (p2, p3) = (p3, p4);
+    }
+
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    #[test]
+    fn find_contours_square() -> Result<(), ImageError> {
+        #[rustfmt::skip]
+        let image = Image::<u8, 1>::new(
+            ImageSize { width: 4, height: 4 },
+            vec![
+                0, 0, 0, 0,
+                0, 9, 9, 0,
+                0, 9, 9, 0,
+                0, 0, 0, 0,
+            ],
+        )?;
+
+        let contours = super::find_contours(&image);
+        assert_eq!(contours.len(), 1);
+        assert_eq!(contours[0].points, vec![[1, 1], [1, 2], [2, 2], [2, 1]]);
+        assert!(!contours[0].is_hole);
+        assert_eq!(contours[0].parent, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn find_contours_hierarchy() -> Result<(), ImageError> {
+        // a ring with a dot inside, a separate line and an isolated pixel
+        #[rustfmt::skip]
+        let image = Image::<u8, 1>::new(
+            ImageSize { width: 9, height: 7 },
+            vec![
+                1, 1, 1, 1, 1, 0, 0, 1, 0,
+                1, 0, 0, 0, 1, 0, 0, 1, 0,
+                1, 0, 1, 0, 1, 0, 0, 1, 0,
+                1, 0, 0, 0, 1, 0, 0, 0, 0,
+                1, 1, 1, 1, 1, 0, 0, 0, 0,
+                0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 0, 0, 0, 0, 0, 1,
+            ],
+        )?;
+
+        let contours = super::find_contours(&image);
+        let summary = contours
+            .iter()
+            .map(|c| (c.points.len(), c.is_hole, c.parent))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            summary,
+            vec![
+                (16, false, None),
+                (4, false, None),
+                (12, true, Some(0)),
+                (1, false, Some(2)),
+                (1, false, None),
+            ]
+        );
+
+        // a line is followed forth and back
+        assert_eq!(contours[1].points, vec![[7, 0], [7, 1], [7, 2], [7, 1]]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,12 @@
+//! Contour operations
+//!
+//! This module provides the border following of binary images and the descriptors of the
+//! resulting shapes.
+
+/// Border following
+mod find;
+pub use find::*;
+
+/// Shape descriptors
+mod shape;
+pub use shape::*;
@@ -0,0 +1,710 @@
+/// A rectangle rotated around its center.
+///
+/// # Fields
+///
+/// * `center` - The (x, y) coordinates of the center
+/// * `size` - The (width, height) of the rectangle
+/// * `angle` - The angle of the width side with respect to the x axis in degrees
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RotatedRect {
+    /// The (x, y) coordinates of the center
+    pub center: [f64; 2],
+    /// The (width, height) of the rectangle
+    pub size: [f64; 2],
+    /// The angle of the width side with respect to the x axis in degrees
+    pub angle: f64,
+}
+
+/// The moments of a polygon up to the third order.
+///
+/// The spatial moments are `m_pq`, the central moments `mu_pq` and the scale invariant
+/// normalized central moments `nu_pq`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[allow(missing_docs)]
+pub struct Moments {
+    pub m00: f64,
+    pub m10: f64,
+    pub m01: f64,
+    pub m20: f64,
+    pub m11: f64,
+    pub m02: f64,
+    pub m30: f64,
+    pub m21: f64,
+    pub m12: f64,
+    pub m03: f64,
+    pub mu20: f64,
+    pub mu11: f64,
+    pub mu02: f64,
+    pub mu30: f64,
+    pub mu21: f64,
+    pub mu12: f64,
+    pub mu03: f64,
+    pub nu20: f64,
+    pub nu11: f64,
+    pub nu02: f64,
+    pub nu30: f64,
+    pub nu21: f64,
+    pub nu12: f64,
+    pub nu03: f64,
+}
+
+impl Moments {
+    /// Get the (x, y) centroid of the polygon, if it has a non-zero area.
+    pub fn centroid(&self) -> Option<[f64; 2]> {
+        (self.m00 != 0.0).then(|| [self.m10 / self.m00, self.m01 / self.m00])
+    }
+}
+
+fn to_f64<T: Copy + Into<f64>>(p: &[T; 2]) -> [f64; 2] {
+    [p[0].into(), p[1].into()]
+}
+
+fn cross(o: [f64; 2], a: [f64; 2], b: [f64; 2]) -> f64 {
+    (a[0] - o[0]) * (b[1] - o[1]) - (a[1] - o[1]) * (b[0] - o[0])
+}
+
+fn distance(a: [f64; 2], b: [f64; 2]) -> f64 {
+    (a[0] - b[0]).hypot(a[1] - b[1])
+}
+
+/// Compute the signed area of a polygon with the shoelace formula.
+fn signed_area<T: Copy + Into<f64>>(points: &[[T; 2]]) -> f64 {
+    let n = points.len();
+    (0..n)
+        .map(|i| {
+            let (a, b) = (to_f64(&points[i]), to_f64(&points[(i + 1) % n]));
+            a[0] * b[1] - b[0] * a[1]
+        })
+        .sum::<f64>()
+        / 2.0
+}
+
+/// Compute the area of a closed polygon.
+///
+/// # Arguments
+///
+/// * `points` - The (x, y) vertices of the polygon.
+///
+/// # Returns
+///
+/// The area enclosed by the polygon. Self-intersecting polygons get the difference of the
+/// areas with opposite orientations.
+pub fn contour_area<T: Copy + Into<f64>>(points: &[[T; 2]]) -> f64 {
+    signed_area(points).abs()
+}
+
+/// Compute the length of a polygonal curve.
+///
+/// # Arguments
+///
+/// * `points` - The (x, y) vertices of the curve.
+/// * `closed` - Whether to include the segment from the last to the first vertex.
+pub fn contour_perimeter<T: Copy + Into<f64>>(points: &[[T; 2]], closed: bool) -> f64 {
+    let open = points
+        .windows(2)
+        .map(|w| distance(to_f64(&w[0]), to_f64(&w[1])))
+        .sum::<f64>();
+    match (closed, points.first(), points.last()) {
+        (true, Some(first), Some(last)) => open + distance(to_f64(last), to_f64(first)),
+        _ => open,
+    }
+}
+
+/// Approximate a polygonal curve with fewer vertices using the Douglas-Peucker algorithm.
+///
+/// # Arguments
+///
+/// * `points` - The (x, y) vertices of the curve.
+/// * `epsilon` - The maximum distance between the curve and its approximation.
+/// * `closed` - Whether the curve is closed.
+///
+/// # Returns
+///
+/// The subset of the vertices kept in the approximation.
+///
+/// # Example
+///
+/// ```
+/// use kornia_imgproc::contours::approx_poly;
+///
+/// let points = [[0, 0], [5, 1], [10, 0], [10, 10], [0, 10]];
+///
+/// assert_eq!(approx_poly(&points, 2.0, true), vec![[0, 0], [10, 0], [10, 10], [0, 10]]);
+/// ```
+pub fn approx_poly<T: Copy + Into<f64>>(
+    points: &[[T; 2]],
+    epsilon: f64,
+    closed: bool,
+) -> Vec<[T; 2]> {
+    let n = points.len();
+    if n < 3 {
+        return points.to_vec();
+    }
+
+    let mut pts = points.iter().map(to_f64).collect::<Vec<_>>();
+    let mut keep = vec![false; n + 1];
+    keep[0] = true;
+
+    if closed {
+        // split the curve at the vertex farthest from the first one and close it
+        let far = (1..n)
+            .max_by(|&a, &b| distance(pts[0], pts[a]).total_cmp(&distance(pts[0], pts[b])))
+            .unwrap_or(1);
+        pts.push(pts[0]);
+        keep[far] = true;
+        douglas_peucker(&pts, 0, far, epsilon, &mut keep);
+        douglas_peucker(&pts, far, n, epsilon, &mut keep);
+    } else {
+        keep[n - 1] = true;
+        douglas_peucker(&pts, 0, n - 1, epsilon, &mut keep);
+    }
+
+    points
+        .iter()
+        .zip(keep)
+        .filter_map(|(p, k)| k.then_some(*p))
+        .collect()
+}
+
+/// Mark the vertices between `first` and `last` needed to keep the curve within epsilon.
+fn douglas_peucker(pts: &[[f64; 2]], first: usize, last: usize, epsilon: f64, keep: &mut [bool]) {
+    let mut stack = vec![(first, last)];
+    while let Some((a, b)) = stack.pop() {
+        if b <= a + 1 {
+            continue;
+        }
+
+        let (pa, pb) = (pts[a], pts[b]);
+        let length = distance(pa, pb);
+        let (k, dmax) = (a + 1..b)
+            .map(|k| {
+                // distance to the line through the ends, or to the end if they coincide
+                let d = match length > 0.0 {
+                    true => cross(pa, pb, pts[k]).abs() / length,
+                    false => distance(pa, pts[k]),
+                };
+                (k, d)
+            })
+            .fold(
+                (a, -1.0),
+                |best, cur| if cur.1 > best.1 { cur } else { best },
+            );
+
+        if dmax > epsilon {
+            keep[k] = true;
+            stack.push((a, k));
+            stack.push((k, b));
+        }
+    }
+}
+
+/// Compute the convex hull of a set of points with the monotone chain algorithm.
+///
+/// # Arguments
+///
+/// * `points` - The (x, y) points.
+///
+/// # Returns
+///
+/// The vertices of the hull without collinear points, ordered with a positive signed area,
+/// i.e. clockwise in image coordinates where the y axis points down.
+///
+/// # Example
+///
+/// ```
+/// use kornia_imgproc::contours::convex_hull;
+///
+/// let points = [[0, 0], [2, 1], [4, 0], [4, 4], [1, 2], [0, 4]];
+///
+/// assert_eq!(convex_hull(&points), vec![[0, 0], [4, 0], [4, 4], [0, 4]]);
+/// ```
+pub fn convex_hull<T: Copy + Into<f64>>(points: &[[T; 2]]) -> Vec<[T; 2]> {
+    let pts = points.iter().map(to_f64).collect::<Vec<_>>();
+    let mut order = (0..pts.len()).collect::<Vec<_>>();
+    order.sort_by(|&a, &b| {
+        pts[a][0]
+            .total_cmp(&pts[b][0])
+            .then(pts[a][1].total_cmp(&pts[b][1]))
+    });
+    order.dedup_by(|a, b| pts[*a] == pts[*b]);
+
+    if order.len() < 3 {
+        return order.iter().map(|&i| points[i]).collect();
+    }
+
+    // build the lower and upper chains, dropping the points that do not turn left
+    let mut hull: Vec<usize> = Vec::with_capacity(2 * order.len());
+    for pass in [order.clone(), order.iter().rev().copied().collect()] {
+        let start = hull.len();
+        for i in pass {
+            while hull.len() >= start + 2
+                && cross(pts[hull[hull.len() - 2]], pts[hull[hull.len() - 1]], pts[i]) <= 0.0
+            {
+                hull.pop();
+            }
+            hull.push(i);
+        }
+        // the last point of a chain is the first of the other
+        hull.pop();
+    }
+
+    hull.iter().map(|&i| points[i]).collect()
+}
+
+/// Compute the moments of a closed polygon with Green's theorem.
+///
+/// # Arguments
+///
+/// * `points` - The (x, y) vertices of the polygon.
+///
+/// # Returns
+///
+/// The moments of the region enclosed by the polygon, independently of its orientation.
+pub fn moments<T: Copy + Into<f64>>(points: &[[T; 2]]) -> Moments {
+    let n = points.len();
+    let mut a = [0.0f64; 10];
+
+    for i in 0..n {
+        let [x0, y0] = to_f64(&points[(i + n - 1) % n]);
+        let [x1, y1] = to_f64(&points[i]);
+        let dxy = x0 * y1 - x1 * y0;
+        let (xs, ys) = (x0 + x1, y0 + y1);
+
+        a[0] += dxy;
+        a[1] += dxy * xs;
+        a[2] += dxy * ys;
+        a[3] += dxy * (x0 * xs + x1 * x1);
+        a[4] += dxy * (x0 * (ys + y0) + x1 * (ys + y1));
+        a[5] += dxy * (y0 * ys + y1 * y1);
+        a[6] += dxy * xs * (x0 * x0 + x1 * x1);
+        a[7] += dxy * (x0 * x0 * (3.0 * y0 + y1) + 2.0 * x0 * x1 * ys + x1 * x1 * (y0 + 3.0 * y1));
+        a[8] += dxy * (y0 * y0 * (3.0 * x0 + x1) + 2.0 * y0 * y1 * xs + y1 * y1 * (x0 + 3.0 * x1));
+        a[9] += dxy * ys * (y0 * y0 + y1 * y1);
+    }
+
+    let sign = if a[0] < 0.0 { -1.0 } else { 1.0 };
+    let scale = [2.0, 6.0, 6.0, 12.0, 24.0, 12.0, 20.0, 60.0, 60.0, 20.0];
+    let [m00, m10, m01, m20, m11, m02, m30, m21, m12, m03] =
+        std::array::from_fn(|i| sign * a[i] / scale[i]);
+
+    let mut m = Moments {
+        m00,
+        m10,
+        m01,
+        m20,
+        m11,
+        m02,
+        m30,
+        m21,
+        m12,
+        m03,
+        ..Default::default()
+    };
+
+    if m00 == 0.0 {
+        return m;
+    }
+
+    let (cx, cy) = (m10 / m00, m01 / m00);
+    m.mu20 = m20 - cx * m10;
+    m.mu11 = m11 - cx * m01;
+    m.mu02 = m02 - cy * m01;
+    m.mu30 = m30 - cx * (3.0 * m.mu20 + cx * m10);
+    m.mu21 = m21 - cx * (2.0 * m.mu11 + cx * m01) - cy * m.mu20;
+    m.mu12 = m12 - cy * (2.0 * m.mu11 + cy * m10) - cx * m.mu02;
+    m.mu03 = m03 - cy * (3.0 * m.mu02 + cy * m01);
+
+    let s2 = 1.0 / (m00 * m00);
+    let s3 = s2 / m00.abs().sqrt();
+    m.nu20 = m.mu20 * s2;
+    m.nu11 = m.mu11 * s2;
+    m.nu02 = m.mu02 * s2;
+    m.nu30 = m.mu30 * s3;
+    m.nu21 = m.mu21 * s3;
+    m.nu12 = m.mu12 * s3;
+    m.nu03 = m.mu03 * s3;
+
+    m
+}
+
+/// Find the rotated rectangle of minimum area enclosing a set of points.
+///
+/// The rectangle has a side collinear with an edge of the convex hull of the points.
+///
+/// # Arguments
+///
+/// * `points` - The (x, y) points.
+///
+/// # Returns
+///
+/// The enclosing rectangle, or `None` if there are no points.
+pub fn min_area_rect<T: Copy + Into<f64>>(points: &[[T; 2]]) -> Option<RotatedRect> {
+    let hull = convex_hull(points).iter().map(to_f64).collect::<Vec<_>>();
+
+    if hull.len() == 1 {
+        return Some(RotatedRect {
+            center: hull[0],
+            size: [0.0, 0.0],
+            angle: 0.0,
+        });
+    }
+
+    let n = hull.len();
+    (0..n)
+        .map(|i| {
+            let (p, q) = (hull[i], hull[(i + 1) % n]);
+            let length = distance(p, q);
+            let u = [(q[0] - p[0]) / length, (q[1] - p[1]) / length];
+            let v = [-u[1], u[0]];
+
+            // the extent of the hull along the edge and its normal
+            let (mut umin, mut umax, mut vmin, mut vmax) = (0.0f64, 0.0f64, 0.0f64, 0.0f64);
+            for h in &hull {
+                let d = [h[0] - p[0], h[1] - p[1]];
+                let (du, dv) = (d[0] * u[0] + d[1] * u[1], d[0] * v[0] + d[1] * v[1]);
+                (umin, umax) = (umin.min(du), umax.max(du));
+                (vmin, vmax) = (vmin.min(dv), vmax.max(dv));
+            }
+
+            let (cu, cv) = ((umin + umax) / 2.0, (vmin + vmax) / 2.0);
+            RotatedRect {
+                center: [p[0] + u[0] * cu + v[0] * cv, p[1] + u[1] * cu + v[1] * cv],
+                size: [umax - umin, vmax - vmin],
+                angle: u[1].atan2(u[0]).to_degrees(),
+            }
+        })
+        .min_by(|a, b| (a.size[0] * a.size[1]).total_cmp(&(b.size[0] * b.size[1])))
+}
+
+/// Fit an ellipse to a set of points in the least-squares sense.
+///
+/// Implements the direct least-squares fitting of Fitzgibbon et al. with the numerically
+/// stable formulation of Halir and Flusser, "Numerically stable direct least squares fitting
+/// of ellipses", WSCG 1998.
+///
+/// # Arguments
+///
+/// * `points` - The (x, y) points, at least 5.
+///
+/// # Returns
+///
+/// The rectangle enclosing the ellipse, whose size is the length of the axes. `None` is
+/// returned if there are not enough points or they do not determine an ellipse.
+pub fn fit_ellipse<T: Copy + Into<f64>>(points: &[[T; 2]]) -> Option<RotatedRect> {
+    if points.len() < 5 {
+        return None;
+    }
+
+    // normalize the points for the conditioning of the scatter matrices
+    let pts = points.iter().map(to_f64).collect::<Vec<_>>();
+    let n = pts.len() as f64;
+    let mean = pts
+        .iter()
+        .fold([0.0, 0.0], |acc, p| [acc[0] + p[0] / n, acc[1] + p[1] / n]);
+    let scale = (pts
+        .iter()
+        .map(|p| (p[0] - mean[0]).powi(2) + (p[1] - mean[1]).powi(2))
+        .sum::<f64>()
+        / n)
+        .sqrt();
+    if scale == 0.0 {
+        return None;
+    }
+
+    // scatter matrices of the quadratic [x², xy, y²] and linear [x, y, 1] terms
+    let (mut s1, mut s2, mut s3) = ([[0.0f64; 3]; 3], [[0.0f64; 3]; 3], [[0.0f64; 3]; 3]);
+    for p in &pts {
+        let (x, y) = ((p[0] - mean[0]) / scale, (p[1] - mean[1]) / scale);
+        let d1 = [x * x, x * y, y * y];
+        let d2 = [x, y, 1.0];
+        for i in 0..3 {
+            for j in 0..3 {
+                s1[i][j] += d1[i] * d1[j];
+                s2[i][j] += d1[i] * d2[j];
+                s3[i][j] += d2[i] * d2[j];
+            }
+        }
+    }
+
+    // the linear terms as a function of the quadratic ones: t = -s3^-1 s2^T
+    let s3_inv = inverse3(&s3)?;
+    let s2t = transpose3(&s2);
+    let t = mul3(&s3_inv, &s2t).map(|row| row.map(|v| -v));
+
+    // reduced scatter matrix premultiplied by the inverse of the constraint matrix
+    let m = add3(&s1, &mul3(&s2, &t));
+    let m = [
+        m[2].map(|v| v / 2.0),
+        m[1].map(|v| -v),
+        m[0].map(|v| v / 2.0),
+    ];
+
+    // the ellipse is the eigenvector satisfying the constraint 4ac - b² > 0
+    let a1 = real_eigenvalues3(&m)
+        .into_iter()
+        .filter_map(|lambda| eigenvector3(&m, lambda))
+        .map(|v| (4.0 * v[0] * v[2] - v[1] * v[1], v))
+        .filter(|(c, _)| *c > 0.0)
+        .max_by(|a, b| a.0.total_cmp(&b.0))?
+        .1;
+    let a2 = [0, 1, 2].map(|i| (0..3).map(|j| t[i][j] * a1[j]).sum::<f64>());
+
+    let (a, b, c) = (a1[0], a1[1], a1[2]);
+    let (d, e, f) = (a2[0], a2[1], a2[2]);
+
+    // center and axes of the conic a x² + b xy + c y² + d x + e y + f = 0
+    let den = b * b - 4.0 * a * c;
+    let (x0, y0) = ((2.0 * c * d - b * e) / den, (2.0 * a * e - b * d) / den);
+    let f0 = a * x0 * x0 + b * x0 * y0 + c * y0 * y0 + d * x0 + e * y0 + f;
+
+    let half_sum = (a + c) / 2.0;
+    let half_diff = ((a - c) / 2.0).hypot(b / 2.0);
+    let (l1, l2) = (half_sum + half_diff, half_sum - half_diff);
+    let (r1, r2) = (-f0 / l1, -f0 / l2);
+    if r1 <= 0.0 || r2 <= 0.0 {
+        return None;
+    }
+
+    // the eigenvector of the largest eigenvalue is along the shortest axis
+    let theta = 0.5 * b.atan2(a - c);
+
+    Some(RotatedRect {
+        center: [x0 * scale + mean[0], y0 * scale + mean[1]],
+        size: [2.0 * r1.sqrt() * scale, 2.0 * r2.sqrt() * scale],
+        angle: theta.to_degrees(),
+    })
+}
+
+type Mat3 = [[f64; 3]; 3];
+
+fn transpose3(m: &Mat3) -> Mat3 {
+    std::array::from_fn(|i| std::array::from_fn(|j| m[j][i]))
+}
+
+fn add3(a: &Mat3, b: &Mat3) -> Mat3 {
+    std::array::from_fn(|i| std::array::from_fn(|j| a[i][j] + b[i][j]))
+}
+
+fn mul3(a: &Mat3, b: &Mat3) -> Mat3 {
+    std::array::from_fn(|i| std::array::from_fn(|j| (0..3).map(|k| a[i][k] * b[k][j]).sum()))
+}
+
+fn det3(m: &Mat3) -> f64 {
+    m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+}
+
+fn inverse3(m: &Mat3) -> Option<Mat3> {
+    let det = det3(m);
+    if det.abs() < f64::EPSILON {
+        return None;
+    }
+    // the transposed cofactors divided by the determinant
+    Some(std::array::from_fn(|i| {
+        std::array::from_fn(|j| {
+            let (r0, r1) = ((j + 1) % 3, (j + 2) % 3);
+            let (c0, c1) = ((i + 1) % 3, (i + 2) % 3);
+            (m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]) / det
+        })
+    }))
+}
+
+/// Compute the real roots of the characteristic polynomial of a 3x3 matrix.
+fn real_eigenvalues3(m: &Mat3) -> Vec<f64> {
+    // λ³ + a λ² + b λ + c = 0
+    let a = -(m[0][0] + m[1][1] + m[2][2]);
+    let b = m[0][0] * m[1][1] - m[0][1] * m[1][0] + m[0][0] * m[2][2] - m[0][2] * m[2][0]
+        + m[1][1] * m[2][2]
+        - m[1][2] * m[2][1];
+    let c = -det3(m);
+
+    // depressed cubic t³ + p t + q = 0 with λ = t - a / 3
+    let p = b - a * a / 3.0;
+    let q = 2.0 * a * a * a / 27.0 - a * b / 3.0 + c;
+    let disc = (q / 2.0).powi(2) + (p / 3.0).powi(3);
+
+    let roots = if disc > 0.0 {
+        let s = disc.sqrt();
+        vec![(-q / 2.0 + s).cbrt() + (-q / 2.0 - s).cbrt()]
+    } else if p == 0.0 {
+        vec![0.0]
+    } else {
+        let r = (-p / 3.0).sqrt();
+        let phi = (-q / (2.0 * r * r * r)).clamp(-1.0, 1.0).acos();
+        (0..3)
+            .map(|k| 2.0 * r * ((phi - 2.0 * std::f64::consts::PI * k as f64) / 3.0).cos())
+            .collect()
+    };
+
+    roots.into_iter().map(|t| t - a / 3.0).collect()
+}
+
+/// Compute the eigenvector of a 3x3 matrix for an eigenvalue from its null space.
+fn eigenvector3(m: &Mat3, lambda: f64) -> Option<[f64; 3]> {
+    let mut r = *m;
+    (0..3).for_each(|i| r[i][i] -= lambda);
+
+    // the null space is orthogonal to the rows, take the best conditioned cross product
+    let cross3 = |u: [f64; 3], v: [f64; 3]| {
+        [
+            u[1] * v[2] - u[2] * v[1],
+            u[2] * v[0] - u[0] * v[2],
+            u[0] * v[1] - u[1] * v[0],
+        ]
+    };
+    let norm = |v: &[f64; 3]| v.iter().map(|x| x * x).sum::<f64>();
+
+    [(0, 1), (0, 2), (1, 2)]
+        .map(|(i, j)| cross3(r[i], r[j]))
+        .into_iter()
+        .max_by(|u, v| norm(u).total_cmp(&norm(v)))
+        .filter(|v| norm(v) > 0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RotatedRect;
+    use crate::contours::find_contours;
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-6, "{a} != {b}");
+    }
+
+    #[test]
+    fn area_perimeter() {
+        let rect = [[0, 0], [4, 0], [4, 3], [0, 3]];
+        assert_close(super::contour_area(&rect), 12.0);
+        assert_close(super::contour_perimeter(&rect, true), 14.0);
+        assert_close(super::contour_perimeter(&rect, false), 11.0);
+
+        let reversed = [[0.0f32, 3.0], [4.0, 3.0], [4.0, 0.0], [0.0, 0.0]];
+        assert_close(super::contour_area(&reversed), 12.0);
+    }
+
+    #[test]
+    fn approx_poly_contour() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 12,
+            height: 8,
+        };
+        let data = (0..size.height)
+            .flat_map(|y| {
+                (0..size.width).map(move |x| ((1..11).contains(&x) && (2..7).contains(&y)) as u8)
+            })
+            .collect();
+        let image = Image::<u8, 1>::new(size, data)?;
+
+        let contours = find_contours(&image);
+        assert_eq!(contours.len(), 1);
+        assert_eq!(contours[0].points.len(), 26);
+
+        let mut poly = super::approx_poly(&contours[0].points, 0.5, true);
+        poly.sort();
+        assert_eq!(poly, vec![[1, 2], [1, 6], [10, 2], [10, 6]]);
+
+        // an open curve keeps its ends
+        let line = [[0, 0], [1, 0], [2, 1], [3, 0], [4, 0]];
+        assert_eq!(super::approx_poly(&line, 2.0, false), vec![[0, 0], [4, 0]]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn convex_hull_collinear() {
+        let points = [
+            [0, 0],
+            [1, 0],
+            [2, 0],
+            [2, 2],
+            [1, 1],
+            [0, 2],
+            [0, 1],
+            [2, 0],
+        ];
+        assert_eq!(
+            super::convex_hull(&points),
+            vec![[0, 0], [2, 0], [2, 2], [0, 2]]
+        );
+        assert_eq!(super::convex_hull(&[[1, 1], [1, 1]]), vec![[1, 1]]);
+    }
+
+    #[test]
+    fn moments_rect() {
+        let (w, h) = (4.0, 3.0);
+        let m = super::moments(&[
+            [1.0, 2.0],
+            [1.0 + w, 2.0],
+            [1.0 + w, 2.0 + h],
+            [1.0, 2.0 + h],
+        ]);
+        assert_close(m.m00, w * h);
+        let c = m.centroid().unwrap_or_default();
+        assert_close(c[0], 3.0);
+        assert_close(c[1], 3.5);
+        assert_close(m.mu20, w * w * w * h / 12.0);
+        assert_close(m.mu02, w * h * h * h / 12.0);
+        assert_close(m.mu11, 0.0);
+        assert_close(m.mu30, 0.0);
+        assert_close(m.nu20, w * w * w * h / 12.0 / (w * h).powi(2));
+    }
+
+    #[test]
+    fn min_area_rect_diamond() {
+        let points = [[0, 2], [2, 0], [4, 2], [2, 4], [2, 2], [1, 2]];
+        let RotatedRect {
+            center,
+            size,
+            angle,
+        } = super::min_area_rect(&points).unwrap_or(RotatedRect {
+            center: [0.0; 2],
+            size: [0.0; 2],
+            angle: 0.0,
+        });
+        assert_close(center[0], 2.0);
+        assert_close(center[1], 2.0);
+        assert_close(size[0], 8f64.sqrt());
+        assert_close(size[1], 8f64.sqrt());
+        assert_close(angle.abs() % 90.0, 45.0);
+
+        assert!(super::min_area_rect::<i32>(&[]).is_none());
+    }
+
+    #[test]
+    fn fit_ellipse_exact() {
+        let (cx, cy, a, b, theta) = (10.0f64, 5.0f64, 6.0f64, 3.0f64, 30f64.to_radians());
+        let points = (0..20)
+            .map(|i| {
+                let t = i as f64 * std::f64::consts::TAU / 20.0;
+                let (x, y) = (a * t.cos(), b * t.sin());
+                [
+                    cx + x * theta.cos() - y * theta.sin(),
+                    cy + x * theta.sin() + y * theta.cos(),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        let ellipse = super::fit_ellipse(&points);
+        assert!(ellipse.is_some());
+        let ellipse = ellipse.unwrap_or(RotatedRect {
+            center: [0.0; 2],
+            size: [0.0; 2],
+            angle: 0.0,
+        });
+
+        assert_close(ellipse.center[0], cx);
+        assert_close(ellipse.center[1], cy);
+
+        // the axes can be reported in any order
+        let (major, minor, angle) = match ellipse.size[0] > ellipse.size[1] {
+            true => (ellipse.size[0], ellipse.size[1], ellipse.angle),
+            false => (ellipse.size[1], ellipse.size[0], ellipse.angle + 90.0),
+        };
+        assert_close(major, 2.0 * a);
+        assert_close(minor, 2.0 * b);
+        assert_close(angle.rem_euclid(180.0), 30.0);
+
+        assert!(super::fit_ellipse(&points[..4]).is_none());
+    }
+}
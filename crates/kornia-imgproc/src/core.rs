@@ -8,7 +8,8 @@ use rayon::{
 /// Compute the mean and standard deviation of an image.
 ///
 /// The mean and standard deviation are computed for each channel
-/// of the image in one pass.
+/// of the image in one pass, accumulating the sums of the values and
+/// of their squares as integers so that they are exact.
 ///
 /// # Arguments
 ///
@@ -18,7 +19,7 @@ use rayon::{
 ///
 /// A tuple containing the mean and standard deviation of the image.
 /// The first element of the tuple is the standard deviation and the
-/// second element is the mean.
+/// second element is the mean. Both are NaN for an empty image.
 ///
 /// # Example
 ///
@@ -41,29 +42,22 @@ use rayon::{
 /// ```
 pub fn std_mean(image: &Image<u8, 3>) -> ([f64; 3], [f64; 3]) {
     let (sum, sq_sum) = image.as_slice().chunks_exact(3).fold(
-        ([0f64; 3], [0f64; 3]),
+        ([0u64; 3], [0u64; 3]),
         |(mut sum, mut sq_sum), pixel| {
-            sum.iter_mut()
-                .zip(pixel.iter())
-                .for_each(|(s, &p)| *s += p as f64);
-            sq_sum
-                .iter_mut()
-                .zip(pixel.iter())
-                .for_each(|(s, &p)| *s += (p as f64).powi(2));
+            for ((s, sq), &p) in sum.iter_mut().zip(sq_sum.iter_mut()).zip(pixel) {
+                *s += p as u64;
+                *sq += (p as u64) * (p as u64);
+            }
             (sum, sq_sum)
         },
     );
 
     let n = (image.width() * image.height()) as f64;
-    let mean = [sum[0] / n, sum[1] / n, sum[2] / n];
+    let mean = sum.map(|s| s as f64 / n);
 
-    let variance = [
-        (sq_sum[0] / n - mean[0].powi(2)).sqrt(),
-        (sq_sum[1] / n - mean[1].powi(2)).sqrt(),
-        (sq_sum[2] / n - mean[2].powi(2)).sqrt(),
-    ];
+    let std = std::array::from_fn(|c| (sq_sum[c] as f64 / n - mean[c].powi(2)).sqrt());
 
-    (variance, mean)
+    (std, mean)
 }
 
 /// Perform a bitwise AND operation between two images using a mask.
@@ -277,6 +271,45 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_std_mean_chunks() -> Result<(), ImageError> {
+        // more pixels than a chunk, compared with the two-pass formula
+        let size = ImageSize {
+            width: 101,
+            height: 97,
+        };
+        let data = (0..size.width * size.height * 3)
+            .map(|i| ((i * 7919) % 251) as u8)
+            .collect::<Vec<_>>();
+        let image = Image::<u8, 3>::new(size, data.clone())?;
+
+        let (std, mean) = super::std_mean(&image);
+        for c in 0..3 {
+            let channel = data.iter().skip(c).step_by(3).map(|&p| p as f64);
+            let n = (size.width * size.height) as f64;
+            let expected_mean = channel.clone().sum::<f64>() / n;
+            let expected_var = channel.map(|p| (p - expected_mean).powi(2)).sum::<f64>() / n;
+            assert!((mean[c] - expected_mean).abs() < 1e-9);
+            assert!((std[c] - expected_var.sqrt()).abs() < 1e-9);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_std_mean_empty() -> Result<(), ImageError> {
+        let image = Image::<u8, 3>::new(
+            ImageSize {
+                width: 0,
+                height: 0,
+            },
+            vec![],
+        )?;
+
+        let (std, mean) = super::std_mean(&image);
+        assert!(std.iter().chain(mean.iter()).all(|v| v.is_nan()));
+        Ok(())
+    }
+
     #[test]
     fn test_bitwise_and() -> Result<(), ImageError> {
         let image = Image::<u8, 3>::new(
@@ -1,8 +1,4 @@
-use kornia_image::{Image, ImageError};
-use rayon::{
-    iter::{IndexedParallelIterator, ParallelIterator},
-    slice::ParallelSliceMut,
-};
+use kornia_image::{AsImageView, AsImageViewMut, ImageError};
 
 /// Crop an image to a specified region.
 ///
@@ -13,6 +9,8 @@ use rayon::{
 /// * `x` - The x-coordinate of the top-left corner of the region to crop.
 /// * `y` - The y-coordinate of the top-left corner of the region to crop.
 ///
+/// To process a region without copying it, use [`kornia_image::Image::roi`] instead.
+///
 /// # Examples
 ///
 /// ```rust
@@ -33,29 +31,19 @@ use rayon::{
 /// assert_eq!(cropped.as_slice(), &[5u8, 6, 9, 10]);
 /// ```
 pub fn crop_image<T, const C: usize>(
-    src: &Image<T, C>,
-    dst: &mut Image<T, C>,
+    src: &impl AsImageView<T, C>,
+    dst: &mut impl AsImageViewMut<T, C>,
     x: usize,
     y: usize,
 ) -> Result<(), ImageError>
 where
     T: Copy + Send + Sync,
 {
-    let dst_cols = dst.cols();
-
-    dst.as_slice_mut()
-        .par_chunks_exact_mut(dst_cols * C)
-        .enumerate()
-        .for_each(|(i, dst_row)| {
-            // get the slice at the top left corner
-            let offset = (y + i) * src.cols() * C + x * C;
-            let src_slice = &src.as_slice()[offset..offset + dst_cols * C];
-
-            // copy the slice to the destination
-            dst_row.copy_from_slice(src_slice);
-        });
+    let src = src.as_view();
+    let mut dst = dst.as_view_mut();
 
-    Ok(())
+    // copy the region at the top left corner to the destination
+    dst.copy_from(&src.roi(x, y, dst.size())?)
 }
 
 #[cfg(test)]
@@ -0,0 +1,226 @@
+use kornia_image::{Image, ImageError};
+
+use crate::{border::BorderMode, filter::spatial_gradient_float_parallel_row, parallel};
+
+/// The norm used to compute the magnitude of the gradient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GradientNorm {
+    /// The sum of the absolute values of the derivatives.
+    L1,
+    /// The euclidean norm of the derivatives.
+    #[default]
+    L2,
+}
+
+/// The pixel is not an edge.
+const NONE: u8 = 0;
+/// The pixel is an edge if it is connected to a strong edge.
+const WEAK: u8 = 1;
+/// The pixel is an edge.
+const STRONG: u8 = 255;
+
+/// Detect the edges of an image with the Canny algorithm.
+///
+/// The gradient is computed with [`spatial_gradient_float_parallel_row`], whose normalized
+/// Sobel kernels give a magnitude of 0.5 for a step of 1 in the image. The local maxima of
+/// the magnitude along the gradient direction above `high_threshold` are edges, and the ones
+/// above `low_threshold` are edges only if they are connected to another edge.
+///
+/// The image is usually smoothed first, for example with a gaussian blur.
+///
+/// # Arguments
+///
+/// * `src` - The input grayscale image.
+/// * `dst` - The output edge map, with 255 on the edges and 0 elsewhere.
+/// * `low_threshold` - The magnitude above which the pixels connected to an edge are edges.
+/// * `high_threshold` - The magnitude above which the pixels are edges.
+/// * `norm` - The norm of the gradient magnitude.
+///
+/// # Errors
+///
+/// If the images do not have the same size, an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::features::{canny, GradientNorm};
+///
+/// let image = Image::<f32, 1>::new(
+///     ImageSize { width: 6, height: 3 },
+///     vec![
+///         0.0, 0.0, 0.0, 1.0, 1.0, 1.0,
+///         0.0, 0.0, 0.0, 1.0, 1.0, 1.0,
+///         0.0, 0.0, 0.0, 1.0, 1.0, 1.0,
+///     ],
+/// ).unwrap();
+///
+/// let mut edges = Image::<u8, 1>::from_size_val(image.size(), 0).unwrap();
+///
+/// canny(&image, &mut edges, 0.1, 0.3, GradientNorm::L2).unwrap();
+///
+/// assert_eq!(edges.as_slice(), &[
+///     0, 0, 255, 0, 0, 0,
+///     0, 0, 255, 0, 0, 0,
+///     0, 0, 255, 0, 0, 0,
+/// ]);
+/// ```
+pub fn canny(
+    src: &Image<f32, 1>,
+    dst: &mut Image<u8, 1>,
+    low_threshold: f32,
+    high_threshold: f32,
+    norm: GradientNorm,
+) -> Result<(), ImageError> {
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    let (rows, cols) = (src.rows(), src.cols());
+    let (low_threshold, high_threshold) = match low_threshold > high_threshold {
+        true => (high_threshold, low_threshold),
+        false => (low_threshold, high_threshold),
+    };
+
+    let mut dx = Image::<f32, 1>::from_size_val(src.size(), 0.0)?;
+    let mut dy = Image::<f32, 1>::from_size_val(src.size(), 0.0)?;
+    spatial_gradient_float_parallel_row(src, &mut dx, &mut dy, BorderMode::Reflect101)?;
+
+    let mut magnitude = Image::<f32, 1>::from_size_val(src.size(), 0.0)?;
+    parallel::par_iter_rows_val_two(&dx, &dy, &mut magnitude, |&gx, &gy, m| {
+        *m = match norm {
+            GradientNorm::L1 => gx.abs() + gy.abs(),
+            GradientNorm::L2 => (gx * gx + gy * gy).sqrt(),
+        };
+    });
+
+    // keep the local maxima along the gradient direction and classify them
+    let (mag, dx_data, dy_data) = (magnitude.as_slice(), dx.as_slice(), dy.as_slice());
+    let mag_at = |r: isize, c: isize| match (0..rows as isize).contains(&r)
+        && (0..cols as isize).contains(&c)
+    {
+        true => mag[r as usize * cols + c as usize],
+        false => 0.0,
+    };
+
+    // tan(22.5 deg) and tan(67.5 deg) bound the sectors of the gradient directions
+    let (tan_22_5, tan_67_5) = (
+        std::f32::consts::SQRT_2 - 1.0,
+        std::f32::consts::SQRT_2 + 1.0,
+    );
+
+    parallel::par_iter_rows_indexed_mut(dst, |r, dst_row| {
+        for (c, d) in dst_row.iter_mut().enumerate() {
+            let i = r * cols + c;
+            let m = mag[i];
+            if m <= low_threshold {
+                *d = NONE;
+                continue;
+            }
+
+            let (gx, gy) = (dx_data[i], dy_data[i]);
+            let (ax, ay) = (gx.abs(), gy.abs());
+            let (dr, dc) = if ay <= ax * tan_22_5 {
+                (0, 1)
+            } else if ay >= ax * tan_67_5 {
+                (1, 0)
+            } else if (gx > 0.0) == (gy > 0.0) {
+                (1, 1)
+            } else {
+                (1, -1)
+            };
+
+            // the ties are broken towards the first pixel to keep the edges thin
+            let (r, c) = (r as isize, c as isize);
+            let is_max = m > mag_at(r - dr, c - dc) && m >= mag_at(r + dr, c + dc);
+
+            *d = match (is_max, m > high_threshold) {
+                (false, _) => NONE,
+                (true, true) => STRONG,
+                (true, false) => WEAK,
+            };
+        }
+    });
+
+    // follow the weak edges connected to the strong ones
+    let edges = dst.as_slice_mut();
+    let mut stack = edges
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &e)| (e == STRONG).then_some(i))
+        .collect::<Vec<_>>();
+
+    while let Some(i) = stack.pop() {
+        let (r, c) = (i / cols, i % cols);
+        for nr in r.saturating_sub(1)..(r + 2).min(rows) {
+            for nc in c.saturating_sub(1)..(c + 2).min(cols) {
+                let j = nr * cols + nc;
+                if edges[j] == WEAK {
+                    edges[j] = STRONG;
+                    stack.push(j);
+                }
+            }
+        }
+    }
+
+    edges
+        .iter_mut()
+        .filter(|e| **e == WEAK)
+        .for_each(|e| *e = NONE);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kornia_image::ImageSize;
+
+    #[test]
+    fn canny_hysteresis() -> Result<(), ImageError> {
+        // a vertical step whose height fades along the rows, and a faint separate step
+        let size = ImageSize {
+            width: 12,
+            height: 6,
+        };
+        let heights = [0.8, 0.7, 0.6, 0.5, 0.4, 0.35];
+        let image = Image::<f32, 1>::new(
+            size,
+            (0..size.width * size.height)
+                .map(|i| {
+                    let (r, c) = (i / size.width, i % size.width);
+                    match c {
+                        0..=2 => 0.0,
+                        3..=7 => heights[r],
+                        _ => heights[r] + 0.36,
+                    }
+                })
+                .collect(),
+        )?;
+
+        // the weak pixels of the first step are connected to its strong top rows, the
+        // second step is weak and isolated
+        let mut edges = Image::<u8, 1>::from_size_val(size, 0)?;
+        canny(&image, &mut edges, 0.15, 0.3, GradientNorm::L2)?;
+        for r in 0..size.height {
+            let row = &edges.as_slice()[r * size.width..(r + 1) * size.width];
+            assert_eq!(row.iter().filter(|&&e| e == STRONG).count(), 1);
+            assert!(row[2] == STRONG || row[3] == STRONG, "row {r}: {row:?}");
+        }
+
+        // without hysteresis only the rows above the high threshold are left, the L1 norm
+        // adds the vertical derivative of the fading step
+        let count = |edges: &Image<u8, 1>| edges.as_slice().iter().filter(|&&e| e != 0).count();
+        canny(&image, &mut edges, 0.3, 0.3, GradientNorm::L2)?;
+        assert_eq!(count(&edges), 3);
+        canny(&image, &mut edges, 0.3, 0.3, GradientNorm::L1)?;
+        assert_eq!(count(&edges), 4);
+
+        Ok(())
+    }
+}
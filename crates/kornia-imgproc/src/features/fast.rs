@@ -1,6 +1,11 @@
 use kornia_image::{Image, ImageError};
 use rayon::prelude::*;
 
+use super::Keypoint;
+
+/// The diameter of the Bresenham circle of the detector.
+const FAST_DIAMETER: f32 = 7.0;
+
 /// Fast feature detector
 ///
 /// # Arguments
@@ -11,21 +16,22 @@ use rayon::prelude::*;
 ///
 /// # Returns
 ///
-/// A vector containing the coordinates of the detected keypoints.
+/// A vector containing the detected keypoints in raster order. The response of a keypoint is
+/// its FAST score, the largest threshold for which the pixel is still a corner.
 pub fn fast_feature_detector(
     src: &Image<u8, 1>,
     threshold: u8,
     arc_length: u8,
-) -> Result<Vec<[i32; 2]>, ImageError> {
-    let (cols, rows) = (src.cols() as i32, src.rows() as i32);
+) -> Result<Vec<Keypoint>, ImageError> {
+    let (cols, rows) = (src.cols() as isize, src.rows() as isize);
 
-    // Precompute the offsets for the Bresenham circle
+    // Precompute the offsets for the Bresenham circle, clockwise from the top
     let offsets = [
         -3 * cols,     // 1
         -3 * cols + 1, // 2
         -2 * cols + 2, // 3
         -cols + 3,     // 4
-        cols + 3,      // 5
+        3,             // 5
         cols + 3,      // 6
         2 * cols + 2,  // 7
         3 * cols + 1,  // 8
@@ -33,28 +39,32 @@ pub fn fast_feature_detector(
         3 * cols - 1,  // 10
         2 * cols - 2,  // 11
         cols - 3,      // 12
-        -cols - 3,     // 13
-        -2 * cols - 2, // 14
-        -3 * cols - 1, // 15
-        -3 * cols,     // 16
+        -3,            // 13
+        -cols - 3,     // 14
+        -2 * cols - 2, // 15
+        -3 * cols - 1, // 16
     ];
 
     // Process rows in parallel
-    let keypoints = (3..rows - 3)
+    let keypoints = (3..(rows - 3).max(3))
         .into_par_iter()
         .flat_map(|y| {
             let row_start_idx = y * cols;
             let mut row_keypoints = Vec::new();
 
             for x in 3..cols - 3 {
-                if is_fast_corner(
+                if let Some(score) = fast_score(
                     src.as_slice(),
                     row_start_idx + x,
-                    offsets,
+                    &offsets,
                     threshold,
-                    arc_length,
+                    arc_length as usize,
                 ) {
-                    row_keypoints.push([x, y]);
+                    row_keypoints.push(Keypoint::new(
+                        [x as f32, y as f32],
+                        score as f32,
+                        FAST_DIAMETER,
+                    ));
                 }
             }
 
@@ -65,72 +75,50 @@ pub fn fast_feature_detector(
     Ok(keypoints)
 }
 
-fn is_fast_corner(
+/// Compute the FAST score of a pixel if it is a corner for the threshold.
+fn fast_score(
     src: &[u8],
-    pixel_idx: i32,
-    offsets: [i32; 16],
+    pixel_idx: isize,
+    offsets: &[isize; 16],
     threshold: u8,
-    arc_length: u8,
-) -> bool {
+    arc_length: usize,
+) -> Option<u8> {
+    if arc_length == 0 || arc_length > 16 {
+        return None;
+    }
+
     let center_pixel = unsafe { *src.get_unchecked(pixel_idx as usize) };
-    let lower_threshold = &center_pixel.saturating_sub(threshold);
-    let upper_threshold = &center_pixel.saturating_add(threshold);
+    let lower_threshold = center_pixel.saturating_sub(threshold);
+    let upper_threshold = center_pixel.saturating_add(threshold);
 
     // Helper to get pixel value efficiently with unchecked access
     let get_pixel_from_offset =
-        |off_idx: usize| unsafe { src.get_unchecked((pixel_idx + offsets[off_idx]) as usize) };
-
-    // Fast rejection test - check if at least 3 of the 4 high-speed test points are different enough
-    let p1 = get_pixel_from_offset(0);
-    let p5 = get_pixel_from_offset(4);
-    let p9 = get_pixel_from_offset(8);
-    let p13 = get_pixel_from_offset(12);
-
-    let m0 = !(p5 >= lower_threshold || p1 >= lower_threshold && p9 >= lower_threshold);
-    let m1 = !(p5 <= upper_threshold || p1 <= upper_threshold && p9 <= upper_threshold);
-
-    if !m0 && !m1 {
-        return false;
+        |off_idx: usize| unsafe { *src.get_unchecked((pixel_idx + offsets[off_idx]) as usize) };
+
+    // Fast rejection test - an arc contains at least a quarter of its length of the 4
+    // high-speed test points
+    let compass = [0, 4, 8, 12].map(get_pixel_from_offset);
+    let min_compass = arc_length / 4;
+    let brighter = compass.iter().filter(|&&p| p > upper_threshold).count();
+    let darker = compass.iter().filter(|&&p| p < lower_threshold).count();
+    if brighter < min_compass && darker < min_compass {
+        return None;
     }
 
-    // check the remaining pixels
-    let p2 = get_pixel_from_offset(1);
-    let p3 = get_pixel_from_offset(2);
-    let p4 = get_pixel_from_offset(3);
-    let p6 = get_pixel_from_offset(5);
-    let p7 = get_pixel_from_offset(6);
-    let p8 = get_pixel_from_offset(7);
-    let p10 = get_pixel_from_offset(9);
-    let p11 = get_pixel_from_offset(10);
-    let p12 = get_pixel_from_offset(11);
-    let p14 = get_pixel_from_offset(13);
-    let p15 = get_pixel_from_offset(14);
-    let p16 = get_pixel_from_offset(15);
-    let pixels = [
-        p1, p2, p3, p4, p5, p6, p7, p8, p9, p10, p11, p12, p13, p14, p15, p16,
-    ];
-
-    let mut consecutive_brighter = 0u8;
-    let mut consecutive_darker = 0u8;
-
-    for pixel in pixels {
-        if pixel > upper_threshold {
-            consecutive_brighter += 1;
-            consecutive_darker = 0;
-        } else if pixel < lower_threshold {
-            consecutive_darker += 1;
-            consecutive_brighter = 0;
-        } else {
-            consecutive_brighter = 0;
-            consecutive_darker = 0;
-        }
-
-        if consecutive_brighter >= arc_length || consecutive_darker >= arc_length {
-            return true;
-        }
+    // the score of an arc is the smallest margin of its pixels, the score of the pixel the
+    // largest score of the arcs
+    let diffs: [i16; 16] =
+        std::array::from_fn(|i| get_pixel_from_offset(i) as i16 - center_pixel as i16);
+
+    let mut score = -1;
+    for start in 0..16 {
+        let arc = (start..start + arc_length).map(|i| diffs[i % 16]);
+        let brighter_margin = arc.clone().map(|d| d - 1).min().unwrap_or(-1);
+        let darker_margin = arc.map(|d| -d - 1).min().unwrap_or(-1);
+        score = score.max(brighter_margin).max(darker_margin);
     }
 
-    false
+    (score >= threshold as i16).then_some(score as u8)
 }
 
 #[cfg(test)]
@@ -153,10 +141,37 @@ mod tests {
                 50,  50,  50,  50,  50,  50,  50,
             ],
         )?;
-        let expected_keypoints = vec![[3, 3]];
         let keypoints = fast_feature_detector(&img, 100, 9)?;
-        assert_eq!(keypoints.len(), expected_keypoints.len());
-        assert_eq!(keypoints, expected_keypoints);
+        assert_eq!(keypoints.len(), 1);
+        assert_eq!(keypoints[0].position, [3.0, 3.0]);
+        assert_eq!(keypoints[0].response, 149.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fast_arc_wraps_around() -> Result<(), ImageError> {
+        // the bright arc covers the circle from the point 10 to the point 2, through the top
+        // left quadrant
+        let mut img = Image::<u8, 1>::from_size_val([7, 7].into(), 50)?;
+        for (x, y) in [
+            (2, 6),
+            (1, 5),
+            (0, 4),
+            (0, 3),
+            (0, 2),
+            (1, 1),
+            (2, 0),
+            (3, 0),
+            (4, 0),
+        ] {
+            img.as_slice_mut()[y * 7 + x] = 200;
+        }
+
+        let keypoints = fast_feature_detector(&img, 100, 9)?;
+        assert_eq!(keypoints.len(), 1);
+        assert_eq!(keypoints[0].response, 149.0);
+        assert!(fast_feature_detector(&img, 100, 10)?.is_empty());
+
         Ok(())
     }
 }
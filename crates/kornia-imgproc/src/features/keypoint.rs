@@ -0,0 +1,41 @@
+/// A keypoint detected in an image.
+///
+/// # Fields
+///
+/// * `position` - The (x, y) coordinates of the keypoint in the input image
+/// * `response` - The strength of the detection, larger is stronger
+/// * `angle` - The orientation of the keypoint in degrees in [0, 360)
+/// * `octave` - The pyramid level where the keypoint was detected
+/// * `size` - The diameter of the neighbourhood of the keypoint in the input image
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Keypoint {
+    /// The (x, y) coordinates of the keypoint in the input image
+    pub position: [f32; 2],
+    /// The strength of the detection, larger is stronger
+    pub response: f32,
+    /// The orientation of the keypoint in degrees in [0, 360)
+    pub angle: f32,
+    /// The pyramid level where the keypoint was detected
+    pub octave: usize,
+    /// The diameter of the neighbourhood of the keypoint in the input image
+    pub size: f32,
+}
+
+impl Keypoint {
+    /// Create a keypoint detected in the input image without orientation.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The (x, y) coordinates of the keypoint.
+    /// * `response` - The strength of the detection.
+    /// * `size` - The diameter of the neighbourhood of the keypoint.
+    pub fn new(position: [f32; 2], response: f32, size: f32) -> Self {
+        Self {
+            position,
+            response,
+            angle: 0.0,
+            octave: 0,
+            size,
+        }
+    }
+}
@@ -0,0 +1,392 @@
+use rayon::prelude::*;
+
+/// An error type for the descriptor matching.
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum MatchingError {
+    /// Error when two descriptors do not have the same length.
+    #[error("Descriptor length mismatch: expected {0}, got {1}")]
+    InvalidDescriptorLength(usize, usize),
+}
+
+/// A match between a query descriptor and a train descriptor.
+///
+/// # Fields
+///
+/// * `query_idx` - The index of the descriptor in the query set
+/// * `train_idx` - The index of the descriptor in the train set
+/// * `distance` - The distance between the two descriptors
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DescriptorMatch {
+    /// The index of the descriptor in the query set
+    pub query_idx: usize,
+    /// The index of the descriptor in the train set
+    pub train_idx: usize,
+    /// The distance between the two descriptors
+    pub distance: f32,
+}
+
+/// Compute the number of different bits of two binary descriptors.
+///
+/// # Arguments
+///
+/// * `a` - The first descriptor.
+/// * `b` - The second descriptor.
+///
+/// # Errors
+///
+/// If the descriptors do not have the same length, an error is returned.
+pub fn hamming_distance(a: &[u8], b: &[u8]) -> Result<u32, MatchingError> {
+    if a.len() != b.len() {
+        return Err(MatchingError::InvalidDescriptorLength(a.len(), b.len()));
+    }
+
+    // count the bits of 8 bytes at a time
+    let (a_chunks, b_chunks) = (a.chunks_exact(8), b.chunks_exact(8));
+    let tail = a_chunks
+        .remainder()
+        .iter()
+        .zip(b_chunks.remainder())
+        .map(|(a, b)| (a ^ b).count_ones())
+        .sum::<u32>();
+
+    let body = a_chunks
+        .zip(b_chunks)
+        .map(|(a, b)| {
+            let a = u64::from_ne_bytes(a.try_into().expect("chunk of 8 bytes"));
+            let b = u64::from_ne_bytes(b.try_into().expect("chunk of 8 bytes"));
+            (a ^ b).count_ones()
+        })
+        .sum::<u32>();
+
+    Ok(body + tail)
+}
+
+/// Compute the euclidean distance of two float descriptors.
+///
+/// # Arguments
+///
+/// * `a` - The first descriptor.
+/// * `b` - The second descriptor.
+///
+/// # Errors
+///
+/// If the descriptors do not have the same length, an error is returned.
+pub fn l2_distance(a: &[f32], b: &[f32]) -> Result<f32, MatchingError> {
+    if a.len() != b.len() {
+        return Err(MatchingError::InvalidDescriptorLength(a.len(), b.len()));
+    }
+
+    // sum the squared differences directly, expanding the square cancels for close descriptors
+    let squared = a.iter().zip(b).map(|(a, b)| (a - b) * (a - b)).sum::<f32>();
+
+    Ok(squared.sqrt())
+}
+
+/// Find the k nearest train descriptors of each binary query descriptor.
+///
+/// # Arguments
+///
+/// * `query` - The binary query descriptors, e.g. the ORB descriptors.
+/// * `train` - The binary train descriptors.
+/// * `k` - The number of neighbours of each query descriptor.
+///
+/// # Returns
+///
+/// For each query descriptor, its `min(k, train.len())` nearest train descriptors by
+/// increasing hamming distance.
+///
+/// # Errors
+///
+/// If the descriptors do not have the same length, an error is returned.
+pub fn knn_match_hamming<D: AsRef<[u8]> + Sync>(
+    query: &[D],
+    train: &[D],
+    k: usize,
+) -> Result<Vec<Vec<DescriptorMatch>>, MatchingError> {
+    knn_match_by(query.len(), train.len(), k, |q, t| {
+        hamming_distance(query[q].as_ref(), train[t].as_ref()).map(|d| d as f32)
+    })
+}
+
+/// Find the k nearest train descriptors of each float query descriptor.
+///
+/// # Arguments
+///
+/// * `query` - The float query descriptors.
+/// * `train` - The float train descriptors.
+/// * `k` - The number of neighbours of each query descriptor.
+///
+/// # Returns
+///
+/// For each query descriptor, its `min(k, train.len())` nearest train descriptors by
+/// increasing euclidean distance.
+///
+/// # Errors
+///
+/// If the descriptors do not have the same length, an error is returned.
+pub fn knn_match_l2<D: AsRef<[f32]> + Sync>(
+    query: &[D],
+    train: &[D],
+    k: usize,
+) -> Result<Vec<Vec<DescriptorMatch>>, MatchingError> {
+    knn_match_by(query.len(), train.len(), k, |q, t| {
+        l2_distance(query[q].as_ref(), train[t].as_ref())
+    })
+}
+
+/// Find the nearest train descriptor of each binary query descriptor.
+///
+/// # Arguments
+///
+/// * `query` - The binary query descriptors, e.g. the ORB descriptors.
+/// * `train` - The binary train descriptors.
+/// * `cross_check` - Keep only the matches whose query descriptor is also the nearest of
+///   the train descriptor.
+///
+/// # Returns
+///
+/// The matches sorted by query index.
+///
+/// # Errors
+///
+/// If the descriptors do not have the same length, an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_imgproc::features::matching::match_hamming;
+///
+/// let query = [[0b1111_0000u8], [0b0000_0011]];
+/// let train = [[0b0000_0001u8], [0b1110_0000], [0b0011_1100]];
+///
+/// let matches = match_hamming(&query, &train, true).unwrap();
+///
+/// assert_eq!(matches.len(), 2);
+/// assert_eq!((matches[0].query_idx, matches[0].train_idx), (0, 1));
+/// assert_eq!((matches[1].query_idx, matches[1].train_idx), (1, 0));
+/// assert_eq!(matches[1].distance, 1.0);
+/// ```
+pub fn match_hamming<D: AsRef<[u8]> + Sync>(
+    query: &[D],
+    train: &[D],
+    cross_check: bool,
+) -> Result<Vec<DescriptorMatch>, MatchingError> {
+    match_by(query.len(), train.len(), cross_check, |q, t| {
+        hamming_distance(query[q].as_ref(), train[t].as_ref()).map(|d| d as f32)
+    })
+}
+
+/// Find the nearest train descriptor of each float query descriptor.
+///
+/// # Arguments
+///
+/// * `query` - The float query descriptors.
+/// * `train` - The float train descriptors.
+/// * `cross_check` - Keep only the matches whose query descriptor is also the nearest of
+///   the train descriptor.
+///
+/// # Returns
+///
+/// The matches sorted by query index.
+///
+/// # Errors
+///
+/// If the descriptors do not have the same length, an error is returned.
+pub fn match_l2<D: AsRef<[f32]> + Sync>(
+    query: &[D],
+    train: &[D],
+    cross_check: bool,
+) -> Result<Vec<DescriptorMatch>, MatchingError> {
+    match_by(query.len(), train.len(), cross_check, |q, t| {
+        l2_distance(query[q].as_ref(), train[t].as_ref())
+    })
+}
+
+/// Keep the matches that are clearly better than the second nearest neighbour.
+///
+/// Implements the ratio test of Lowe, "Distinctive Image Features from Scale-Invariant
+/// Keypoints", IJCV 2004. The query descriptors with less than two neighbours are dropped.
+///
+/// # Arguments
+///
+/// * `knn_matches` - The nearest neighbours of each query descriptor by increasing distance,
+///   as returned by [`knn_match_hamming`] or [`knn_match_l2`].
+/// * `ratio` - The largest ratio of the distances of the two nearest neighbours, usually 0.7
+///   to 0.8.
+///
+/// # Returns
+///
+/// The nearest neighbours passing the test.
+///
+/// # Example
+///
+/// ```
+/// use kornia_imgproc::features::matching::{knn_match_l2, ratio_test};
+///
+/// let query = [vec![0.0, 0.0], vec![5.0, 5.0]];
+/// let train = [vec![0.1, 0.0], vec![4.0, 5.0], vec![6.0, 5.0]];
+///
+/// let knn_matches = knn_match_l2(&query, &train, 2).unwrap();
+/// let matches = ratio_test(&knn_matches, 0.8);
+///
+/// // the second query descriptor is equally far from two train descriptors
+/// assert_eq!(matches.len(), 1);
+/// assert_eq!((matches[0].query_idx, matches[0].train_idx), (0, 0));
+/// ```
+pub fn ratio_test(knn_matches: &[Vec<DescriptorMatch>], ratio: f32) -> Vec<DescriptorMatch> {
+    knn_matches
+        .iter()
+        .filter_map(|neighbours| match neighbours.as_slice() {
+            [best, second, ..] if best.distance < ratio * second.distance => Some(*best),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Find the k nearest train indices of each query index, the ties are broken by train index.
+fn knn_match_by(
+    n_query: usize,
+    n_train: usize,
+    k: usize,
+    distance: impl Fn(usize, usize) -> Result<f32, MatchingError> + Sync,
+) -> Result<Vec<Vec<DescriptorMatch>>, MatchingError> {
+    let k = k.min(n_train);
+
+    (0..n_query)
+        .into_par_iter()
+        .map(|query_idx| {
+            let mut neighbours = (0..n_train)
+                .map(|train_idx| {
+                    Ok(DescriptorMatch {
+                        query_idx,
+                        train_idx,
+                        distance: distance(query_idx, train_idx)?,
+                    })
+                })
+                .collect::<Result<Vec<_>, MatchingError>>()?;
+
+            let order = |a: &DescriptorMatch, b: &DescriptorMatch| {
+                a.distance
+                    .total_cmp(&b.distance)
+                    .then(a.train_idx.cmp(&b.train_idx))
+            };
+            if k > 0 && k < neighbours.len() {
+                neighbours.select_nth_unstable_by(k - 1, order);
+            }
+            neighbours.truncate(k);
+            neighbours.sort_unstable_by(order);
+
+            Ok(neighbours)
+        })
+        .collect()
+}
+
+/// Find the nearest train index of each query index, optionally checking the reverse match.
+fn match_by(
+    n_query: usize,
+    n_train: usize,
+    cross_check: bool,
+    distance: impl Fn(usize, usize) -> Result<f32, MatchingError> + Sync,
+) -> Result<Vec<DescriptorMatch>, MatchingError> {
+    let matches = knn_match_by(n_query, n_train, 1, &distance)?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+    if !cross_check {
+        return Ok(matches);
+    }
+
+    let reverse = knn_match_by(n_train, n_query, 1, |t, q| distance(q, t))?;
+
+    Ok(matches
+        .into_iter()
+        .filter(|m| {
+            reverse[m.train_idx]
+                .first()
+                .is_some_and(|r| r.train_idx == m.query_idx)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hamming_distance() -> Result<(), MatchingError> {
+        let a = (0..37).map(|i| (i * 37 % 256) as u8).collect::<Vec<_>>();
+        let b = (0..37).map(|i| (i * 91 % 256) as u8).collect::<Vec<_>>();
+        let expected = a
+            .iter()
+            .zip(&b)
+            .map(|(a, b)| (a ^ b).count_ones())
+            .sum::<u32>();
+
+        assert_eq!(hamming_distance(&a, &b)?, expected);
+        assert_eq!(hamming_distance(&a, &a)?, 0);
+        assert!(hamming_distance(&a, &b[..36]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_knn_match_l2() -> Result<(), MatchingError> {
+        let query = [vec![0.0, 0.0, 0.0], vec![1.0, 1.0, 1.0]];
+        let train = [
+            vec![0.0, 3.0, 0.0],
+            vec![1.0, 1.0, 2.0],
+            vec![0.0, 0.0, 1.0],
+            vec![0.0, 2.0, 0.0],
+        ];
+
+        let knn_matches = knn_match_l2(&query, &train, 3)?;
+        let neighbours = |i: usize| {
+            knn_matches[i]
+                .iter()
+                .map(|m| (m.train_idx, m.distance))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(neighbours(0), [(2, 1.0), (3, 2.0), (1, 6f32.sqrt())]);
+        assert_eq!(
+            neighbours(1),
+            [(1, 1.0), (2, 2f32.sqrt()), (3, 3f32.sqrt())]
+        );
+
+        // more neighbours than train descriptors
+        assert_eq!(knn_match_l2(&query, &train, 10)?[0].len(), 4);
+        assert!(knn_match_l2(&query, &[vec![0.0, 1.0]], 1).is_err());
+
+        // close descriptors far from the origin
+        let d = l2_distance(&[1000.0, 1000.0], &[1000.0, 1000.01])?;
+        assert!((d - 0.01).abs() < 1e-4, "{d}");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_match_cross_check() -> Result<(), MatchingError> {
+        // the train descriptor 0 is the nearest of both query descriptors, but only the
+        // query descriptor 1 is its nearest
+        let query = [[0b0000_1111u8, 0], [0b0000_0111, 0], [0xff, 0xff]];
+        let train = [[0b0000_0011u8, 0], [0b1111_1111, 0xf0]];
+
+        let matches = match_hamming(&query, &train, false)?;
+        let pairs = matches
+            .iter()
+            .map(|m| (m.query_idx, m.train_idx))
+            .collect::<Vec<_>>();
+        assert_eq!(pairs, [(0, 0), (1, 0), (2, 1)]);
+
+        let matches = match_hamming(&query, &train, true)?;
+        let pairs = matches
+            .iter()
+            .map(|m| (m.query_idx, m.train_idx, m.distance))
+            .collect::<Vec<_>>();
+        assert_eq!(pairs, [(1, 0, 1.0), (2, 1, 4.0)]);
+
+        assert!(match_hamming(&query, &[[0u8, 0]; 0], true)?.is_empty());
+
+        Ok(())
+    }
+}
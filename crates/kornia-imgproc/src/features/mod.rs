@@ -3,3 +3,18 @@ pub use responses::*;
 
 mod fast;
 pub use fast::*;
+
+mod canny;
+pub use canny::*;
+
+mod keypoint;
+pub use keypoint::*;
+
+mod orb;
+pub use orb::*;
+
+/// descriptor matching module.
+pub mod matching;
+
+mod nms;
+pub use nms::*;
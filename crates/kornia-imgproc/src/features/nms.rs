@@ -0,0 +1,348 @@
+use kornia_image::{Image, ImageError};
+use rayon::prelude::*;
+
+use super::Keypoint;
+use crate::morphology::{dilate, StructuringElement};
+
+/// Find the local maxima of a response map.
+///
+/// A pixel is a keypoint if its response is above the threshold and the largest in the
+/// square window centered at it. The plateaus of equal responses keep their first pixel in
+/// raster order. The responses with a sign, e.g. the DoG response, can be passed through their
+/// absolute value to find the minima too.
+///
+/// # Arguments
+///
+/// * `response` - The response map, e.g. of [`super::HarrisResponse`],
+///   [`super::hessian_response`] or [`super::dog_response`].
+/// * `window_size` - The odd size of the window of the suppression, e.g. 3.
+/// * `threshold` - The response above which the pixels are keypoints.
+/// * `max_keypoints` - The largest number of keypoints kept, the strongest first.
+///
+/// # Returns
+///
+/// The keypoints by decreasing response, with the window size as size.
+///
+/// # Errors
+///
+/// If the window size is not odd, an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::features::non_max_suppression;
+///
+/// let response = Image::<f32, 1>::new(
+///     ImageSize { width: 5, height: 3 },
+///     vec![
+///         0.0, 0.0, 0.0, 0.0, 0.0,
+///         0.0, 0.5, 0.0, 0.9, 0.0,
+///         0.0, 0.4, 0.0, 0.0, 0.0,
+///     ],
+/// ).unwrap();
+///
+/// let keypoints = non_max_suppression(&response, 3, 0.1, None).unwrap();
+///
+/// assert_eq!(keypoints.len(), 2);
+/// assert_eq!(keypoints[0].position, [3.0, 1.0]);
+/// assert_eq!(keypoints[1].position, [1.0, 1.0]);
+/// ```
+pub fn non_max_suppression(
+    response: &Image<f32, 1>,
+    window_size: usize,
+    threshold: f32,
+    max_keypoints: Option<usize>,
+) -> Result<Vec<Keypoint>, ImageError> {
+    if window_size % 2 == 0 {
+        return Err(ImageError::InvalidKernelLength(window_size, window_size));
+    }
+
+    let (rows, cols) = (response.rows(), response.cols());
+    let half = window_size / 2;
+
+    // the largest response of the window of each pixel
+    let mut window_max = Image::<f32, 1>::from_size_val(response.size(), 0.0)?;
+    dilate(
+        response,
+        &mut window_max,
+        &StructuringElement::rect(window_size, window_size)?,
+    )?;
+
+    let (data, window_max) = (response.as_slice(), window_max.as_slice());
+    let mut keypoints = data
+        .par_iter()
+        .zip(window_max)
+        .enumerate()
+        .filter(|&(_, (&v, &max))| v > threshold && v >= max)
+        .filter(|&(i, (&v, _))| {
+            // break the ties with the previous maxima of the window
+            let (r, c) = (i / cols, i % cols);
+            let first_col = c.saturating_sub(half);
+            let last_col = (c + half).min(cols - 1);
+            (r.saturating_sub(half)..=(r + half).min(rows - 1))
+                .flat_map(|nr| (first_col..=last_col).map(move |nc| nr * cols + nc))
+                .take_while(|&j| j < i)
+                .all(|j| data[j] != v || window_max[j] > v)
+        })
+        .map(|(i, (&v, _))| {
+            Keypoint::new(
+                [(i % cols) as f32, (i / cols) as f32],
+                v,
+                window_size as f32,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    keypoints.sort_by(|a, b| b.response.total_cmp(&a.response));
+    if let Some(max_keypoints) = max_keypoints {
+        keypoints.truncate(max_keypoints);
+    }
+
+    Ok(keypoints)
+}
+
+/// Select keypoints spread over the image with the adaptive non-maximal suppression.
+///
+/// The suppression radius of a keypoint is its distance to the nearest keypoint that is
+/// significantly stronger, and the keypoints with the largest radii are kept. Implements
+/// "Multi-Image Matching using Multi-Scale Oriented Patches", Brown et al., CVPR 2005.
+///
+/// # Arguments
+///
+/// * `keypoints` - The candidate keypoints.
+/// * `num_keypoints` - The number of keypoints to select.
+/// * `robustness` - The ratio of the responses below which a keypoint is suppressed by a
+///   stronger one, usually 0.9.
+///
+/// # Returns
+///
+/// The selected keypoints by decreasing suppression radius.
+pub fn adaptive_non_max_suppression(
+    keypoints: &[Keypoint],
+    num_keypoints: usize,
+    robustness: f32,
+) -> Vec<Keypoint> {
+    let mut sorted = keypoints.to_vec();
+    sorted.sort_by(|a, b| b.response.total_cmp(&a.response));
+
+    let squared_radii = sorted
+        .par_iter()
+        .map(|kp| {
+            sorted
+                .iter()
+                .filter(|other| kp.response < robustness * other.response)
+                .map(|other| {
+                    let dx = kp.position[0] - other.position[0];
+                    let dy = kp.position[1] - other.position[1];
+                    dx * dx + dy * dy
+                })
+                .fold(f32::INFINITY, f32::min)
+        })
+        .collect::<Vec<_>>();
+
+    // the sort is stable, the keypoints with the same radius keep the strongest first
+    let mut order = (0..sorted.len()).collect::<Vec<_>>();
+    order.sort_by(|&a, &b| squared_radii[b].total_cmp(&squared_radii[a]));
+
+    order
+        .into_iter()
+        .take(num_keypoints)
+        .map(|i| sorted[i])
+        .collect()
+}
+
+/// Refine the position of keypoints with a quadratic fit of the response map.
+///
+/// The response around each keypoint is approximated by its second order Taylor expansion
+/// from the finite differences of the 3x3 neighbourhood, and the keypoint is moved to the
+/// extremum of the expansion. The keypoints on the border of the map, with a singular
+/// hessian or with an extremum more than one pixel away are left unchanged.
+///
+/// # Arguments
+///
+/// * `response` - The response map where the keypoints were detected.
+/// * `keypoints` - The keypoints with positions in pixels of the response map, refined in
+///   place with their interpolated response.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::features::{non_max_suppression, refine_subpixel};
+///
+/// let response = Image::<f32, 1>::new(
+///     ImageSize { width: 3, height: 3 },
+///     vec![
+///         0.0, 0.0, 0.0,
+///         0.25, 1.0, 0.75,
+///         0.0, 0.0, 0.0,
+///     ],
+/// ).unwrap();
+///
+/// let mut keypoints = non_max_suppression(&response, 3, 0.1, None).unwrap();
+/// refine_subpixel(&response, &mut keypoints);
+///
+/// assert_eq!(keypoints[0].position, [1.25, 1.0]);
+/// ```
+pub fn refine_subpixel(response: &Image<f32, 1>, keypoints: &mut [Keypoint]) {
+    let (rows, cols) = (response.rows(), response.cols());
+    let data = response.as_slice();
+
+    keypoints.par_iter_mut().for_each(|kp| {
+        let (x, y) = (
+            kp.position[0].round() as isize,
+            kp.position[1].round() as isize,
+        );
+        if x < 1 || y < 1 || x as usize + 1 >= cols || y as usize + 1 >= rows {
+            return;
+        }
+
+        let at = |dx: isize, dy: isize| data[(y + dy) as usize * cols + (x + dx) as usize];
+        let v = at(0, 0);
+
+        let gx = 0.5 * (at(1, 0) - at(-1, 0));
+        let gy = 0.5 * (at(0, 1) - at(0, -1));
+        let dxx = at(1, 0) - 2.0 * v + at(-1, 0);
+        let dyy = at(0, 1) - 2.0 * v + at(0, -1);
+        let dxy = 0.25 * (at(1, 1) - at(1, -1) - at(-1, 1) + at(-1, -1));
+
+        // solve hessian * offset = -gradient
+        let det = dxx * dyy - dxy * dxy;
+        if det.abs() < f32::EPSILON {
+            return;
+        }
+        let ox = -(dyy * gx - dxy * gy) / det;
+        let oy = -(dxx * gy - dxy * gx) / det;
+        if ox.abs() > 1.0 || oy.abs() > 1.0 {
+            return;
+        }
+
+        kp.position = [x as f32 + ox, y as f32 + oy];
+        kp.response = v + 0.5 * (gx * ox + gy * oy);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::HarrisResponse;
+
+    #[test]
+    fn test_non_max_suppression() -> Result<(), ImageError> {
+        #[rustfmt::skip]
+        let response = Image::<f32, 1>::new(
+            [6, 4].into(),
+            vec![
+                0.0, 0.0, 0.0, 0.0, 0.0, 0.2,
+                0.0, 0.7, 0.7, 0.0, 0.6, 0.0,
+                0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+                0.3, 0.0, 0.0, 0.8, 0.0, 0.0,
+            ],
+        )?;
+
+        // the plateau keeps its first pixel
+        let positions =
+            |keypoints: Vec<Keypoint>| keypoints.iter().map(|kp| kp.position).collect::<Vec<_>>();
+        let keypoints = non_max_suppression(&response, 3, 0.25, None)?;
+        assert_eq!(
+            positions(keypoints),
+            [[3.0, 3.0], [1.0, 1.0], [4.0, 1.0], [0.0, 3.0]]
+        );
+
+        let keypoints = non_max_suppression(&response, 3, 0.1, Some(2))?;
+        assert_eq!(positions(keypoints), [[3.0, 3.0], [1.0, 1.0]]);
+
+        // the larger window suppresses the peaks two pixels away
+        let keypoints = non_max_suppression(&response, 5, 0.1, None)?;
+        assert_eq!(positions(keypoints), [[3.0, 3.0]]);
+
+        // an equal neighbour that is not a maximum does not suppress the pixel
+        let response = Image::<f32, 1>::new([5, 1].into(), vec![0.9, 0.7, 0.7, 0.0, 0.0])?;
+        let keypoints = non_max_suppression(&response, 3, 0.1, None)?;
+        assert_eq!(positions(keypoints), [[0.0, 0.0], [2.0, 0.0]]);
+
+        assert!(non_max_suppression(&response, 4, 0.1, None).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_harris_corners() -> Result<(), ImageError> {
+        let src = Image::<f32, 1>::new(
+            [9, 9].into(),
+            (0..81)
+                .map(
+                    |i| match (2..7).contains(&(i % 9)) && (2..7).contains(&(i / 9)) {
+                        true => 1.0,
+                        false => 0.0,
+                    },
+                )
+                .collect(),
+        )?;
+
+        let mut response = Image::from_size_val(src.size(), 0.0)?;
+        HarrisResponse::new(src.size()).compute(&src, &mut response)?;
+
+        let mut keypoints = non_max_suppression(&response, 3, 0.1, None)?;
+        refine_subpixel(&response, &mut keypoints);
+
+        // the corners are refined towards the inside of the square
+        let mut positions = keypoints.iter().map(|kp| kp.position).collect::<Vec<_>>();
+        positions.sort_by(|a, b| a[1].total_cmp(&b[1]).then(a[0].total_cmp(&b[0])));
+        assert_eq!(positions.len(), 4);
+        for (p, [cx, cy]) in positions
+            .iter()
+            .zip([[2.0, 2.0], [6.0, 2.0], [2.0, 6.0], [6.0, 6.0]])
+        {
+            assert!((p[0] - cx).abs() < 0.5 && (p[1] - cy).abs() < 0.5, "{p:?}");
+            assert!((p[0] - 4.0).abs() <= (cx - 4.0f32).abs());
+            assert!((p[1] - 4.0).abs() <= (cy - 4.0f32).abs());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_refine_subpixel_quadratic() -> Result<(), ImageError> {
+        // a quadratic surface is fitted exactly
+        let (px, py) = (3.3, 4.6);
+        let response = Image::<f32, 1>::new(
+            [8, 8].into(),
+            (0..64)
+                .map(|i| {
+                    let (x, y) = ((i % 8) as f32 - px, (i / 8) as f32 - py);
+                    10.0 - x * x - 2.0 * y * y - 0.5 * x * y
+                })
+                .collect(),
+        )?;
+
+        let mut keypoints = non_max_suppression(&response, 3, 0.0, None)?;
+        assert_eq!(keypoints.len(), 1);
+        assert_eq!(keypoints[0].position, [3.0, 5.0]);
+
+        refine_subpixel(&response, &mut keypoints);
+        assert!((keypoints[0].position[0] - px).abs() < 1e-4);
+        assert!((keypoints[0].position[1] - py).abs() < 1e-4);
+        assert!((keypoints[0].response - 10.0).abs() < 1e-4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_adaptive_non_max_suppression() {
+        let keypoints = [
+            Keypoint::new([50.0, 1.0], 2.0, 3.0),
+            Keypoint::new([0.0, 0.0], 10.0, 3.0),
+            Keypoint::new([1.0, 0.0], 5.0, 3.0),
+            Keypoint::new([50.0, 0.0], 4.0, 3.0),
+        ];
+
+        // the weak keypoint far from the strongest is kept before the strong neighbours
+        let selected = adaptive_non_max_suppression(&keypoints, 2, 0.9);
+        assert_eq!(selected, [keypoints[1], keypoints[3]]);
+
+        let selected = adaptive_non_max_suppression(&keypoints, 10, 0.9);
+        assert_eq!(selected.len(), 4);
+    }
+}
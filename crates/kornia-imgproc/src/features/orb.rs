@@ -0,0 +1,454 @@
+use std::sync::OnceLock;
+
+use kornia_image::{Image, ImageError, ImageSize};
+use rayon::prelude::*;
+
+use super::{fast_feature_detector, Keypoint};
+use crate::{
+    border::BorderMode,
+    filter::{kernels, separable_filter},
+    interpolation::InterpolationMode,
+    parallel,
+    resize::resize_native,
+};
+
+/// A 256 bits binary descriptor.
+pub type OrbDescriptor = [u8; 32];
+
+/// The diameter of the patch described around a keypoint.
+const PATCH_SIZE: usize = 31;
+
+/// The radius of the circular patch of the orientation.
+const HALF_PATCH_SIZE: isize = 15;
+
+/// The largest coordinate of the pairs of points of the descriptor.
+const PATTERN_RADIUS: i32 = 13;
+
+/// The distance to the image border of the keypoints, enough for the rotated pattern.
+const EDGE_THRESHOLD: usize = 19;
+
+/// The size of the window of the harris response.
+const HARRIS_BLOCK_SIZE: isize = 7;
+
+/// The ORB feature detector and descriptor.
+///
+/// Implements "ORB: an efficient alternative to SIFT or SURF", Rublee et al., ICCV 2011.
+/// The FAST corners of each level of an image pyramid are ranked by their harris response,
+/// oriented with the intensity centroid of their patch and described with 256 binary tests
+/// rotated to the orientation.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::features::OrbDetector;
+///
+/// let image = Image::<u8, 1>::new(
+///     ImageSize { width: 64, height: 64 },
+///     (0..64 * 64).map(|i| if (i % 64) / 16 % 2 == (i / 64) / 16 % 2 { 0 } else { 255 }).collect(),
+/// ).unwrap();
+///
+/// let orb = OrbDetector::new().with_n_features(10);
+///
+/// let (keypoints, descriptors) = orb.detect_and_compute(&image).unwrap();
+///
+/// assert!(keypoints.len() <= 10);
+/// assert_eq!(keypoints.len(), descriptors.len());
+/// ```
+#[derive(Debug, Clone)]
+pub struct OrbDetector {
+    n_features: usize,
+    scale_factor: f32,
+    n_levels: usize,
+    fast_threshold: u8,
+    harris_k: f32,
+}
+
+impl Default for OrbDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OrbDetector {
+    /// Create a detector of 500 features over 8 levels with a scale factor of 1.2.
+    pub fn new() -> Self {
+        Self {
+            n_features: 500,
+            scale_factor: 1.2,
+            n_levels: 8,
+            fast_threshold: 20,
+            harris_k: 0.04,
+        }
+    }
+
+    /// Set the maximum number of features.
+    pub fn with_n_features(mut self, n_features: usize) -> Self {
+        self.n_features = n_features;
+        self
+    }
+
+    /// Set the ratio between the sizes of two consecutive levels of the pyramid.
+    pub fn with_scale_factor(mut self, scale_factor: f32) -> Self {
+        self.scale_factor = scale_factor;
+        self
+    }
+
+    /// Set the number of levels of the pyramid.
+    pub fn with_n_levels(mut self, n_levels: usize) -> Self {
+        self.n_levels = n_levels;
+        self
+    }
+
+    /// Set the threshold of the FAST detector.
+    pub fn with_fast_threshold(mut self, fast_threshold: u8) -> Self {
+        self.fast_threshold = fast_threshold;
+        self
+    }
+
+    /// Set the k parameter of the harris response.
+    pub fn with_harris_k(mut self, harris_k: f32) -> Self {
+        self.harris_k = harris_k;
+        self
+    }
+
+    /// Detect the keypoints of an image and compute their descriptors.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - The input grayscale image.
+    ///
+    /// # Returns
+    ///
+    /// The keypoints, with their position and size in the input image and their pyramid
+    /// level as octave, and their descriptors in the same order.
+    pub fn detect_and_compute(
+        &self,
+        src: &Image<u8, 1>,
+    ) -> Result<(Vec<Keypoint>, Vec<OrbDescriptor>), ImageError> {
+        let levels = self.build_pyramid(src)?;
+
+        // distribute the features over the levels proportionally to their area
+        let factor = 1.0 / self.scale_factor;
+        let first =
+            self.n_features as f32 * (1.0 - factor) / (1.0 - factor.powi(self.n_levels as i32));
+        let mut n_level_features = (0..levels.len())
+            .map(|level| (first * factor.powi(level as i32)).round() as usize)
+            .collect::<Vec<_>>();
+        if let Some((last, others)) = n_level_features.split_last_mut() {
+            *last = self.n_features.saturating_sub(others.iter().sum());
+        }
+
+        let features = levels
+            .par_iter()
+            .zip(n_level_features)
+            .enumerate()
+            .map(|(octave, (level, n_features))| self.detect_level(level, octave, n_features))
+            .collect::<Result<Vec<_>, ImageError>>()?;
+
+        Ok(features.into_iter().flatten().unzip())
+    }
+
+    /// Build the pyramid of the image, stopping at the levels too small for a keypoint.
+    fn build_pyramid(&self, src: &Image<u8, 1>) -> Result<Vec<Image<u8, 1>>, ImageError> {
+        let src_f32 = src.clone().cast::<f32>()?;
+        let mut levels = vec![src.clone()];
+
+        for level in 1..self.n_levels {
+            let scale = self.scale_factor.powi(level as i32);
+            let size = ImageSize {
+                width: (src.cols() as f32 / scale).round() as usize,
+                height: (src.rows() as f32 / scale).round() as usize,
+            };
+            if size.width <= 2 * EDGE_THRESHOLD || size.height <= 2 * EDGE_THRESHOLD {
+                break;
+            }
+
+            let mut resized = Image::<f32, 1>::from_size_val(size, 0.0)?;
+            resize_native(&src_f32, &mut resized, InterpolationMode::Bilinear)?;
+
+            let mut level_u8 = Image::<u8, 1>::from_size_val(size, 0)?;
+            parallel::par_iter_rows_val(&resized, &mut level_u8, |&v, d| {
+                *d = v.round().clamp(0.0, 255.0) as u8;
+            });
+            levels.push(level_u8);
+        }
+
+        Ok(levels)
+    }
+
+    fn detect_level(
+        &self,
+        level: &Image<u8, 1>,
+        octave: usize,
+        n_features: usize,
+    ) -> Result<Vec<(Keypoint, OrbDescriptor)>, ImageError> {
+        let (rows, cols) = (level.rows(), level.cols());
+        if rows <= 2 * EDGE_THRESHOLD || cols <= 2 * EDGE_THRESHOLD || n_features == 0 {
+            return Ok(Vec::new());
+        }
+
+        // the FAST corners away from the border that are maximal in their 3x3 neighbourhood
+        let corners = fast_feature_detector(level, self.fast_threshold, 9)?;
+        let mut scores = vec![0.0; rows * cols];
+        for kp in &corners {
+            scores[kp.position[1] as usize * cols + kp.position[0] as usize] = kp.response;
+        }
+        let is_local_max = |x: usize, y: usize| {
+            let i = y * cols + x;
+            (y - 1..=y + 1)
+                .flat_map(|ny| (x - 1..=x + 1).map(move |nx| ny * cols + nx))
+                .all(|j| scores[i] > scores[j] || (scores[i] == scores[j] && i <= j))
+        };
+
+        let mut candidates = corners
+            .into_iter()
+            .filter_map(|kp| {
+                let (x, y) = (kp.position[0] as usize, kp.position[1] as usize);
+                let inside = (EDGE_THRESHOLD..cols - EDGE_THRESHOLD).contains(&x)
+                    && (EDGE_THRESHOLD..rows - EDGE_THRESHOLD).contains(&y);
+                (inside && is_local_max(x, y))
+                    .then(|| (x, y, harris_score(level, x, y, self.harris_k)))
+            })
+            .collect::<Vec<_>>();
+
+        // keep the strongest corners
+        candidates.sort_by(|a, b| b.2.total_cmp(&a.2));
+        candidates.truncate(n_features);
+
+        // the descriptors compare the pixels of the smoothed level
+        let kernel = kernels::gaussian_kernel_1d(7, 2.0);
+        let mut blurred = Image::<u8, 1>::from_size_val(level.size(), 0)?;
+        separable_filter(
+            level,
+            &mut blurred,
+            &kernel,
+            &kernel,
+            BorderMode::Reflect101,
+        )?;
+
+        let scale = self.scale_factor.powi(octave as i32);
+        let features = candidates
+            .into_iter()
+            .map(|(x, y, response)| {
+                let angle = intensity_centroid_angle(level, x, y);
+                let keypoint = Keypoint {
+                    position: [x as f32 * scale, y as f32 * scale],
+                    response,
+                    angle,
+                    octave,
+                    size: PATCH_SIZE as f32 * scale,
+                };
+                (keypoint, rotated_brief(&blurred, x, y, angle))
+            })
+            .collect();
+
+        Ok(features)
+    }
+}
+
+/// Compute the harris response of the window centered at a pixel.
+fn harris_score(image: &Image<u8, 1>, x: usize, y: usize, k: f32) -> f32 {
+    let cols = image.cols() as isize;
+    let data = image.as_slice();
+    let pixel = |x: isize, y: isize| data[(y * cols + x) as usize] as f32;
+
+    let (mut xx, mut yy, mut xy) = (0.0, 0.0, 0.0);
+    let half = HARRIS_BLOCK_SIZE / 2;
+    for y in y as isize - half..=y as isize + half {
+        for x in x as isize - half..=x as isize + half {
+            let dx = (pixel(x + 1, y - 1) + 2.0 * pixel(x + 1, y) + pixel(x + 1, y + 1))
+                - (pixel(x - 1, y - 1) + 2.0 * pixel(x - 1, y) + pixel(x - 1, y + 1));
+            let dy = (pixel(x - 1, y + 1) + 2.0 * pixel(x, y + 1) + pixel(x + 1, y + 1))
+                - (pixel(x - 1, y - 1) + 2.0 * pixel(x, y - 1) + pixel(x + 1, y - 1));
+            xx += dx * dx;
+            yy += dy * dy;
+            xy += dx * dy;
+        }
+    }
+
+    // normalize the sums of the squared sobel derivatives of the 8 bits pixels
+    let norm = 1.0 / (4.0 * 255.0 * HARRIS_BLOCK_SIZE as f32);
+    let (xx, yy, xy) = (xx * norm * norm, yy * norm * norm, xy * norm * norm);
+    xx * yy - xy * xy - k * (xx + yy) * (xx + yy)
+}
+
+/// Compute the orientation in degrees of the vector to the intensity centroid of the patch.
+fn intensity_centroid_angle(image: &Image<u8, 1>, x: usize, y: usize) -> f32 {
+    let cols = image.cols() as isize;
+    let data = image.as_slice();
+
+    let (mut m10, mut m01) = (0i64, 0i64);
+    for dy in -HALF_PATCH_SIZE..=HALF_PATCH_SIZE {
+        for dx in -HALF_PATCH_SIZE..=HALF_PATCH_SIZE {
+            if dx * dx + dy * dy > HALF_PATCH_SIZE * HALF_PATCH_SIZE {
+                continue;
+            }
+            let v = data[((y as isize + dy) * cols + x as isize + dx) as usize] as i64;
+            m10 += dx as i64 * v;
+            m01 += dy as i64 * v;
+        }
+    }
+
+    (m01 as f32)
+        .atan2(m10 as f32)
+        .to_degrees()
+        .rem_euclid(360.0)
+}
+
+/// Compute the binary tests of the pattern rotated to the orientation of a keypoint.
+fn rotated_brief(image: &Image<u8, 1>, x: usize, y: usize, angle: f32) -> OrbDescriptor {
+    let cols = image.cols() as isize;
+    let data = image.as_slice();
+    let (sin, cos) = angle.to_radians().sin_cos();
+
+    let pixel = |[px, py]: [i32; 2]| {
+        let (px, py) = (px as f32, py as f32);
+        let rx = (px * cos - py * sin).round() as isize;
+        let ry = (px * sin + py * cos).round() as isize;
+        data[((y as isize + ry) * cols + x as isize + rx) as usize]
+    };
+
+    let mut descriptor = [0u8; 32];
+    for (bit, [p1, p2]) in brief_pattern().iter().enumerate() {
+        if pixel(*p1) < pixel(*p2) {
+            descriptor[bit / 8] |= 1 << (bit % 8);
+        }
+    }
+
+    descriptor
+}
+
+/// The pairs of points of the binary tests.
+///
+/// The points are sampled once from an isotropic gaussian of standard deviation
+/// `PATCH_SIZE / 5`, as the second sampling strategy of Calonder et al., with a fixed seed.
+fn brief_pattern() -> &'static [[[i32; 2]; 2]; 256] {
+    static PATTERN: OnceLock<[[[i32; 2]; 2]; 256]> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut uniform = move || {
+            // xorshift64
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+
+        // the sum of 12 uniform samples is approximately gaussian with unit variance
+        let sigma = PATCH_SIZE as f64 / 5.0;
+        let mut coordinate = move || {
+            let g = (0..12).map(|_| uniform()).sum::<f64>() - 6.0;
+            ((g * sigma).round() as i32).clamp(-PATTERN_RADIUS, PATTERN_RADIUS)
+        };
+
+        std::array::from_fn(|_| std::array::from_fn(|_| [coordinate(), coordinate()]))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A deterministic image with blobs of different sizes and intensities.
+    fn blobs(size: usize) -> Result<Image<u8, 1>, ImageError> {
+        Image::new(
+            [size, size].into(),
+            (0..size * size)
+                .map(|i| {
+                    let (x, y) = ((i % size) as f32, (i / size) as f32);
+                    let v = 128.0
+                        + 60.0 * (x * 0.21).sin() * (y * 0.13).cos()
+                        + 50.0 * ((x + 2.0 * y) * 0.07).sin();
+                    v as u8
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn orb_orientation() -> Result<(), ImageError> {
+        // a ramp brighter to the right, then down
+        let size = [41, 41].into();
+        let right = Image::<u8, 1>::new(size, (0..41 * 41).map(|i| (i % 41 * 5) as u8).collect())?;
+        let down = Image::<u8, 1>::new(size, (0..41 * 41).map(|i| (i / 41 * 5) as u8).collect())?;
+
+        assert!(intensity_centroid_angle(&right, 20, 20).abs() < 1e-3);
+        assert!((intensity_centroid_angle(&down, 20, 20) - 90.0).abs() < 1e-3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn orb_descriptor_rotation_invariant() -> Result<(), ImageError> {
+        let n = 61;
+        let image = blobs(n)?;
+
+        // rotate the image by 90 degrees, the pixel (x, y) moves to (n - 1 - y, x)
+        let mut rotated = Image::<u8, 1>::from_size_val(image.size(), 0)?;
+        for y in 0..n {
+            for x in 0..n {
+                rotated.as_slice_mut()[x * n + n - 1 - y] = image.as_slice()[y * n + x];
+            }
+        }
+
+        let (x, y) = (27, 33);
+        let (rx, ry) = (n - 1 - y, x);
+
+        let angle = intensity_centroid_angle(&image, x, y);
+        let rotated_angle = intensity_centroid_angle(&rotated, rx, ry);
+        assert!(((rotated_angle - angle).rem_euclid(360.0) - 90.0).abs() < 1e-3);
+
+        let descriptor = rotated_brief(&image, x, y, angle);
+        let rotated_descriptor = rotated_brief(&rotated, rx, ry, rotated_angle);
+        let distance = descriptor
+            .iter()
+            .zip(rotated_descriptor)
+            .map(|(a, b)| (a ^ b).count_ones())
+            .sum::<u32>();
+        assert!(distance <= 4, "{distance}");
+
+        // the descriptor of another keypoint is far
+        let other = rotated_brief(&image, 35, 22, intensity_centroid_angle(&image, 35, 22));
+        let distance = descriptor
+            .iter()
+            .zip(other)
+            .map(|(a, b)| (a ^ b).count_ones())
+            .sum::<u32>();
+        assert!(distance > 40, "{distance}");
+
+        Ok(())
+    }
+
+    #[test]
+    fn orb_detect_and_compute() -> Result<(), ImageError> {
+        let image = blobs(160)?;
+        let orb = OrbDetector::new()
+            .with_n_features(50)
+            .with_n_levels(3)
+            .with_fast_threshold(10);
+
+        let (keypoints, descriptors) = orb.detect_and_compute(&image)?;
+        assert!(!keypoints.is_empty() && keypoints.len() <= 50);
+        assert_eq!(keypoints.len(), descriptors.len());
+
+        for kp in &keypoints {
+            let scale = 1.2f32.powi(kp.octave as i32);
+            let margin = EDGE_THRESHOLD as f32 * scale - 1e-3;
+            assert!(kp.octave < 3);
+            assert!((margin..160.0 - margin).contains(&kp.position[0]));
+            assert!((margin..160.0 - margin).contains(&kp.position[1]));
+            assert!((0.0..360.0).contains(&kp.angle));
+            assert_eq!(kp.size, 31.0 * scale);
+        }
+
+        // the pattern points are inside the patch
+        assert!(brief_pattern()
+            .iter()
+            .flatten()
+            .flatten()
+            .all(|c| c.abs() <= PATTERN_RADIUS));
+
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use crate::border::BorderMode;
 use crate::filter::gaussian_blur;
 use kornia_image::{Image, ImageError, ImageSize};
 use rayon::prelude::*;
@@ -305,8 +306,20 @@ pub fn dog_response(
     let ks1 = _get_kernel_size(sigma1);
     let ks2 = _get_kernel_size(sigma2);
 
-    gaussian_blur(src, &mut gauss1, (ks1, ks1), (sigma1, sigma1))?;
-    gaussian_blur(src, &mut gauss2, (ks2, ks2), (sigma2, sigma2))?;
+    gaussian_blur(
+        src,
+        &mut gauss1,
+        (ks1, ks1),
+        (sigma1, sigma1),
+        BorderMode::Constant(0.0),
+    )?;
+    gaussian_blur(
+        src,
+        &mut gauss2,
+        (ks2, ks2),
+        (sigma2, sigma2),
+        BorderMode::Constant(0.0),
+    )?;
 
     let gauss1_data = gauss1.as_slice();
     let gauss2_data = gauss2.as_slice();
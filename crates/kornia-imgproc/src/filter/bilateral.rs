@@ -0,0 +1,187 @@
+use kornia_image::{AsImageView, AsImageViewMut, ImageError};
+
+use crate::{border::BorderMode, parallel};
+
+/// Blur an image using a bilateral filter.
+///
+/// Each pixel is replaced by the mean of its circular neighbourhood, weighted by a gaussian
+/// of the distance to the pixel and a gaussian of the difference of color, so that the
+/// edges are preserved. The color difference is the euclidean distance over the channels.
+///
+/// # Arguments
+///
+/// * `src` - The source image with shape (H, W, C).
+/// * `dst` - The destination image with shape (H, W, C).
+/// * `kernel_size` - The diameter of the neighbourhood, must be odd.
+/// * `sigma_color` - The sigma of the gaussian of the color difference.
+/// * `sigma_space` - The sigma of the gaussian of the distance.
+/// * `border` - The extrapolation of the pixels outside the image.
+///
+/// # Errors
+///
+/// If the kernel size is not odd, if a sigma is not positive, or if the images do not have
+/// the same size, an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::border::BorderMode;
+/// use kornia_imgproc::filter::bilateral_filter;
+///
+/// let image = Image::<f32, 1>::new(
+///     ImageSize { width: 4, height: 1 },
+///     vec![0.0, 0.0, 100.0, 100.0],
+/// ).unwrap();
+///
+/// let mut dst = Image::<f32, 1>::from_size_val(image.size(), 0.0).unwrap();
+///
+/// bilateral_filter(&image, &mut dst, 3, 10.0, 1.0, BorderMode::Replicate).unwrap();
+///
+/// // the edge is kept
+/// assert!(dst.as_slice()[1] < 1e-3);
+/// assert!(dst.as_slice()[2] > 100.0 - 1e-3);
+/// ```
+pub fn bilateral_filter<const C: usize>(
+    src: &impl AsImageView<f32, C>,
+    dst: &mut impl AsImageViewMut<f32, C>,
+    kernel_size: usize,
+    sigma_color: f32,
+    sigma_space: f32,
+    border: BorderMode,
+) -> Result<(), ImageError> {
+    let src = src.as_view();
+    let mut dst = dst.as_view_mut();
+
+    if kernel_size % 2 == 0 {
+        return Err(ImageError::InvalidKernelLength(kernel_size, kernel_size));
+    }
+
+    if sigma_color <= 0.0 || sigma_space <= 0.0 {
+        return Err(ImageError::InvalidSigmaValue(sigma_color, sigma_space));
+    }
+
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    // the offsets inside the circle and their spatial weight
+    let half = (kernel_size / 2) as isize;
+    let space_coeff = -0.5 / (sigma_space * sigma_space);
+    let taps = (-half..=half)
+        .flat_map(|dy| (-half..=half).map(move |dx| (dy, dx)))
+        .filter(|(dy, dx)| dy * dy + dx * dx <= half * half)
+        .map(|(dy, dx)| (dy, dx, ((dy * dy + dx * dx) as f32 * space_coeff).exp()))
+        .collect::<Vec<_>>();
+
+    let color_coeff = -0.5 / (sigma_color * sigma_color);
+    let constant = [border.constant_value(); C];
+
+    parallel::par_iter_rows_indexed_mut(&mut dst, |r, dst_row| {
+        let src_row = src.row(r);
+        for (c, dst_pixel) in dst_row.chunks_exact_mut(C).enumerate() {
+            let center = &src_row[c * C..(c + 1) * C];
+
+            let mut sum = [0.0; C];
+            let mut norm = 0.0;
+            for &(dy, dx, space_weight) in &taps {
+                let y = border.index(r as isize + dy, src.rows());
+                let x = border.index(c as isize + dx, src.cols());
+                let pixel = match (y, x) {
+                    (Some(y), Some(x)) => &src.row(y)[x * C..(x + 1) * C],
+                    _ => &constant[..],
+                };
+
+                let dist = pixel
+                    .iter()
+                    .zip(center)
+                    .map(|(p, q)| (p - q) * (p - q))
+                    .sum::<f32>();
+                let weight = space_weight * (dist * color_coeff).exp();
+
+                sum.iter_mut()
+                    .zip(pixel)
+                    .for_each(|(s, p)| *s += weight * p);
+                norm += weight;
+            }
+
+            dst_pixel
+                .iter_mut()
+                .zip(sum)
+                .for_each(|(d, s)| *d = s / norm);
+        }
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kornia_image::{Image, ImageSize};
+
+    #[test]
+    fn bilateral_filter_edge() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 8,
+            height: 6,
+        };
+
+        // a vertical edge between two noisy regions
+        let image = Image::<f32, 3>::new(
+            size,
+            (0..size.width * size.height * 3)
+                .map(|i| {
+                    let noise = ((i * 7) % 5) as f32 * 0.2;
+                    match (i / 3) % size.width < 4 {
+                        true => 10.0 + noise,
+                        false => 200.0 + noise,
+                    }
+                })
+                .collect(),
+        )?;
+
+        let mut dst = Image::<f32, 3>::from_size_val(size, 0.0)?;
+        bilateral_filter(&image, &mut dst, 5, 20.0, 2.0, BorderMode::Reflect101)?;
+
+        for (i, &v) in dst.as_slice().iter().enumerate() {
+            match (i / 3) % size.width < 4 {
+                true => assert!((10.0..=10.8).contains(&v), "{i}: {v}"),
+                false => assert!((200.0..=200.8).contains(&v), "{i}: {v}"),
+            }
+        }
+
+        // with a large sigma of the color it is a gaussian blur over the circle
+        let image = Image::<f32, 1>::new(size, (0..48).map(|i| i as f32).collect())?;
+        let mut dst = Image::<f32, 1>::from_size_val(size, 0.0)?;
+        bilateral_filter(&image, &mut dst, 3, 1e6, 1.0, BorderMode::Constant(0.0))?;
+
+        // the center and its 4 neighbours, a linear image is kept inside
+        assert!((dst.as_slice()[8 + 1] - 9.0).abs() < 1e-3);
+        let corner = 9.0 * (-0.5f32).exp() / (1.0 + 2.0 * (-0.5f32).exp() + 2.0 * (-0.5f32).exp());
+        assert!((dst.as_slice()[0] - corner).abs() < 1e-3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn bilateral_filter_invalid_sigma() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 4,
+            height: 4,
+        };
+        let image = Image::<f32, 1>::from_size_val(size, 1.0)?;
+        let mut dst = Image::<f32, 1>::from_size_val(size, 0.0)?;
+
+        let border = BorderMode::Replicate;
+        assert!(bilateral_filter(&image, &mut dst, 3, 0.0, 1.0, border).is_err());
+        assert!(bilateral_filter(&image, &mut dst, 3, 1.0, 0.0, border).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,354 @@
+use kornia_image::{AsImageView, AsImageViewMut, ImageError, ImageView};
+use rayon::prelude::*;
+use rustfft::{num_complex::Complex, FftDirection, FftPlanner};
+
+use super::FloatConversion;
+use crate::{border::BorderMode, parallel};
+
+/// The number of kernel elements from which the filter is computed in the frequency domain.
+const FFT_MIN_KERNEL_AREA: usize = 15 * 15;
+
+/// Apply a 2D filter with an arbitrary kernel to an image.
+///
+/// The output is the correlation of the image with the kernel, centered at the pixel
+/// `(kernel_width / 2, kernel_height / 2)` of the kernel, as `filter2D` in OpenCV.
+///
+/// The small kernels are applied directly, the large kernels in the frequency domain.
+///
+/// # Arguments
+///
+/// * `src` - The source image with shape (H, W, C).
+/// * `dst` - The destination image with shape (H, W, C).
+/// * `kernel` - The kernel coefficients in row-major order.
+/// * `kernel_size` - The size of the kernel (kernel_x, kernel_y).
+/// * `border` - The extrapolation of the pixels outside the image.
+///
+/// # Errors
+///
+/// If the kernel is empty or its length does not match its size, or if the images do not
+/// have the same size, an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::border::BorderMode;
+/// use kornia_imgproc::filter::filter2d;
+///
+/// let image = Image::<f32, 1>::new(
+///     ImageSize { width: 3, height: 2 },
+///     vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0],
+/// ).unwrap();
+///
+/// let mut dst = Image::<f32, 1>::from_size_val(image.size(), 0.0).unwrap();
+///
+/// // the difference with the right neighbour
+/// filter2d(&image, &mut dst, &[0.0, -1.0, 1.0], (3, 1), BorderMode::Replicate).unwrap();
+///
+/// assert_eq!(dst.as_slice(), &[1.0, 1.0, 0.0, 1.0, 1.0, 0.0]);
+/// ```
+pub fn filter2d<T, const C: usize>(
+    src: &impl AsImageView<T, C>,
+    dst: &mut impl AsImageViewMut<T, C>,
+    kernel: &[f32],
+    kernel_size: (usize, usize),
+    border: BorderMode,
+) -> Result<(), ImageError>
+where
+    T: FloatConversion + Copy + Send + Sync,
+{
+    match kernel_size.0 * kernel_size.1 < FFT_MIN_KERNEL_AREA {
+        true => filter2d_impl(src, dst, kernel, kernel_size, border, filter2d_direct::<C>),
+        false => filter2d_impl(src, dst, kernel, kernel_size, border, filter2d_fft::<C>),
+    }
+}
+
+/// The image extended with the border, with shape (H + kernel_y - 1, W + kernel_x - 1, C).
+struct Padded<const C: usize> {
+    data: Vec<f32>,
+    rows: usize,
+    cols: usize,
+}
+
+fn filter2d_impl<T, const C: usize>(
+    src: &impl AsImageView<T, C>,
+    dst: &mut impl AsImageViewMut<T, C>,
+    kernel: &[f32],
+    kernel_size: (usize, usize),
+    border: BorderMode,
+    correlate: impl Fn(&Padded<C>, &[f32], (usize, usize), &mut [f32]),
+) -> Result<(), ImageError>
+where
+    T: FloatConversion + Copy + Send + Sync,
+{
+    let src = src.as_view();
+    let mut dst = dst.as_view_mut();
+
+    let (kw, kh) = kernel_size;
+    if kernel.is_empty() || kernel.len() != kw * kh {
+        return Err(ImageError::InvalidKernelLength(kernel.len(), kw * kh));
+    }
+
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    if src.rows() == 0 || src.cols() == 0 {
+        return Ok(());
+    }
+
+    let padded = pad(&src, kernel_size, border);
+
+    let cols = src.cols();
+    let mut out = vec![0.0f32; src.rows() * cols * C];
+    correlate(&padded, kernel, kernel_size, &mut out);
+
+    parallel::par_iter_rows_indexed_mut(&mut dst, |r, dst_row| {
+        dst_row
+            .iter_mut()
+            .zip(&out[r * cols * C..(r + 1) * cols * C])
+            .for_each(|(d, &v)| *d = T::from_f32(v));
+    });
+
+    Ok(())
+}
+
+/// Extend the image with the border so that every tap of the kernel is inside.
+fn pad<T: FloatConversion + Sync, const C: usize>(
+    src: &ImageView<T, C>,
+    (kw, kh): (usize, usize),
+    border: BorderMode,
+) -> Padded<C> {
+    let (rows, cols) = (src.rows() + kh - 1, src.cols() + kw - 1);
+    let (top, left) = ((kh / 2) as isize, (kw / 2) as isize);
+    let mut data = vec![border.constant_value(); rows * cols * C];
+
+    data.par_chunks_mut(cols * C)
+        .enumerate()
+        .for_each(|(r, row)| {
+            let Some(y) = border.index(r as isize - top, src.rows()) else {
+                return;
+            };
+            let src_row = src.row(y);
+            for (c, pixel) in row.chunks_exact_mut(C).enumerate() {
+                if let Some(x) = border.index(c as isize - left, src.cols()) {
+                    pixel
+                        .iter_mut()
+                        .zip(&src_row[x * C..(x + 1) * C])
+                        .for_each(|(p, v)| *p = v.to_f32());
+                }
+            }
+        });
+
+    Padded { data, rows, cols }
+}
+
+/// Correlate the padded image with the kernel in the spatial domain.
+fn filter2d_direct<const C: usize>(
+    padded: &Padded<C>,
+    kernel: &[f32],
+    (kw, _): (usize, usize),
+    out: &mut [f32],
+) {
+    let out_cols = padded.cols + 1 - kw;
+
+    out.par_chunks_mut(out_cols * C)
+        .enumerate()
+        .for_each(|(r, out_row)| {
+            for (ky, kernel_row) in kernel.chunks_exact(kw).enumerate() {
+                let row = &padded.data[(r + ky) * padded.cols * C..(r + ky + 1) * padded.cols * C];
+                for (kx, &k) in kernel_row.iter().enumerate() {
+                    if k == 0.0 {
+                        continue;
+                    }
+                    out_row
+                        .iter_mut()
+                        .zip(&row[kx * C..])
+                        .for_each(|(o, &v)| *o += v * k);
+                }
+            }
+        });
+}
+
+/// Correlate the padded image with the kernel in the frequency domain.
+///
+/// The circular correlation over the padded image does not wrap around for the pixels of
+/// the output, so no further padding is needed.
+fn filter2d_fft<const C: usize>(
+    padded: &Padded<C>,
+    kernel: &[f32],
+    (kw, kh): (usize, usize),
+    out: &mut [f32],
+) {
+    let (rows, cols) = (padded.rows, padded.cols);
+    let (out_rows, out_cols) = (rows + 1 - kh, cols + 1 - kw);
+    let mut planner = FftPlanner::new();
+
+    let mut kernel_freq = vec![Complex::new(0.0, 0.0); rows * cols];
+    for (ky, kernel_row) in kernel.chunks_exact(kw).enumerate() {
+        for (kx, &k) in kernel_row.iter().enumerate() {
+            kernel_freq[ky * cols + kx].re = k;
+        }
+    }
+    fft2d(
+        &mut planner,
+        &mut kernel_freq,
+        rows,
+        cols,
+        FftDirection::Forward,
+    );
+
+    let scale = 1.0 / (rows * cols) as f32;
+    for ch in 0..C {
+        let mut freq = padded
+            .data
+            .iter()
+            .skip(ch)
+            .step_by(C)
+            .map(|&v| Complex::new(v, 0.0))
+            .collect::<Vec<_>>();
+
+        fft2d(&mut planner, &mut freq, rows, cols, FftDirection::Forward);
+        freq.par_iter_mut()
+            .zip(&kernel_freq)
+            .for_each(|(f, k)| *f *= k.conj());
+        fft2d(&mut planner, &mut freq, rows, cols, FftDirection::Inverse);
+
+        for r in 0..out_rows {
+            for c in 0..out_cols {
+                out[(r * out_cols + c) * C + ch] = freq[r * cols + c].re * scale;
+            }
+        }
+    }
+}
+
+/// Compute the unnormalized 2D discrete Fourier transform of a row-major buffer in place.
+fn fft2d(
+    planner: &mut FftPlanner<f32>,
+    data: &mut [Complex<f32>],
+    rows: usize,
+    cols: usize,
+    direction: FftDirection,
+) {
+    let row_fft = planner.plan_fft(cols, direction);
+    data.par_chunks_mut(cols)
+        .for_each(|row| row_fft.process(row));
+
+    // the columns are transformed as the rows of the transposed buffer
+    let col_fft = planner.plan_fft(rows, direction);
+    let mut transposed = vec![Complex::new(0.0, 0.0); rows * cols];
+    transpose(data, &mut transposed, rows, cols);
+    transposed
+        .par_chunks_mut(rows)
+        .for_each(|col| col_fft.process(col));
+    transpose(&transposed, data, cols, rows);
+}
+
+/// Transpose a row-major buffer with shape (rows, cols).
+fn transpose(src: &[Complex<f32>], dst: &mut [Complex<f32>], rows: usize, cols: usize) {
+    dst.par_chunks_mut(rows)
+        .enumerate()
+        .for_each(|(c, dst_row)| {
+            dst_row
+                .iter_mut()
+                .enumerate()
+                .for_each(|(r, d)| *d = src[r * cols + c]);
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kornia_image::{Image, ImageSize};
+
+    #[test]
+    fn filter2d_kernel_anchor() -> Result<(), ImageError> {
+        #[rustfmt::skip]
+        let image = Image::<u8, 1>::new(
+            ImageSize { width: 4, height: 3 },
+            vec![
+                0, 0, 0, 0,
+                0, 10, 0, 0,
+                0, 0, 0, 0,
+            ],
+        )?;
+
+        // the correlation flips the kernel around its center
+        #[rustfmt::skip]
+        let kernel = [
+            1.0, 2.0,
+            3.0, 4.0,
+        ];
+
+        let mut dst = Image::<u8, 1>::from_size_val(image.size(), 0)?;
+        filter2d(&image, &mut dst, &kernel, (2, 2), BorderMode::Constant(0.0))?;
+
+        #[rustfmt::skip]
+        assert_eq!(dst.as_slice(), &[
+            0, 0, 0, 0,
+            0, 40, 30, 0,
+            0, 20, 10, 0,
+        ]);
+
+        let mut dst = Image::<u8, 1>::from_size_val(image.size(), 0)?;
+        let result = filter2d(&image, &mut dst, &kernel, (3, 1), BorderMode::Replicate);
+        assert!(matches!(result, Err(ImageError::InvalidKernelLength(4, 3))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn filter2d_fft_matches_direct() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 23,
+            height: 17,
+        };
+        let image = Image::<f32, 2>::new(
+            size,
+            (0..size.width * size.height * 2)
+                .map(|i| ((i * 37) % 101) as f32)
+                .collect(),
+        )?;
+
+        let kernel_size = (5, 4);
+        let kernel = (0..20).map(|i| (i as f32 - 7.5) / 10.0).collect::<Vec<_>>();
+
+        for border in [
+            BorderMode::Constant(3.0),
+            BorderMode::Replicate,
+            BorderMode::Reflect,
+            BorderMode::Reflect101,
+            BorderMode::Wrap,
+        ] {
+            let mut direct = Image::<f32, 2>::from_size_val(size, 0.0)?;
+            let mut fft = Image::<f32, 2>::from_size_val(size, 0.0)?;
+            filter2d_impl(
+                &image,
+                &mut direct,
+                &kernel,
+                kernel_size,
+                border,
+                filter2d_direct::<2>,
+            )?;
+            filter2d_impl(
+                &image,
+                &mut fft,
+                &kernel,
+                kernel_size,
+                border,
+                filter2d_fft::<2>,
+            )?;
+
+            for (a, b) in direct.as_slice().iter().zip(fft.as_slice()) {
+                assert!((a - b).abs() < 1e-2, "{border:?}: {a} != {b}");
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,178 @@
+use kornia_image::{AsImageView, AsImageViewMut, Image, ImageError};
+
+use super::box_blur;
+use crate::{border::BorderMode, parallel};
+
+/// Smooth an image with the guided filter of He et al.
+///
+/// The output is locally a linear transform of the grayscale guide, fitted to the source in
+/// each square window, so that the edges of the guide are transferred to the output.
+/// Implements "Guided Image Filtering", TPAMI 2013.
+///
+/// # Arguments
+///
+/// * `guide` - The guide image with shape (H, W, 1).
+/// * `src` - The source image with shape (H, W, C).
+/// * `dst` - The destination image with shape (H, W, C).
+/// * `radius` - The radius of the square windows.
+/// * `eps` - The regularization of the linear transforms, larger values smooth more.
+/// * `border` - The extrapolation of the pixels outside the image in the window means.
+///
+/// # Errors
+///
+/// If the images do not have the same size, an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::border::BorderMode;
+/// use kornia_imgproc::filter::guided_filter;
+///
+/// let image = Image::<f32, 1>::new(
+///     ImageSize { width: 4, height: 1 },
+///     vec![0.0, 0.0, 1.0, 1.0],
+/// ).unwrap();
+///
+/// let mut dst = Image::<f32, 1>::from_size_val(image.size(), 0.0).unwrap();
+///
+/// guided_filter(&image, &image, &mut dst, 1, 1e-6, BorderMode::Reflect101).unwrap();
+///
+/// assert!((dst.as_slice()[1] - 0.0).abs() < 1e-3);
+/// assert!((dst.as_slice()[2] - 1.0).abs() < 1e-3);
+/// ```
+pub fn guided_filter<const C: usize>(
+    guide: &impl AsImageView<f32, 1>,
+    src: &impl AsImageView<f32, C>,
+    dst: &mut impl AsImageViewMut<f32, C>,
+    radius: usize,
+    eps: f32,
+    border: BorderMode,
+) -> Result<(), ImageError> {
+    let guide = guide.as_view();
+    let src = src.as_view();
+    let mut dst = dst.as_view_mut();
+
+    for (cols, rows) in [(src.cols(), src.rows()), (dst.cols(), dst.rows())] {
+        if (cols, rows) != (guide.cols(), guide.rows()) {
+            return Err(ImageError::InvalidImageSize(
+                guide.cols(),
+                guide.rows(),
+                cols,
+                rows,
+            ));
+        }
+    }
+
+    let size = guide.size();
+    let kernel_size = (2 * radius + 1, 2 * radius + 1);
+
+    // the products of the guide with itself and with the source
+    let mut guide_sq = Image::<f32, 1>::from_size_val(size, 0.0)?;
+    parallel::par_iter_rows_val(&guide, &mut guide_sq, |&i, ii| *ii = i * i);
+
+    let mut guide_src = Image::<f32, C>::from_size_val(size, 0.0)?;
+    parallel::par_iter_rows_indexed_mut(&mut guide_src, |r, row| {
+        for ((ip, p), &i) in row
+            .chunks_exact_mut(C)
+            .zip(src.row(r).chunks_exact(C))
+            .zip(guide.row(r))
+        {
+            ip.iter_mut().zip(p).for_each(|(ip, p)| *ip = i * p);
+        }
+    });
+
+    // the means over the windows
+    let mut mean_i = Image::<f32, 1>::from_size_val(size, 0.0)?;
+    let mut mean_ii = Image::<f32, 1>::from_size_val(size, 0.0)?;
+    let mut mean_p = Image::<f32, C>::from_size_val(size, 0.0)?;
+    let mut mean_ip = Image::<f32, C>::from_size_val(size, 0.0)?;
+    box_blur(&guide, &mut mean_i, kernel_size, border)?;
+    box_blur(&guide_sq, &mut mean_ii, kernel_size, border)?;
+    box_blur(&src, &mut mean_p, kernel_size, border)?;
+    box_blur(&guide_src, &mut mean_ip, kernel_size, border)?;
+
+    // the coefficients of the linear transform q = a * I + b in each window
+    let mut a = Image::<f32, C>::from_size_val(size, 0.0)?;
+    let mut b = Image::<f32, C>::from_size_val(size, 0.0)?;
+    let (mean_i, mean_ii) = (mean_i.view(), mean_ii.view());
+    let (mean_p, mean_ip) = (mean_p.view(), mean_ip.view());
+    parallel::par_iter_rows_indexed_mut(&mut a, |r, row| {
+        for (c, pixel) in row.chunks_exact_mut(C).enumerate() {
+            let (mi, mii) = (mean_i.row(r)[c], mean_ii.row(r)[c]);
+            let var = mii - mi * mi;
+            for (ch, a) in pixel.iter_mut().enumerate() {
+                let cov = mean_ip.row(r)[c * C + ch] - mi * mean_p.row(r)[c * C + ch];
+                *a = cov / (var + eps);
+            }
+        }
+    });
+    parallel::par_iter_rows_indexed_mut(&mut b, |r, row| {
+        let a = a.view();
+        for (c, pixel) in row.chunks_exact_mut(C).enumerate() {
+            for (ch, b) in pixel.iter_mut().enumerate() {
+                let i = c * C + ch;
+                *b = mean_p.row(r)[i] - a.row(r)[i] * mean_i.row(r)[c];
+            }
+        }
+    });
+
+    // average the transforms of the windows covering each pixel
+    let mut mean_a = Image::<f32, C>::from_size_val(size, 0.0)?;
+    let mut mean_b = Image::<f32, C>::from_size_val(size, 0.0)?;
+    box_blur(&a, &mut mean_a, kernel_size, border)?;
+    box_blur(&b, &mut mean_b, kernel_size, border)?;
+
+    let (mean_a, mean_b) = (mean_a.view(), mean_b.view());
+    parallel::par_iter_rows_indexed_mut(&mut dst, |r, row| {
+        for (c, pixel) in row.chunks_exact_mut(C).enumerate() {
+            let i = guide.row(r)[c];
+            for (ch, q) in pixel.iter_mut().enumerate() {
+                *q = mean_a.row(r)[c * C + ch] * i + mean_b.row(r)[c * C + ch];
+            }
+        }
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kornia_image::ImageSize;
+
+    #[test]
+    fn guided_filter_limits() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 7,
+            height: 5,
+        };
+        let guide = Image::<f32, 1>::new(size, (0..35).map(|i| ((i * 13) % 10) as f32).collect())?;
+        let src =
+            Image::<f32, 2>::new(size, (0..70).map(|i| ((i * 7) % 9) as f32 / 9.0).collect())?;
+
+        // a guide equal to the source with a small eps is the identity
+        let mut dst = Image::<f32, 1>::from_size_val(size, 0.0)?;
+        guided_filter(&guide, &guide, &mut dst, 2, 1e-6, BorderMode::Reflect101)?;
+        for (d, g) in dst.as_slice().iter().zip(guide.as_slice()) {
+            assert!((d - g).abs() < 1e-3);
+        }
+
+        // a large eps averages the box means of the source
+        let mut dst = Image::<f32, 2>::from_size_val(size, 0.0)?;
+        guided_filter(&guide, &src, &mut dst, 1, 1e9, BorderMode::Reflect101)?;
+
+        let mut mean = Image::<f32, 2>::from_size_val(size, 0.0)?;
+        let mut expected = Image::<f32, 2>::from_size_val(size, 0.0)?;
+        box_blur(&src, &mut mean, (3, 3), BorderMode::Reflect101)?;
+        box_blur(&mean, &mut expected, (3, 3), BorderMode::Reflect101)?;
+        for (d, e) in dst.as_slice().iter().zip(expected.as_slice()) {
+            assert!((d - e).abs() < 1e-4);
+        }
+
+        let mut small = Image::<f32, 2>::from_size_val([3, 3].into(), 0.0)?;
+        assert!(guided_filter(&guide, &src, &mut small, 1, 1.0, BorderMode::Replicate).is_err());
+
+        Ok(())
+    }
+}
@@ -85,6 +85,35 @@ pub fn normalized_sobel_kernel3() -> ([[f32; 3]; 3], [[f32; 3]; 3]) {
     )
 }
 
+/// Create a laplacian kernel.
+///
+/// # Arguments
+///
+/// * `kernel_size` - The size of the kernel, 1 for the 4-neighbour kernel or 3.
+///
+/// # Returns
+///
+/// A vector of the 3x3 kernel in row-major order.
+pub fn laplacian_kernel(kernel_size: usize) -> Vec<f32> {
+    match kernel_size {
+        1 => vec![0.0, 1.0, 0.0, 1.0, -4.0, 1.0, 0.0, 1.0, 0.0],
+        3 => vec![2.0, 0.0, 2.0, 0.0, -8.0, 0.0, 2.0, 0.0, 2.0],
+        _ => panic!("Invalid kernel size for laplacian kernel"),
+    }
+}
+
+/// Create the scharr kernels.
+///
+/// # Returns
+///
+/// A tuple of the 3x3 kernels in row-major order. (dx_kernel, dy_kernel)
+pub fn scharr_kernel3() -> (Vec<f32>, Vec<f32>) {
+    (
+        vec![-3.0, 0.0, 3.0, -10.0, 0.0, 10.0, -3.0, 0.0, 3.0],
+        vec![-3.0, -10.0, -3.0, 0.0, 0.0, 0.0, 3.0, 10.0, 3.0],
+    )
+}
+
 /// Create list of optimized box blur kernels based on gaussian sigma
 ///
 /// <https://www.peterkovesi.com/papers/FastGaussianSmoothing.pdf>
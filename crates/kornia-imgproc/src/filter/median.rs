@@ -0,0 +1,312 @@
+use kornia_image::{AsImageView, AsImageViewMut, ImageError, ImageView, ImageViewMut};
+
+use super::FloatConversion;
+use crate::{border::BorderMode, parallel};
+
+/// The number of fine bins grouped in a coarse bin of the histograms.
+const COARSE_BIN: usize = 16;
+
+/// Blur an image using a median filter.
+///
+/// Each pixel is replaced by the median of its square neighbourhood, sorting the window of
+/// every pixel. Use [`median_blur_u8`] for the faster histogram-based method on `u8` images.
+///
+/// # Arguments
+///
+/// * `src` - The source image with shape (H, W, C).
+/// * `dst` - The destination image with shape (H, W, C).
+/// * `kernel_size` - The size of the window, must be odd.
+/// * `border` - The extrapolation of the pixels outside the image.
+///
+/// # Errors
+///
+/// If the kernel size is not odd, or if the images do not have the same size, an error is
+/// returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::border::BorderMode;
+/// use kornia_imgproc::filter::median_blur;
+///
+/// let image = Image::<f32, 1>::new(
+///     ImageSize { width: 3, height: 3 },
+///     vec![1.0, 1.0, 1.0, 1.0, 100.0, 1.0, 1.0, 1.0, 1.0],
+/// ).unwrap();
+///
+/// let mut dst = Image::<f32, 1>::from_size_val(image.size(), 0.0).unwrap();
+///
+/// median_blur(&image, &mut dst, 3, BorderMode::Replicate).unwrap();
+///
+/// assert_eq!(dst.as_slice(), &[1.0; 9]);
+/// ```
+pub fn median_blur<T, const C: usize>(
+    src: &impl AsImageView<T, C>,
+    dst: &mut impl AsImageViewMut<T, C>,
+    kernel_size: usize,
+    border: BorderMode,
+) -> Result<(), ImageError>
+where
+    T: FloatConversion + PartialOrd + Copy + Send + Sync,
+{
+    let src = src.as_view();
+    check_median_args(&src, &dst.as_view_mut(), kernel_size)?;
+
+    let half = (kernel_size / 2) as isize;
+    let constant = T::from_f32(border.constant_value());
+
+    parallel::par_iter_rows_indexed_mut(dst, |r, dst_row| {
+        let rows = window_rows(&src, r, half, border);
+        let mut window = Vec::with_capacity(kernel_size * kernel_size);
+
+        for (c, dst_pixel) in dst_row.chunks_exact_mut(C).enumerate() {
+            for (ch, d) in dst_pixel.iter_mut().enumerate() {
+                window.clear();
+                for row in &rows {
+                    for dx in -half..=half {
+                        let x = border.index(c as isize + dx, src.cols());
+                        window.push(match (row, x) {
+                            (Some(row), Some(x)) => row[x * C + ch],
+                            _ => constant,
+                        });
+                    }
+                }
+
+                let mid = window.len() / 2;
+                let (_, median, _) = window.select_nth_unstable_by(mid, |a, b| {
+                    a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal)
+                });
+                *d = *median;
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Blur an image of `u8` using a median filter.
+///
+/// The histogram of the window is updated as it slides along each row, with a coarse and a
+/// fine level to find the median, so the cost per pixel grows linearly with the kernel size.
+///
+/// # Arguments
+///
+/// * `src` - The source image with shape (H, W, C).
+/// * `dst` - The destination image with shape (H, W, C).
+/// * `kernel_size` - The size of the window, must be odd.
+/// * `border` - The extrapolation of the pixels outside the image.
+///
+/// # Errors
+///
+/// If the kernel size is not odd, or if the images do not have the same size, an error is
+/// returned.
+pub fn median_blur_u8<const C: usize>(
+    src: &impl AsImageView<u8, C>,
+    dst: &mut impl AsImageViewMut<u8, C>,
+    kernel_size: usize,
+    border: BorderMode,
+) -> Result<(), ImageError> {
+    let src = src.as_view();
+    check_median_args(&src, &dst.as_view_mut(), kernel_size)?;
+
+    let half = (kernel_size / 2) as isize;
+    let constant = u8::from_f32(border.constant_value());
+    let rank = (kernel_size * kernel_size / 2) as u32;
+
+    parallel::par_iter_rows_indexed_mut(dst, |r, dst_row| {
+        let rows = window_rows(&src, r, half, border);
+
+        // add or remove a column of the window to the histograms of every channel
+        let update = |hist: &mut [Histogram; C], x: isize, add: bool| {
+            let x = border.index(x, src.cols());
+            for row in &rows {
+                for (ch, h) in hist.iter_mut().enumerate() {
+                    let v = match (row, x) {
+                        (Some(row), Some(x)) => row[x * C + ch],
+                        _ => constant,
+                    };
+                    h.update(v, add);
+                }
+            }
+        };
+
+        let mut hist = [Histogram::new(); C];
+        for x in -half..=half {
+            update(&mut hist, x, true);
+        }
+
+        for (c, dst_pixel) in dst_row.chunks_exact_mut(C).enumerate() {
+            if c > 0 {
+                update(&mut hist, c as isize - half - 1, false);
+                update(&mut hist, c as isize + half, true);
+            }
+            for (d, h) in dst_pixel.iter_mut().zip(hist.iter()) {
+                *d = h.nth(rank);
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// The histogram of the values of a window, with 16 coarse bins of 16 values each.
+#[derive(Clone, Copy)]
+struct Histogram {
+    coarse: [u32; 256 / COARSE_BIN],
+    fine: [u32; 256],
+}
+
+impl Histogram {
+    fn new() -> Self {
+        Self {
+            coarse: [0; 256 / COARSE_BIN],
+            fine: [0; 256],
+        }
+    }
+
+    fn update(&mut self, v: u8, add: bool) {
+        let (coarse, fine) = (
+            &mut self.coarse[v as usize / COARSE_BIN],
+            &mut self.fine[v as usize],
+        );
+        if add {
+            *coarse += 1;
+            *fine += 1;
+        } else {
+            *coarse -= 1;
+            *fine -= 1;
+        }
+    }
+
+    /// Find the value of rank `n` of the window, starting from 0.
+    fn nth(&self, mut n: u32) -> u8 {
+        let mut base = 0;
+        for &count in &self.coarse {
+            if n < count {
+                break;
+            }
+            n -= count;
+            base += COARSE_BIN;
+        }
+
+        for (v, &count) in self.fine[base..].iter().enumerate() {
+            if n < count {
+                return (base + v) as u8;
+            }
+            n -= count;
+        }
+
+        u8::MAX
+    }
+}
+
+/// Get the rows of the window centered at a row, `None` for the rows taking the constant.
+fn window_rows<'a, T, const C: usize>(
+    src: &'a ImageView<T, C>,
+    r: usize,
+    half: isize,
+    border: BorderMode,
+) -> Vec<Option<&'a [T]>> {
+    (-half..=half)
+        .map(|dy| {
+            border
+                .index(r as isize + dy, src.rows())
+                .map(|y| src.row(y))
+        })
+        .collect()
+}
+
+fn check_median_args<T, const C: usize>(
+    src: &ImageView<T, C>,
+    dst: &ImageViewMut<T, C>,
+    kernel_size: usize,
+) -> Result<(), ImageError> {
+    if kernel_size % 2 == 0 {
+        return Err(ImageError::InvalidKernelLength(kernel_size, kernel_size));
+    }
+
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kornia_image::{Image, ImageSize};
+
+    #[test]
+    fn median_blur_u8_matches_sort() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 13,
+            height: 9,
+        };
+        let image = Image::<u8, 2>::new(
+            size,
+            (0..size.width * size.height * 2)
+                .map(|i| ((i * 97 + i / 7) % 256) as u8)
+                .collect(),
+        )?;
+
+        for kernel_size in [1, 3, 5] {
+            for border in [
+                BorderMode::Constant(200.0),
+                BorderMode::Replicate,
+                BorderMode::Reflect101,
+                BorderMode::Wrap,
+            ] {
+                let mut expected = Image::<u8, 2>::from_size_val(size, 0)?;
+                median_blur(&image, &mut expected, kernel_size, border)?;
+
+                let mut dst = Image::<u8, 2>::from_size_val(size, 0)?;
+                median_blur_u8(&image, &mut dst, kernel_size, border)?;
+
+                assert_eq!(
+                    dst.as_slice(),
+                    expected.as_slice(),
+                    "{kernel_size} {border:?}"
+                );
+            }
+        }
+
+        let mut dst = Image::<u8, 2>::from_size_val(size, 0)?;
+        assert!(median_blur_u8(&image, &mut dst, 4, BorderMode::Replicate).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn median_blur_salt_and_pepper() -> Result<(), ImageError> {
+        #[rustfmt::skip]
+        let image = Image::<u8, 1>::new(
+            ImageSize { width: 5, height: 4 },
+            vec![
+                10, 10, 255, 20, 20,
+                10, 0, 10, 20, 20,
+                10, 10, 10, 20, 255,
+                10, 10, 10, 20, 20,
+            ],
+        )?;
+
+        let mut dst = Image::<u8, 1>::from_size_val(image.size(), 0)?;
+        median_blur_u8(&image, &mut dst, 3, BorderMode::Reflect101)?;
+
+        #[rustfmt::skip]
+        assert_eq!(dst.as_slice(), &[
+            10, 10, 10, 20, 20,
+            10, 10, 10, 20, 20,
+            10, 10, 10, 20, 20,
+            10, 10, 10, 20, 20,
+        ]);
+
+        Ok(())
+    }
+}
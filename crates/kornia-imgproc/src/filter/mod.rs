@@ -2,9 +2,25 @@
 //!
 //! This module provides filter operations for image processing.
 
+/// Bilateral filter operations
+mod bilateral;
+pub use bilateral::*;
+
+/// 2D filter operations
+mod filter2d;
+pub use filter2d::*;
+
 /// Filter kernels
 pub mod kernels;
 
+/// Guided filter operations
+mod guided;
+pub use guided::*;
+
+/// Median filter operations
+mod median;
+pub use median::*;
+
 /// Filter operations
 mod ops;
 pub use ops::*;
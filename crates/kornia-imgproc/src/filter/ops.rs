@@ -1,10 +1,12 @@
-use kornia_image::{Image, ImageError, ImageSize};
+use kornia_image::{AsImageView, AsImageViewMut, Image, ImageError, ImageSize};
 use rayon::{
     iter::{IndexedParallelIterator, ParallelIterator},
     slice::ParallelSliceMut,
 };
 
-use super::{fast_horizontal_filter, kernels, separable_filter};
+use crate::border::BorderMode;
+
+use super::{fast_horizontal_filter, filter2d, kernels, separable_filter};
 
 /// Blur an image using a box blur filter
 ///
@@ -13,16 +15,18 @@ use super::{fast_horizontal_filter, kernels, separable_filter};
 /// * `src` - The source image with shape (H, W, C).
 /// * `dst` - The destination image with shape (H, W, C).
 /// * `kernel_size` - The size of the kernel (kernel_x, kernel_y).
+/// * `border` - The extrapolation of the pixels outside the image.
 ///
 /// PRECONDITION: `src` and `dst` must have the same shape.
 pub fn box_blur<const C: usize>(
-    src: &Image<f32, C>,
-    dst: &mut Image<f32, C>,
+    src: &impl AsImageView<f32, C>,
+    dst: &mut impl AsImageViewMut<f32, C>,
     kernel_size: (usize, usize),
+    border: BorderMode,
 ) -> Result<(), ImageError> {
     let kernel_x = kernels::box_blur_kernel_1d(kernel_size.0);
     let kernel_y = kernels::box_blur_kernel_1d(kernel_size.1);
-    separable_filter(src, dst, &kernel_x, &kernel_y)?;
+    separable_filter(src, dst, &kernel_x, &kernel_y, border)?;
     Ok(())
 }
 
@@ -34,18 +38,19 @@ pub fn box_blur<const C: usize>(
 /// * `dst` - The destination image with shape (H, W, C).
 /// * `kernel_size` - The size of the kernel (kernel_x, kernel_y).
 /// * `sigma` - The sigma of the gaussian kernel.
+/// * `border` - The extrapolation of the pixels outside the image.
 ///
 /// PRECONDITION: `src` and `dst` must have the same shape.
-/// NOTE: This function uses a constant border type.
 pub fn gaussian_blur<const C: usize>(
-    src: &Image<f32, C>,
-    dst: &mut Image<f32, C>,
+    src: &impl AsImageView<f32, C>,
+    dst: &mut impl AsImageViewMut<f32, C>,
     kernel_size: (usize, usize),
     sigma: (f32, f32),
+    border: BorderMode,
 ) -> Result<(), ImageError> {
     let kernel_x = kernels::gaussian_kernel_1d(kernel_size.0, sigma.0);
     let kernel_y = kernels::gaussian_kernel_1d(kernel_size.1, sigma.1);
-    separable_filter(src, dst, &kernel_x, &kernel_y)?;
+    separable_filter(src, dst, &kernel_x, &kernel_y, border)?;
     Ok(())
 }
 
@@ -56,35 +61,131 @@ pub fn gaussian_blur<const C: usize>(
 /// * `src` - The source image with shape (H, W, C).
 /// * `dst` - The destination image with shape (H, W, C).
 /// * `kernel_size` - The size of the kernel (kernel_x, kernel_y).
+/// * `border` - The extrapolation of the pixels outside the image.
 ///
 /// PRECONDITION: `src` and `dst` must have the same shape.
 pub fn sobel<const C: usize>(
-    src: &Image<f32, C>,
-    dst: &mut Image<f32, C>,
+    src: &impl AsImageView<f32, C>,
+    dst: &mut impl AsImageViewMut<f32, C>,
     kernel_size: usize,
+    border: BorderMode,
 ) -> Result<(), ImageError> {
+    let src = src.as_view();
+
     // get the sobel kernels
     let (kernel_x, kernel_y) = kernels::sobel_kernel_1d(kernel_size);
 
     // apply the sobel filter using separable filter
     let mut gx = Image::<f32, C>::from_size_val(src.size(), 0.0)?;
-    separable_filter(src, &mut gx, &kernel_x, &kernel_y)?;
+    separable_filter(&src, &mut gx, &kernel_x, &kernel_y, border)?;
 
     let mut gy = Image::<f32, C>::from_size_val(src.size(), 0.0)?;
-    separable_filter(src, &mut gy, &kernel_y, &kernel_x)?;
+    separable_filter(&src, &mut gy, &kernel_y, &kernel_x, border)?;
 
     // compute the magnitude in parallel by rows
-    dst.as_slice_mut()
-        .iter_mut()
-        .zip(gx.as_slice().iter())
-        .zip(gy.as_slice().iter())
-        .for_each(|((dst, &gx), &gy)| {
-            *dst = (gx * gx + gy * gy).sqrt();
-        });
+    crate::parallel::par_iter_rows_val_two(&gx, &gy, dst, |&gx, &gy, dst| {
+        *dst = (gx * gx + gy * gy).sqrt();
+    });
+
+    Ok(())
+}
+
+/// Compute the laplacian of an image.
+///
+/// # Arguments
+///
+/// * `src` - The source image with shape (H, W, C).
+/// * `dst` - The destination image with shape (H, W, C).
+/// * `kernel_size` - The size of the kernel, 1 or 3.
+/// * `border` - The extrapolation of the pixels outside the image.
+///
+/// PRECONDITION: `src` and `dst` must have the same shape.
+///
+/// # Errors
+///
+/// If the kernel size is not 1 or 3, an error is returned.
+pub fn laplacian<const C: usize>(
+    src: &impl AsImageView<f32, C>,
+    dst: &mut impl AsImageViewMut<f32, C>,
+    kernel_size: usize,
+    border: BorderMode,
+) -> Result<(), ImageError> {
+    if kernel_size != 1 && kernel_size != 3 {
+        return Err(ImageError::InvalidKernelLength(kernel_size, kernel_size));
+    }
+
+    let kernel = kernels::laplacian_kernel(kernel_size);
+    filter2d(src, dst, &kernel, (3, 3), border)
+}
+
+/// Compute the gradient magnitude of an image with the scharr operator.
+///
+/// # Arguments
+///
+/// * `src` - The source image with shape (H, W, C).
+/// * `dst` - The destination image with shape (H, W, C).
+/// * `border` - The extrapolation of the pixels outside the image.
+///
+/// PRECONDITION: `src` and `dst` must have the same shape.
+pub fn scharr<const C: usize>(
+    src: &impl AsImageView<f32, C>,
+    dst: &mut impl AsImageViewMut<f32, C>,
+    border: BorderMode,
+) -> Result<(), ImageError> {
+    let src = src.as_view();
+
+    let (kernel_x, kernel_y) = kernels::scharr_kernel3();
+
+    let mut gx = Image::<f32, C>::from_size_val(src.size(), 0.0)?;
+    filter2d(&src, &mut gx, &kernel_x, (3, 3), border)?;
+
+    let mut gy = Image::<f32, C>::from_size_val(src.size(), 0.0)?;
+    filter2d(&src, &mut gy, &kernel_y, (3, 3), border)?;
+
+    crate::parallel::par_iter_rows_val_two(&gx, &gy, dst, |&gx, &gy, dst| {
+        *dst = (gx * gx + gy * gy).sqrt();
+    });
 
     Ok(())
 }
 
+/// Sharpen an image by adding the difference with its gaussian blur.
+///
+/// The output is `src + amount * (src - gaussian_blur(src))`, computed with a single kernel.
+///
+/// # Arguments
+///
+/// * `src` - The source image with shape (H, W, C).
+/// * `dst` - The destination image with shape (H, W, C).
+/// * `kernel_size` - The size of the gaussian kernel (kernel_x, kernel_y).
+/// * `sigma` - The sigma of the gaussian kernel.
+/// * `amount` - The weight of the difference with the blurred image.
+/// * `border` - The extrapolation of the pixels outside the image.
+///
+/// PRECONDITION: `src` and `dst` must have the same shape.
+pub fn unsharp_mask<const C: usize>(
+    src: &impl AsImageView<f32, C>,
+    dst: &mut impl AsImageViewMut<f32, C>,
+    kernel_size: (usize, usize),
+    sigma: (f32, f32),
+    amount: f32,
+    border: BorderMode,
+) -> Result<(), ImageError> {
+    let kernel_x = kernels::gaussian_kernel_1d(kernel_size.0, sigma.0);
+    let kernel_y = kernels::gaussian_kernel_1d(kernel_size.1, sigma.1);
+
+    // (1 + amount) * identity - amount * gaussian
+    let mut kernel = kernel_y
+        .iter()
+        .flat_map(|ky| kernel_x.iter().map(move |kx| -amount * kx * ky))
+        .collect::<Vec<_>>();
+    if let Some(center) = kernel.get_mut(kernel_size.1 / 2 * kernel_size.0 + kernel_size.0 / 2) {
+        *center += 1.0 + amount;
+    }
+
+    filter2d(src, dst, &kernel, kernel_size, border)
+}
+
 /// Blur an image using a box blur filter multiple times to achieve a near gaussian blur
 ///
 /// # Arguments
@@ -93,34 +194,38 @@ pub fn sobel<const C: usize>(
 /// * `dst` - The destination image with shape (H, W, C).
 /// * `kernel_size` - The size of the kernel (kernel_x, kernel_y).
 /// * `sigma` - The sigma of the gaussian kernel, xy-ordered.
+/// * `border` - The extrapolation of the pixels outside the image.
 ///
 /// PRECONDITION: `src` and `dst` must have the same shape.
 pub fn box_blur_fast<const C: usize>(
-    src: &Image<f32, C>,
-    dst: &mut Image<f32, C>,
+    src: &impl AsImageView<f32, C>,
+    dst: &mut impl AsImageViewMut<f32, C>,
     sigma: (f32, f32),
+    border: BorderMode,
 ) -> Result<(), ImageError> {
     let half_kernel_x_sizes = kernels::box_blur_fast_kernels_1d(sigma.0, 3);
     let half_kernel_y_sizes = kernels::box_blur_fast_kernels_1d(sigma.1, 3);
 
+    let src = src.as_view();
+    let mut dst = dst.as_view_mut();
+
     let transposed_size = ImageSize {
         width: src.size().height,
         height: src.size().width,
     };
 
-    let mut input_img = src.clone();
+    // the passes run on contiguous buffers, the result is copied to the destination
+    let mut input_img = src.to_image()?;
     let mut transposed = Image::<f32, C>::from_size_val(transposed_size, 0.0)?;
 
     for (half_kernel_x_size, half_kernel_y_size) in
         half_kernel_x_sizes.iter().zip(half_kernel_y_sizes.iter())
     {
-        fast_horizontal_filter(&input_img, &mut transposed, *half_kernel_x_size)?;
-        fast_horizontal_filter(&transposed, dst, *half_kernel_y_size)?;
-
-        input_img = dst.clone();
+        fast_horizontal_filter(&input_img, &mut transposed, *half_kernel_x_size, border)?;
+        fast_horizontal_filter(&transposed, &mut input_img, *half_kernel_y_size, border)?;
     }
 
-    Ok(())
+    dst.copy_from(&input_img.view())
 }
 
 /// Compute the first order image derivative in both x and y using a Sobel operator.
@@ -129,10 +234,12 @@ pub fn box_blur_fast<const C: usize>(
 ///
 /// * `src` - The source image with shape (H, W).
 /// * `dst` - The destination image with shape (H, W, 2).
+/// * `border` - The extrapolation of the pixels outside the image.
 pub fn spatial_gradient_float<const C: usize>(
     src: &Image<f32, C>,
     dx: &mut Image<f32, C>,
     dy: &mut Image<f32, C>,
+    border: BorderMode,
 ) -> Result<(), ImageError> {
     if src.size() != dx.size() {
         return Err(ImageError::InvalidImageSize(
@@ -171,11 +278,16 @@ pub fn spatial_gradient_float<const C: usize>(
                     let mut sum_y = [0.0; C];
                     for dy in 0..3 {
                         for dx in 0..3 {
-                            let row = (r + dy).min(src.rows()).max(1) - 1;
-                            let col = (c + dx).min(src.cols()).max(1) - 1;
+                            let row = border.index((r + dy) as isize - 1, src.rows());
+                            let col = border.index((c + dx) as isize - 1, src.cols());
                             for ch in 0..C {
-                                let src_pix_offset = (row * src.cols() + col) * C + ch;
-                                let val = unsafe { src_data.get_unchecked(src_pix_offset) };
+                                let val = match (row, col) {
+                                    (Some(row), Some(col)) => {
+                                        let src_pix_offset = (row * src.cols() + col) * C + ch;
+                                        unsafe { *src_data.get_unchecked(src_pix_offset) }
+                                    }
+                                    _ => border.constant_value(),
+                                };
                                 sum_x[ch] += val * sobel_x[dy][dx];
                                 sum_y[ch] += val * sobel_y[dy][dx];
                             }
@@ -196,10 +308,12 @@ pub fn spatial_gradient_float<const C: usize>(
 ///
 /// * `src` - The source image with shape (H, W).
 /// * `dst` - The destination image with shape (H, W, 2).
+/// * `border` - The extrapolation of the pixels outside the image.
 pub fn spatial_gradient_float_parallel_row<const C: usize>(
     src: &Image<f32, C>,
     dx: &mut Image<f32, C>,
     dy: &mut Image<f32, C>,
+    border: BorderMode,
 ) -> Result<(), ImageError> {
     if src.size() != dx.size() {
         return Err(ImageError::InvalidImageSize(
@@ -238,11 +352,16 @@ pub fn spatial_gradient_float_parallel_row<const C: usize>(
                     let mut sum_y = [0.0; C];
                     for dy in 0..3 {
                         for dx in 0..3 {
-                            let row = (r + dy).min(src.rows()).max(1) - 1;
-                            let col = (c + dx).min(src.cols()).max(1) - 1;
+                            let row = border.index((r + dy) as isize - 1, src.rows());
+                            let col = border.index((c + dx) as isize - 1, src.cols());
                             for ch in 0..C {
-                                let src_pix_offset = (row * src.cols() + col) * C + ch;
-                                let val = unsafe { src_data.get_unchecked(src_pix_offset) };
+                                let val = match (row, col) {
+                                    (Some(row), Some(col)) => {
+                                        let src_pix_offset = (row * src.cols() + col) * C + ch;
+                                        unsafe { *src_data.get_unchecked(src_pix_offset) }
+                                    }
+                                    _ => border.constant_value(),
+                                };
                                 sum_x[ch] += val * sobel_x[dy][dx];
                                 sum_y[ch] += val * sobel_y[dy][dx];
                             }
@@ -263,10 +382,12 @@ pub fn spatial_gradient_float_parallel_row<const C: usize>(
 ///
 /// * `src` - The source image with shape (H, W).
 /// * `dst` - The destination image with shape (H, W, 2).
+/// * `border` - The extrapolation of the pixels outside the image.
 pub fn spatial_gradient_float_parallel<const C: usize>(
     src: &Image<f32, C>,
     dx: &mut Image<f32, C>,
     dy: &mut Image<f32, C>,
+    border: BorderMode,
 ) -> Result<(), ImageError> {
     if src.size() != dx.size() {
         return Err(ImageError::InvalidImageSize(
@@ -305,11 +426,16 @@ pub fn spatial_gradient_float_parallel<const C: usize>(
                     let mut sum_y = [0.0; C];
                     for dy in 0..3 {
                         for dx in 0..3 {
-                            let row = (r + dy).min(src.rows()).max(1) - 1;
-                            let col = (c + dx).min(src.cols()).max(1) - 1;
+                            let row = border.index((r + dy) as isize - 1, src.rows());
+                            let col = border.index((c + dx) as isize - 1, src.cols());
                             for ch in 0..C {
-                                let src_pix_offset = (row * src.cols() + col) * C + ch;
-                                let val = unsafe { src_data.get_unchecked(src_pix_offset) };
+                                let val = match (row, col) {
+                                    (Some(row), Some(col)) => {
+                                        let src_pix_offset = (row * src.cols() + col) * C + ch;
+                                        unsafe { *src_data.get_unchecked(src_pix_offset) }
+                                    }
+                                    _ => border.constant_value(),
+                                };
                                 sum_x[ch] += val * sobel_x[dy][dx];
                                 sum_y[ch] += val * sobel_y[dy][dx];
                             }
@@ -341,7 +467,7 @@ mod tests {
         )?;
         let mut dst = Image::<_, 1>::from_size_val(size, 0.0)?;
 
-        box_blur_fast(&img, &mut dst, (0.5, 0.5))?;
+        box_blur_fast(&img, &mut dst, (0.5, 0.5), BorderMode::Replicate)?;
 
         #[rustfmt::skip]
         assert_eq!(
@@ -373,7 +499,13 @@ mod tests {
 
         let mut dst = Image::<_, 1>::from_size_val(size, 0.0)?;
 
-        gaussian_blur(&img, &mut dst, (3, 3), (0.5, 0.5))?;
+        gaussian_blur(
+            &img,
+            &mut dst,
+            (3, 3),
+            (0.5, 0.5),
+            BorderMode::Constant(0.0),
+        )?;
 
         #[rustfmt::skip]
         assert_eq!(
@@ -392,8 +524,12 @@ mod tests {
     #[test]
     fn test_spatial_gradient() -> Result<(), ImageError> {
         // First, define a type alias for the function signature
-        type FilterFunction =
-            fn(&Image<f32, 2>, &mut Image<f32, 2>, &mut Image<f32, 2>) -> Result<(), ImageError>;
+        type FilterFunction = fn(
+            &Image<f32, 2>,
+            &mut Image<f32, 2>,
+            &mut Image<f32, 2>,
+            BorderMode,
+        ) -> Result<(), ImageError>;
 
         // Then, define a type for the test tuple
         type TestCase = (FilterFunction, &'static str);
@@ -425,7 +561,7 @@ mod tests {
             let mut dx = Image::<_, 2>::from_size_val(size, 0.0)?;
             let mut dy = Image::<_, 2>::from_size_val(size, 0.0)?;
 
-            test_fn(&img, &mut dx, &mut dy)?;
+            test_fn(&img, &mut dx, &mut dy, BorderMode::Replicate)?;
 
             #[rustfmt::skip]
             assert_eq!(
@@ -486,4 +622,105 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_laplacian_scharr() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 5,
+            height: 4,
+        };
+
+        // f(x, y) = x^2 + 2y, the laplacian is 2 and the gradient (2x, 2)
+        let img = Image::<f32, 1>::new(
+            size,
+            (0..20)
+                .map(|i| ((i % 5) * (i % 5) + 2 * (i / 5)) as f32)
+                .collect(),
+        )?;
+
+        let mut dst = Image::<f32, 1>::from_size_val(size, 0.0)?;
+        laplacian(&img, &mut dst, 1, BorderMode::Reflect101)?;
+        for r in 1..3 {
+            assert_eq!(&dst.as_slice()[r * 5 + 1..r * 5 + 4], &[2.0, 2.0, 2.0]);
+        }
+        assert!(laplacian(&img, &mut dst, 5, BorderMode::Reflect101).is_err());
+
+        scharr(&img, &mut dst, BorderMode::Replicate)?;
+        // the scharr kernels are scaled by 32
+        let (gx, gy) = (2.0 * 2.0 * 32.0, 2.0 * 32.0);
+        assert_eq!(dst.as_slice()[5 + 2], f32::hypot(gx, gy));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsharp_mask() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 6,
+            height: 5,
+        };
+        let img = Image::<f32, 1>::new(size, (0..30).map(|x| (x * x % 11) as f32).collect())?;
+
+        let mut blurred = Image::<f32, 1>::from_size_val(size, 0.0)?;
+        gaussian_blur(&img, &mut blurred, (5, 3), (1.0, 0.8), BorderMode::Reflect)?;
+
+        let mut dst = Image::<f32, 1>::from_size_val(size, 0.0)?;
+        unsharp_mask(&img, &mut dst, (5, 3), (1.0, 0.8), 1.5, BorderMode::Reflect)?;
+
+        for ((&s, &b), &d) in img
+            .as_slice()
+            .iter()
+            .zip(blurred.as_slice())
+            .zip(dst.as_slice())
+        {
+            assert!((d - (s + 1.5 * (s - b))).abs() < 1e-4);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gaussian_blur_roi() -> Result<(), ImageError> {
+        let image = Image::<f32, 1>::new(
+            ImageSize {
+                width: 6,
+                height: 5,
+            },
+            (0..30).map(|x| (x * x % 7) as f32).collect(),
+        )?;
+
+        let tile_size = ImageSize {
+            width: 3,
+            height: 4,
+        };
+        let tile = image.roi(2, 1, tile_size)?;
+
+        // filtering a tile in place matches filtering a copy of it
+        let mut expected = Image::<f32, 1>::from_size_val(tile_size, 0.0)?;
+        gaussian_blur(
+            &tile.to_image()?,
+            &mut expected,
+            (3, 3),
+            (1.0, 1.0),
+            BorderMode::Reflect101,
+        )?;
+
+        let mut dst = Image::<f32, 1>::from_size_val(image.size(), -1.0)?;
+        gaussian_blur(
+            &tile,
+            &mut dst.roi_mut(1, 0, tile_size)?,
+            (3, 3),
+            (1.0, 1.0),
+            BorderMode::Reflect101,
+        )?;
+
+        assert_eq!(
+            dst.roi(1, 0, tile_size)?.to_image()?.as_slice(),
+            expected.as_slice()
+        );
+        assert_eq!(dst.get_pixel(0, 0, 0)?, &-1.0);
+        assert_eq!(dst.get_pixel(4, 0, 0)?, &-1.0);
+
+        Ok(())
+    }
 }
@@ -1,6 +1,8 @@
-use kornia_image::{Image, ImageError};
+use kornia_image::{AsImageView, AsImageViewMut, Image, ImageError};
 use num_traits::Zero;
 
+use crate::border::BorderMode;
+
 /// Trait for floating point casting
 pub trait FloatConversion {
     /// Convert the type to f32
@@ -47,15 +49,20 @@ impl FloatConversion for u8 {
 /// * `dst` - The destination image with shape (H, W, C).
 /// * `kernel_x` - The horizontal kernel.
 /// * `kernel_y` - The vertical kernel.
+/// * `border` - The extrapolation of the pixels outside the image.
 pub fn separable_filter<T, const C: usize>(
-    src: &Image<T, C>,
-    dst: &mut Image<T, C>,
+    src: &impl AsImageView<T, C>,
+    dst: &mut impl AsImageViewMut<T, C>,
     kernel_x: &[f32],
     kernel_y: &[f32],
+    border: BorderMode,
 ) -> Result<(), ImageError>
 where
     T: FloatConversion + Clone + Zero + std::ops::Mul<Output = T> + std::ops::AddAssign,
 {
+    let src = src.as_view();
+    let mut dst = dst.as_view_mut();
+
     if kernel_x.is_empty() || kernel_y.is_empty() {
         return Err(ImageError::InvalidKernelLength(
             kernel_x.len(),
@@ -75,14 +82,15 @@ where
     let half_kernel_x = kernel_x.len() / 2;
     let half_kernel_y = kernel_y.len() / 2;
 
-    let src_data = src.as_slice();
-    let dst_data = dst.as_slice_mut();
+    // the value of the rows outside the image after the row-wise filtering
+    let constant = border.constant_value();
+    let constant_row = constant * kernel_x.iter().sum::<f32>();
 
     // preallocate the temporary buffer for intermediate results
-    let mut temp = vec![0.0f32; src_data.len()];
+    let mut temp = vec![0.0f32; src.rows() * src.cols() * C];
 
     // Row-wise filtering
-    for r in 0..src.rows() {
+    for (r, src_row) in src.row_iter().enumerate() {
         let row_offset = r * src.cols();
         for c in 0..src.cols() {
             let col_offset = (row_offset + c) * C;
@@ -91,11 +99,11 @@ where
                 let mut row_acc = 0.0f32;
                 for (k_idx, k_val) in kernel_x.iter().enumerate() {
                     let x_pos = c as isize + k_idx as isize - half_kernel_x as isize;
-                    if x_pos >= 0 && x_pos < src.cols() as isize {
-                        let neighbor_idx = (row_offset + x_pos as usize) * C + ch;
-                        let neighbor_val = unsafe { src_data.get_unchecked(neighbor_idx) };
-                        row_acc += neighbor_val.to_f32() * k_val;
-                    }
+                    let neighbor_val = match border.index(x_pos, src.cols()) {
+                        Some(x) => unsafe { src_row.get_unchecked(x * C + ch) }.to_f32(),
+                        None => constant,
+                    };
+                    row_acc += neighbor_val * k_val;
                 }
 
                 unsafe {
@@ -106,23 +114,22 @@ where
     }
 
     // Column-wise filtering
-    for r in 0..src.rows() {
-        let row_offset = r * src.cols();
-        for c in 0..src.cols() {
-            let col_offset = (row_offset + c) * C;
+    let (rows, cols) = (src.rows(), src.cols());
+    for (r, dst_row) in dst.row_iter_mut().enumerate() {
+        for c in 0..cols {
             for ch in 0..C {
-                let pix_offset = col_offset + ch;
+                let pix_offset = c * C + ch;
                 let mut col_acc = 0.0f32;
                 for (k_idx, k_val) in kernel_y.iter().enumerate() {
                     let y_pos = r as isize + k_idx as isize - half_kernel_y as isize;
-                    if y_pos >= 0 && y_pos < src.rows() as isize {
-                        let neighbor_idx = (y_pos as usize * src.cols() + c) * C + ch;
-                        let neighbor_val = unsafe { temp.get_unchecked(neighbor_idx) };
-                        col_acc += neighbor_val * k_val;
-                    }
+                    let neighbor_val = match border.index(y_pos, rows) {
+                        Some(y) => unsafe { *temp.get_unchecked((y * cols + c) * C + ch) },
+                        None => constant_row,
+                    };
+                    col_acc += neighbor_val * k_val;
                 }
                 unsafe {
-                    *dst_data.get_unchecked_mut(pix_offset) = T::from_f32(col_acc);
+                    *dst_row.get_unchecked_mut(pix_offset) = T::from_f32(col_acc);
                 }
             }
         }
@@ -138,58 +145,36 @@ where
 /// * `src` - The source image with shape (H, W, C).
 /// * `dst` - The destination image with transposed shape (W, H, C).
 /// * `half_kernel_x_size` - Half of the kernel at weight 1. The total size would be 2*this+1
+/// * `border` - The extrapolation of the pixels outside the image.
 pub(crate) fn fast_horizontal_filter<const C: usize>(
     src: &Image<f32, C>,
     dst: &mut Image<f32, C>,
     half_kernel_x_size: usize,
+    border: BorderMode,
 ) -> Result<(), ImageError> {
-    let src_data = src.as_slice();
+    let (rows, cols) = (src.rows(), src.cols());
     let dst_data = dst.as_slice_mut();
-    let mut row_acc = [0.0; C];
-
-    let mut leftmost_pixel = [0.0; C];
-    let mut rightmost_pixel = [0.0; C];
-
-    let pixels_between_first_last_cols = (src.cols() - 1) * C;
-    let kernel_pix_offset_diffs: Vec<usize> =
-        (0..half_kernel_x_size).map(|p| (p + 1) * C).collect();
-    for (pix_offset, source_pixel) in src_data.iter().enumerate() {
-        let ch = pix_offset % C;
-        let rc = pix_offset / C;
-        let c = rc % src.cols();
-        let r = rc / src.cols();
-
-        let transposed_r = c;
-        let transposed_c = r;
-        let transposed_pix_offset = transposed_r * src.rows() * C + transposed_c * C + ch;
-
-        if c == 0 {
-            row_acc[ch] = *source_pixel * (half_kernel_x_size + 1) as f32;
-            for pix_diff in &kernel_pix_offset_diffs {
-                row_acc[ch] += src_data[pix_offset + pix_diff]
-            }
-            leftmost_pixel[ch] = *source_pixel;
-            rightmost_pixel[ch] = src_data[pix_offset + pixels_between_first_last_cols];
-        } else {
-            row_acc[ch] -= match c.checked_sub(half_kernel_x_size + 1) {
-                Some(_) => {
-                    let prv_leftmost_pix_offset = pix_offset - C * (half_kernel_x_size + 1);
-                    src_data[prv_leftmost_pix_offset]
-                }
-                None => leftmost_pixel[ch],
-            };
+    let half = half_kernel_x_size as isize;
+    let norm = (half_kernel_x_size * 2 + 1) as f32;
 
-            let rightmost_x = c + half_kernel_x_size;
+    for (r, src_row) in src.as_slice().chunks_exact(cols * C).enumerate() {
+        let sample = |x: isize, ch: usize| match border.index(x, cols) {
+            Some(x) => src_row[x * C + ch],
+            None => border.constant_value(),
+        };
 
-            row_acc[ch] += match rightmost_x {
-                x if x < src.cols() => {
-                    let rightmost_pix_offset = pix_offset + C * half_kernel_x_size;
-                    src_data[rightmost_pix_offset]
+        for ch in 0..C {
+            // running sum of the window centered at each column
+            let mut row_acc = (-half..=half).map(|x| sample(x, ch)).sum::<f32>();
+            for c in 0..cols {
+                if c > 0 {
+                    let c = c as isize;
+                    row_acc -= sample(c - half - 1, ch);
+                    row_acc += sample(c + half, ch);
                 }
-                _ => rightmost_pixel[ch],
-            };
+                dst_data[(c * rows + r) * C + ch] = row_acc / norm;
+            }
         }
-        dst_data[transposed_pix_offset] = row_acc[ch] / (half_kernel_x_size * 2 + 1) as f32;
     }
 
     Ok(())
@@ -222,7 +207,13 @@ mod tests {
         let mut dst = Image::<_, 1>::from_size_val(img.size(), 0f32)?;
         let kernel_x = vec![1.0, 1.0, 1.0];
         let kernel_y = vec![1.0, 1.0, 1.0];
-        separable_filter(&img, &mut dst, &kernel_x, &kernel_y)?;
+        separable_filter(
+            &img,
+            &mut dst,
+            &kernel_x,
+            &kernel_y,
+            BorderMode::Constant(0.0),
+        )?;
 
         #[rustfmt::skip]
         assert_eq!(
@@ -242,6 +233,35 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_separable_filter_border() -> Result<(), ImageError> {
+        let cases = [
+            (BorderMode::Constant(10.0), [13.0, 6.0, 9.0, 17.0]),
+            (BorderMode::Replicate, [4.0, 6.0, 9.0, 11.0]),
+            (BorderMode::Reflect, [4.0, 6.0, 9.0, 11.0]),
+            (BorderMode::Reflect101, [5.0, 6.0, 9.0, 10.0]),
+            (BorderMode::Wrap, [7.0, 6.0, 9.0, 8.0]),
+        ];
+
+        // the same row filtered horizontally and as a column filtered vertically
+        for size in [[4, 1], [1, 4]] {
+            let size = ImageSize::from(size);
+            let img = Image::<f32, 1>::new(size, vec![1.0, 2.0, 3.0, 4.0])?;
+            let (kernel_x, kernel_y) = match size.width {
+                1 => (vec![1.0], vec![1.0, 1.0, 1.0]),
+                _ => (vec![1.0, 1.0, 1.0], vec![1.0]),
+            };
+
+            for (border, expected) in cases {
+                let mut dst = Image::<f32, 1>::from_size_val(size, 0.0)?;
+                separable_filter(&img, &mut dst, &kernel_x, &kernel_y, border)?;
+                assert_eq!(dst.as_slice(), &expected, "{border:?} {size:?}");
+            }
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_separable_filter_u8() -> Result<(), ImageError> {
         let size = ImageSize {
@@ -264,7 +284,13 @@ mod tests {
         let mut dst = Image::<u8, 1>::from_size_val(img.size(), 0)?;
         let kernel_x = vec![1.0, 1.0, 1.0];
         let kernel_y = vec![1.0, 1.0, 1.0];
-        separable_filter(&img, &mut dst, &kernel_x, &kernel_y)?;
+        separable_filter(
+            &img,
+            &mut dst,
+            &kernel_x,
+            &kernel_y,
+            BorderMode::Constant(0.0),
+        )?;
 
         #[rustfmt::skip]
         assert_eq!(
@@ -294,7 +320,13 @@ mod tests {
         img.as_slice_mut()[12] = 255;
 
         let mut dst = Image::<u8, 1>::from_size_val(size, 0)?;
-        separable_filter(&img, &mut dst, &kernel_x, &kernel_y)?;
+        separable_filter(
+            &img,
+            &mut dst,
+            &kernel_x,
+            &kernel_y,
+            BorderMode::Constant(0.0),
+        )?;
 
         #[rustfmt::skip]
         assert_eq!(
@@ -329,7 +361,7 @@ mod tests {
 
         let mut transposed = Image::<_, 1>::from_size_val(size, 0.0)?;
 
-        fast_horizontal_filter(&img, &mut transposed, 1)?;
+        fast_horizontal_filter(&img, &mut transposed, 1, BorderMode::Replicate)?;
 
         #[rustfmt::skip]
         assert_eq!(
@@ -345,7 +377,7 @@ mod tests {
 
         let mut dst = Image::<_, 1>::from_size_val(size, 0.0)?;
 
-        fast_horizontal_filter(&transposed, &mut dst, 1)?;
+        fast_horizontal_filter(&transposed, &mut dst, 1, BorderMode::Replicate)?;
 
         #[rustfmt::skip]
         assert_eq!(
@@ -0,0 +1,41 @@
+use super::window::window_interpolation;
+use crate::border::BorderMode;
+use kornia_image::ImageView;
+
+/// The free parameter of the cubic convolution kernel, which gives the Catmull-Rom spline.
+const A: f32 = -0.5;
+
+/// The cubic convolution kernel of Keys.
+fn cubic_weight(x: f32) -> f32 {
+    let x = x.abs();
+    if x <= 1.0 {
+        ((A + 2.0) * x - (A + 3.0)) * x * x + 1.0
+    } else if x < 2.0 {
+        ((A * x - 5.0 * A) * x + 8.0 * A) * x - 4.0 * A
+    } else {
+        0.0
+    }
+}
+
+/// Kernel for bicubic interpolation
+///
+/// # Arguments
+///
+/// * `image` - The input image container.
+/// * `u` - The x coordinate of the pixel to interpolate.
+/// * `v` - The y coordinate of the pixel to interpolate.
+/// * `c` - The channel of the pixel to interpolate.
+/// * `border` - The extrapolation of the pixels outside the image.
+///
+/// # Returns
+///
+/// The interpolated pixel value.
+pub(crate) fn bicubic_interpolation<const C: usize>(
+    image: &ImageView<f32, C>,
+    u: f32,
+    v: f32,
+    c: usize,
+    border: BorderMode,
+) -> f32 {
+    window_interpolation::<C, 4>(image, u, v, c, border, cubic_weight)
+}
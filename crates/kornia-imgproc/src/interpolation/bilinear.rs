@@ -1,4 +1,6 @@
-use kornia_image::Image;
+use super::interpolate::pixel_or_border;
+use crate::border::BorderMode;
+use kornia_image::ImageView;
 
 /// Kernel for bilinear interpolation
 ///
@@ -8,40 +10,28 @@ use kornia_image::Image;
 /// * `u` - The x coordinate of the pixel to interpolate.
 /// * `v` - The y coordinate of the pixel to interpolate.
 /// * `c` - The channel of the pixel to interpolate.
+/// * `border` - The extrapolation of the pixels outside the image.
 ///
 /// # Returns
 ///
 /// The interpolated pixel value.
 // TODO: add support for other data types. Maybe use a trait? or template?
 pub(crate) fn bilinear_interpolation<const C: usize>(
-    image: &Image<f32, C>,
+    image: &ImageView<f32, C>,
     u: f32,
     v: f32,
     c: usize,
+    border: BorderMode,
 ) -> f32 {
-    let (rows, cols) = (image.rows(), image.cols());
+    let (u0, v0) = (u.floor(), v.floor());
+    let frac_u = u - u0;
+    let frac_v = v - v0;
+    let (iu, iv) = (u0 as isize, v0 as isize);
 
-    let iu = u.trunc() as usize;
-    let iv = v.trunc() as usize;
-
-    let frac_u = u.fract();
-    let frac_v = v.fract();
-    let val00 = *image.get_unchecked([iv, iu, c]);
-    let val01 = if iu + 1 < cols {
-        *image.get_unchecked([iv, iu + 1, c])
-    } else {
-        val00
-    };
-    let val10 = if iv + 1 < rows {
-        *image.get_unchecked([iv + 1, iu, c])
-    } else {
-        val00
-    };
-    let val11 = if iu + 1 < cols && iv + 1 < rows {
-        *image.get_unchecked([iv + 1, iu + 1, c])
-    } else {
-        val00
-    };
+    let val00 = pixel_or_border(image, iu, iv, c, border);
+    let val01 = pixel_or_border(image, iu.saturating_add(1), iv, c, border);
+    let val10 = pixel_or_border(image, iu, iv.saturating_add(1), c, border);
+    let val11 = pixel_or_border(image, iu.saturating_add(1), iv.saturating_add(1), c, border);
 
     let frac_uu = 1. - frac_u;
     let frac_vv = 1. - frac_v;
@@ -1,6 +1,9 @@
+use super::bicubic::bicubic_interpolation;
 use super::bilinear::bilinear_interpolation;
+use super::lanczos::lanczos3_interpolation;
 use super::nearest::nearest_neighbor_interpolation;
-use kornia_image::Image;
+use crate::border::BorderMode;
+use kornia_image::{AsImageView, ImageView};
 
 /// Interpolation mode for the resize operation
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -9,10 +12,24 @@ pub enum InterpolationMode {
     Bilinear,
     /// Nearest neighbor interpolation
     Nearest,
+    /// Bicubic interpolation with the Catmull-Rom spline over 4x4 pixels
+    Bicubic,
+    /// Lanczos interpolation over 6x6 pixels
+    Lanczos3,
 }
 
+/// The largest distance in pixels from a coordinate to the pixels of an interpolation kernel.
+const KERNEL_RADIUS: f32 = 3.0;
+
+/// The largest magnitude of a coordinate passed to the kernels, far enough from the image that
+/// the border modes give the same value and small enough to compute the pixel indices.
+const MAX_COORDINATE: f32 = (1 << 24) as f32;
+
 /// Kernel for interpolating a pixel value
 ///
+/// The coordinates that are not finite, or out of the image by more than the kernel size with
+/// a constant border, give the constant value of the border.
+///
 /// # Arguments
 ///
 /// * `image` - The input image container with shape (height, width, C).
@@ -20,20 +37,180 @@ pub enum InterpolationMode {
 /// * `v` - The y coordinate of the pixel to interpolate.
 /// * `c` - The channel of the pixel to interpolate.
 /// * `interpolation` - The interpolation mode to use.
+/// * `border` - The extrapolation of the pixels outside the image.
 ///
 /// # Returns
 ///
 /// The interpolated pixel value.
 pub fn interpolate_pixel<const C: usize>(
-    //image: &ArrayView3<T>,
-    image: &Image<f32, C>,
+    image: &impl AsImageView<f32, C>,
     u: f32,
     v: f32,
     c: usize,
     interpolation: InterpolationMode,
+    border: BorderMode,
 ) -> f32 {
+    let image = image.as_view();
+
+    if !u.is_finite() || !v.is_finite() {
+        return border.constant_value();
+    }
+
+    if let BorderMode::Constant(value) = border {
+        let (max_u, max_v) = (image.cols() as f32, image.rows() as f32);
+        if u < -KERNEL_RADIUS
+            || v < -KERNEL_RADIUS
+            || u > max_u - 1.0 + KERNEL_RADIUS
+            || v > max_v - 1.0 + KERNEL_RADIUS
+        {
+            return value;
+        }
+    }
+
+    let u = u.clamp(-MAX_COORDINATE, MAX_COORDINATE);
+    let v = v.clamp(-MAX_COORDINATE, MAX_COORDINATE);
+
     match interpolation {
-        InterpolationMode::Bilinear => bilinear_interpolation(image, u, v, c),
-        InterpolationMode::Nearest => nearest_neighbor_interpolation(image, u, v, c),
+        InterpolationMode::Bilinear => bilinear_interpolation(&image, u, v, c, border),
+        InterpolationMode::Nearest => nearest_neighbor_interpolation(&image, u, v, c, border),
+        InterpolationMode::Bicubic => bicubic_interpolation(&image, u, v, c, border),
+        InterpolationMode::Lanczos3 => lanczos3_interpolation(&image, u, v, c, border),
+    }
+}
+
+/// Get the value of a pixel, extrapolating the coordinates outside the image.
+pub(crate) fn pixel_or_border<const C: usize>(
+    image: &ImageView<f32, C>,
+    iu: isize,
+    iv: isize,
+    c: usize,
+    border: BorderMode,
+) -> f32 {
+    match (
+        border.index(iu, image.cols()),
+        border.index(iv, image.rows()),
+    ) {
+        (Some(u), Some(v)) => *image.get_unchecked([v, u, c]),
+        _ => border.constant_value(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{interpolate_pixel, InterpolationMode};
+    use crate::border::BorderMode;
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    const MODES: [InterpolationMode; 4] = [
+        InterpolationMode::Bilinear,
+        InterpolationMode::Nearest,
+        InterpolationMode::Bicubic,
+        InterpolationMode::Lanczos3,
+    ];
+
+    #[test]
+    fn interpolate_at_pixels() -> Result<(), ImageError> {
+        let image = Image::<f32, 2>::new(
+            ImageSize {
+                width: 5,
+                height: 4,
+            },
+            (0..40).map(|i| ((i * 7) % 13) as f32).collect(),
+        )?;
+
+        // every kernel interpolates, the pixel values are kept at the pixel centers
+        for mode in MODES {
+            for (y, x, c) in [(0, 0, 0), (1, 2, 1), (3, 4, 0), (2, 1, 1)] {
+                let value =
+                    interpolate_pixel(&image, x as f32, y as f32, c, mode, BorderMode::Replicate);
+                assert!((value - image.get_pixel(x, y, c)?).abs() < 1e-5, "{mode:?}");
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn interpolate_between_pixels() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 8,
+            height: 8,
+        };
+        let constant = Image::<f32, 1>::from_size_val(size, 3.0)?;
+        let ramp = Image::<f32, 1>::new(size, (0..64).map(|i| (i % 8) as f32 * 2.0).collect())?;
+
+        for mode in MODES {
+            let value = interpolate_pixel(&constant, 3.3, 0.7, 0, mode, BorderMode::Replicate);
+            assert!((value - 3.0).abs() < 1e-5, "{mode:?}");
+        }
+
+        // the cubic convolution reproduces the linear functions away from the borders
+        let value = interpolate_pixel(
+            &ramp,
+            3.25,
+            4.5,
+            0,
+            InterpolationMode::Bicubic,
+            BorderMode::Reflect101,
+        );
+        assert!((value - 6.5).abs() < 1e-5);
+
+        // the coordinates outside the image take the border values
+        let value = interpolate_pixel(
+            &ramp,
+            -2.0,
+            9.0,
+            0,
+            InterpolationMode::Lanczos3,
+            BorderMode::Replicate,
+        );
+        assert!(value.abs() < 1e-5);
+
+        for (border, expected) in [
+            (BorderMode::Constant(-1.0), -1.0),
+            (BorderMode::Replicate, 0.0),
+            (BorderMode::Reflect, 2.0),
+            (BorderMode::Reflect101, 4.0),
+            (BorderMode::Wrap, 12.0),
+        ] {
+            let value = interpolate_pixel(&ramp, -2.0, 1.0, 0, InterpolationMode::Nearest, border);
+            assert_eq!(value, expected, "{border:?}");
+        }
+
+        // the bilinear interpolation blends with the constant at the border
+        let value = interpolate_pixel(
+            &ramp,
+            7.5,
+            2.0,
+            0,
+            InterpolationMode::Bilinear,
+            BorderMode::Constant(0.0),
+        );
+        assert_eq!(value, 7.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn interpolate_far_coordinates() -> Result<(), ImageError> {
+        let image = Image::<f32, 1>::from_size_val([4, 4].into(), 1.0)?;
+
+        // the coordinates that are not finite or very far do not overflow the pixel indices
+        for mode in MODES {
+            for (u, v) in [
+                (f32::INFINITY, 1.0),
+                (f32::NAN, 1.0),
+                (1.0, f32::NEG_INFINITY),
+                (1e30, -1e30),
+            ] {
+                let value = interpolate_pixel(&image, u, v, 0, mode, BorderMode::Constant(-1.0));
+                assert_eq!(value, -1.0, "{mode:?}");
+            }
+
+            let value = interpolate_pixel(&image, 1e30, 1.0, 0, mode, BorderMode::Replicate);
+            assert!((value - 1.0).abs() < 1e-5, "{mode:?}");
+        }
+
+        Ok(())
     }
 }
@@ -0,0 +1,39 @@
+use super::window::window_interpolation;
+use crate::border::BorderMode;
+use kornia_image::ImageView;
+use std::f32::consts::PI;
+
+/// The Lanczos kernel with a support of 3 pixels.
+fn lanczos3_weight(x: f32) -> f32 {
+    if x == 0.0 {
+        1.0
+    } else if x.abs() < 3.0 {
+        let px = PI * x;
+        3.0 * px.sin() * (px / 3.0).sin() / (px * px)
+    } else {
+        0.0
+    }
+}
+
+/// Kernel for Lanczos interpolation with a window of 6x6 pixels
+///
+/// # Arguments
+///
+/// * `image` - The input image container.
+/// * `u` - The x coordinate of the pixel to interpolate.
+/// * `v` - The y coordinate of the pixel to interpolate.
+/// * `c` - The channel of the pixel to interpolate.
+/// * `border` - The extrapolation of the pixels outside the image.
+///
+/// # Returns
+///
+/// The interpolated pixel value.
+pub(crate) fn lanczos3_interpolation<const C: usize>(
+    image: &ImageView<f32, C>,
+    u: f32,
+    v: f32,
+    c: usize,
+    border: BorderMode,
+) -> f32 {
+    window_interpolation::<C, 6>(image, u, v, c, border, lanczos3_weight)
+}
@@ -1,11 +1,14 @@
+mod bicubic;
 mod bilinear;
 
 /// Utility functions to generate meshgrid and remap images
 pub mod grid;
 
 pub(crate) mod interpolate;
+mod lanczos;
 mod nearest;
 mod remap;
+mod window;
 
 pub use interpolate::InterpolationMode;
 pub use remap::remap;
@@ -1,4 +1,6 @@
-use kornia_image::Image;
+use super::interpolate::pixel_or_border;
+use crate::border::BorderMode;
+use kornia_image::ImageView;
 
 /// Kernel for nearest neighbor interpolation
 ///
@@ -8,23 +10,20 @@ use kornia_image::Image;
 /// * `u` - The x coordinate of the pixel to interpolate.
 /// * `v` - The y coordinate of the pixel to interpolate.
 /// * `c` - The channel of the pixel to interpolate.
+/// * `border` - The extrapolation of the pixels outside the image.
 ///
 /// # Returns
 ///
 /// The interpolated pixel value.
 pub(crate) fn nearest_neighbor_interpolation<const C: usize>(
-    image: &Image<f32, C>,
+    image: &ImageView<f32, C>,
     u: f32,
     v: f32,
     c: usize,
+    border: BorderMode,
 ) -> f32 {
-    let (rows, cols) = (image.rows(), image.cols());
+    let iu = u.round() as isize;
+    let iv = v.round() as isize;
 
-    let iu = u.round() as usize;
-    let iv = v.round() as usize;
-
-    let iu = iu.clamp(0, cols - 1);
-    let iv = iv.clamp(0, rows - 1);
-
-    *image.get_unchecked([iv, iu, c])
+    pixel_or_border(image, iu, iv, c, border)
 }
@@ -2,7 +2,8 @@ use crate::parallel;
 
 use super::interpolate::interpolate_pixel;
 use super::InterpolationMode;
-use kornia_image::{Image, ImageError};
+use crate::border::BorderMode;
+use kornia_image::{AsImageView, AsImageViewMut, ImageError};
 use kornia_tensor::{CpuAllocator, Tensor2};
 
 /// Apply generic geometric transformation to an image.
@@ -14,18 +15,23 @@ use kornia_tensor::{CpuAllocator, Tensor2};
 /// * `map_x` - The x coordinates of the pixels to interpolate.
 /// * `map_y` - The y coordinates of the pixels to interpolate.
 /// * `interpolation` - The interpolation mode to use.
+/// * `border` - The extrapolation of the pixels outside the input image.
 ///
 /// # Errors
 ///
 /// * The mapx and mapy must have the same size.
 /// * The output image must have the same size as the mapx and mapy.
 pub fn remap<const C: usize>(
-    src: &Image<f32, C>,
-    dst: &mut Image<f32, C>,
+    src: &impl AsImageView<f32, C>,
+    dst: &mut impl AsImageViewMut<f32, C>,
     map_x: &Tensor2<f32, CpuAllocator>,
     map_y: &Tensor2<f32, CpuAllocator>,
     interpolation: InterpolationMode,
+    border: BorderMode,
 ) -> Result<(), ImageError> {
+    let src = src.as_view();
+    let mut dst = dst.as_view_mut();
+
     if map_x.shape != map_y.shape {
         return Err(ImageError::InvalidImageSize(
             map_x.shape[0],
@@ -35,20 +41,20 @@ pub fn remap<const C: usize>(
         ));
     }
 
-    if dst.shape[0..2] != map_x.shape {
+    if [dst.rows(), dst.cols()] != map_x.shape {
         return Err(ImageError::InvalidImageSize(
-            src.shape[0],
-            src.shape[1],
-            dst.shape[0],
-            dst.shape[1],
+            src.rows(),
+            src.cols(),
+            dst.rows(),
+            dst.cols(),
         ));
     }
 
     // parallelize the remap operation by rows
-    parallel::par_iter_rows_resample(dst, map_x, map_y, |&x, &y, dst_pixel| {
+    parallel::par_iter_rows_resample(&mut dst, map_x, map_y, |&x, &y, dst_pixel| {
         // interpolate the pixel value
         dst_pixel.iter_mut().enumerate().for_each(|(c, pixel)| {
-            *pixel = interpolate_pixel(src, x, y, c, interpolation);
+            *pixel = interpolate_pixel(&src, x, y, c, interpolation, border);
         });
     });
 
@@ -91,6 +97,7 @@ mod tests {
             &map_x,
             &map_y,
             super::InterpolationMode::Bilinear,
+            crate::border::BorderMode::Replicate,
         )?;
 
         assert_eq!(image_transformed.num_channels(), 1);
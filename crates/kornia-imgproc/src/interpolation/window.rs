@@ -0,0 +1,51 @@
+use super::interpolate::pixel_or_border;
+use crate::border::BorderMode;
+use kornia_image::ImageView;
+
+/// Kernel for interpolation with a separable filter over a window of N x N pixels
+///
+/// The weights are normalized to sum one.
+///
+/// # Arguments
+///
+/// * `image` - The input image container.
+/// * `u` - The x coordinate of the pixel to interpolate.
+/// * `v` - The y coordinate of the pixel to interpolate.
+/// * `c` - The channel of the pixel to interpolate.
+/// * `border` - The extrapolation of the pixels outside the image.
+/// * `weight` - The filter as a function of the distance to the pixel.
+///
+/// # Returns
+///
+/// The interpolated pixel value.
+pub(crate) fn window_interpolation<const C: usize, const N: usize>(
+    image: &ImageView<f32, C>,
+    u: f32,
+    v: f32,
+    c: usize,
+    border: BorderMode,
+    weight: impl Fn(f32) -> f32,
+) -> f32 {
+    // the first pixel of the window, which is centered between the pixels around (u, v)
+    let (u0, v0) = (u.floor() as isize, v.floor() as isize);
+    let first = 1 - N as isize / 2;
+
+    let wx: [f32; N] =
+        std::array::from_fn(|i| weight(u - u0.saturating_add(first + i as isize) as f32));
+    let wy: [f32; N] =
+        std::array::from_fn(|i| weight(v - v0.saturating_add(first + i as isize) as f32));
+
+    let mut acc = 0.0;
+    for (j, wy_j) in wy.iter().enumerate() {
+        let iv = v0.saturating_add(first + j as isize);
+        let mut row_acc = 0.0;
+        for (i, wx_i) in wx.iter().enumerate() {
+            let iu = u0.saturating_add(first + i as isize);
+            row_acc += pixel_or_border(image, iu, iv, c, border) * wx_i;
+        }
+        acc += row_acc * wy_j;
+    }
+
+    let norm = wx.iter().sum::<f32>() * wy.iter().sum::<f32>();
+    acc / norm
+}
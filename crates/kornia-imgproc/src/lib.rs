@@ -1,11 +1,20 @@
 #![deny(missing_docs)]
 #![doc = env!("CARGO_PKG_DESCRIPTION")]
+/// border extrapolation module.
+pub mod border;
+
 /// image undistortion module.
 pub mod calibration;
 
 /// color transformations module.
 pub mod color;
 
+/// connected components labeling module.
+pub mod connected_components;
+
+/// contour finding and shape descriptors module.
+pub mod contours;
+
 /// image basic operations module.
 pub mod core;
 
@@ -42,9 +51,15 @@ pub mod parallel;
 /// image processing metrics module.
 pub mod metrics;
 
+/// morphological operations module.
+pub mod morphology;
+
 /// operations to normalize images.
 pub mod normalize;
 
+/// sparse optical flow and feature tracking module.
+pub mod optical_flow;
+
 /// utility functions for resizing images.
 pub mod resize;
 
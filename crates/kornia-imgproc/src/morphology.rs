@@ -0,0 +1,581 @@
+use num_traits::{Bounded, Zero};
+
+use kornia_image::{AsImageView, AsImageViewMut, Image, ImageError, ImageView, ImageViewMut};
+
+use crate::parallel;
+
+/// A binary structuring element defining the neighbourhood of a morphological operation.
+///
+/// The anchor of the element is its center pixel `(width / 2, height / 2)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StructuringElement {
+    width: usize,
+    height: usize,
+    data: Vec<bool>,
+    is_rect: bool,
+}
+
+impl StructuringElement {
+    /// Create a custom structuring element from a row-major mask.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the element.
+    /// * `height` - The height of the element.
+    /// * `data` - The mask of the element with `width * height` values.
+    ///
+    /// # Errors
+    ///
+    /// If the element is empty or the mask does not match the size, an error is returned.
+    pub fn new(width: usize, height: usize, data: Vec<bool>) -> Result<Self, ImageError> {
+        if width == 0 || height == 0 {
+            return Err(ImageError::InvalidKernelLength(width, height));
+        }
+
+        if data.len() != width * height {
+            return Err(ImageError::InvalidKernelMask(data.len(), width * height));
+        }
+
+        let is_rect = data.iter().all(|&v| v);
+
+        Ok(Self {
+            width,
+            height,
+            data,
+            is_rect,
+        })
+    }
+
+    /// Create a rectangular structuring element.
+    ///
+    /// Rectangular elements are separable and are applied as a row and a column pass.
+    pub fn rect(width: usize, height: usize) -> Result<Self, ImageError> {
+        Self::new(width, height, vec![true; width * height])
+    }
+
+    /// Create a cross-shaped structuring element through the anchor.
+    pub fn cross(width: usize, height: usize) -> Result<Self, ImageError> {
+        let data = (0..height)
+            .flat_map(|y| (0..width).map(move |x| y == height / 2 || x == width / 2))
+            .collect();
+        Self::new(width, height, data)
+    }
+
+    /// Create an elliptic structuring element inscribed in the given size.
+    pub fn ellipse(width: usize, height: usize) -> Result<Self, ImageError> {
+        let (cx, cy) = ((width / 2) as f32, (height / 2) as f32);
+        let inv_cy2 = if cy > 0.0 { 1.0 / (cy * cy) } else { 0.0 };
+        let data = (0..height)
+            .flat_map(|y| {
+                // half the width of the ellipse at this row
+                let dy = y as f32 - cy;
+                let dx = (cx * ((cy * cy - dy * dy) * inv_cy2).max(0.0).sqrt()).round();
+                (0..width).map(move |x| (x as f32 - cx).abs() <= dx)
+            })
+            .collect();
+        Self::new(width, height, data)
+    }
+
+    /// Get the width of the element.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Get the height of the element.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Get the row-major mask of the element.
+    pub fn as_slice(&self) -> &[bool] {
+        &self.data
+    }
+
+    /// Get the (dy, dx) offsets of the active pixels relative to the anchor.
+    fn offsets(&self) -> Vec<(isize, isize)> {
+        let (ax, ay) = ((self.width / 2) as isize, (self.height / 2) as isize);
+        self.data
+            .iter()
+            .enumerate()
+            .filter(|(_, &v)| v)
+            .map(|(i, _)| {
+                let (y, x) = (i / self.width, i % self.width);
+                (y as isize - ay, x as isize - ax)
+            })
+            .collect()
+    }
+}
+
+/// Erode an image with a structuring element.
+///
+/// Each pixel is set to the minimum of the pixels under the element. The pixels outside
+/// the image are ignored.
+///
+/// # Arguments
+///
+/// * `src` - The input image with shape (H, W, C).
+/// * `dst` - The output image with shape (H, W, C).
+/// * `kernel` - The structuring element.
+///
+/// # Errors
+///
+/// If the images do not have the same size, an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::morphology::{erode, StructuringElement};
+///
+/// let image = Image::<u8, 1>::new(
+///     ImageSize { width: 3, height: 3 },
+///     vec![0, 255, 255, 255, 255, 255, 255, 255, 255],
+/// ).unwrap();
+///
+/// let mut eroded = Image::<u8, 1>::from_size_val(image.size(), 0).unwrap();
+///
+/// erode(&image, &mut eroded, &StructuringElement::rect(3, 3).unwrap()).unwrap();
+///
+/// assert_eq!(eroded.as_slice(), &[0, 0, 255, 0, 0, 255, 255, 255, 255]);
+/// ```
+pub fn erode<T, const C: usize>(
+    src: &impl AsImageView<T, C>,
+    dst: &mut impl AsImageViewMut<T, C>,
+    kernel: &StructuringElement,
+) -> Result<(), ImageError>
+where
+    T: Copy + Send + Sync + Default + PartialOrd + Bounded,
+{
+    morph(
+        &src.as_view(),
+        &mut dst.as_view_mut(),
+        kernel,
+        T::max_value(),
+        |a, b| if b < a { b } else { a },
+    )
+}
+
+/// Dilate an image with a structuring element.
+///
+/// Each pixel is set to the maximum of the pixels under the element. The pixels outside
+/// the image are ignored.
+///
+/// # Arguments
+///
+/// * `src` - The input image with shape (H, W, C).
+/// * `dst` - The output image with shape (H, W, C).
+/// * `kernel` - The structuring element.
+///
+/// # Errors
+///
+/// If the images do not have the same size, an error is returned.
+pub fn dilate<T, const C: usize>(
+    src: &impl AsImageView<T, C>,
+    dst: &mut impl AsImageViewMut<T, C>,
+    kernel: &StructuringElement,
+) -> Result<(), ImageError>
+where
+    T: Copy + Send + Sync + Default + PartialOrd + Bounded,
+{
+    morph(
+        &src.as_view(),
+        &mut dst.as_view_mut(),
+        kernel,
+        T::min_value(),
+        |a, b| if b > a { b } else { a },
+    )
+}
+
+/// Open an image with a structuring element, i.e. an erosion followed by a dilation.
+///
+/// Opening removes the bright details smaller than the element.
+///
+/// # Arguments
+///
+/// * `src` - The input image with shape (H, W, C).
+/// * `dst` - The output image with shape (H, W, C).
+/// * `kernel` - The structuring element.
+///
+/// # Errors
+///
+/// If the images do not have the same size, an error is returned.
+pub fn open<T, const C: usize>(
+    src: &impl AsImageView<T, C>,
+    dst: &mut impl AsImageViewMut<T, C>,
+    kernel: &StructuringElement,
+) -> Result<(), ImageError>
+where
+    T: Copy + Send + Sync + Default + PartialOrd + Bounded,
+{
+    let src = src.as_view();
+    let mut eroded = Image::from_size_val(src.size(), T::min_value())?;
+    erode(&src, &mut eroded, kernel)?;
+    dilate(&eroded, dst, kernel)
+}
+
+/// Close an image with a structuring element, i.e. a dilation followed by an erosion.
+///
+/// Closing fills the dark details smaller than the element.
+///
+/// # Arguments
+///
+/// * `src` - The input image with shape (H, W, C).
+/// * `dst` - The output image with shape (H, W, C).
+/// * `kernel` - The structuring element.
+///
+/// # Errors
+///
+/// If the images do not have the same size, an error is returned.
+pub fn close<T, const C: usize>(
+    src: &impl AsImageView<T, C>,
+    dst: &mut impl AsImageViewMut<T, C>,
+    kernel: &StructuringElement,
+) -> Result<(), ImageError>
+where
+    T: Copy + Send + Sync + Default + PartialOrd + Bounded,
+{
+    let src = src.as_view();
+    let mut dilated = Image::from_size_val(src.size(), T::min_value())?;
+    dilate(&src, &mut dilated, kernel)?;
+    erode(&dilated, dst, kernel)
+}
+
+/// Compute the morphological gradient of an image, i.e. the dilation minus the erosion.
+///
+/// # Arguments
+///
+/// * `src` - The input image with shape (H, W, C).
+/// * `dst` - The output image with shape (H, W, C).
+/// * `kernel` - The structuring element.
+///
+/// # Errors
+///
+/// If the images do not have the same size, an error is returned.
+pub fn gradient<T, const C: usize>(
+    src: &impl AsImageView<T, C>,
+    dst: &mut impl AsImageViewMut<T, C>,
+    kernel: &StructuringElement,
+) -> Result<(), ImageError>
+where
+    T: Copy + Send + Sync + Default + PartialOrd + Bounded + Zero + std::ops::Sub<Output = T>,
+{
+    let src = src.as_view();
+    let mut dilated = Image::from_size_val(src.size(), T::min_value())?;
+    let mut eroded = Image::from_size_val(src.size(), T::min_value())?;
+    dilate(&src, &mut dilated, kernel)?;
+    erode(&src, &mut eroded, kernel)?;
+    difference(&dilated, &eroded, dst)
+}
+
+/// Compute the top-hat of an image, i.e. the image minus its opening.
+///
+/// The top-hat keeps the bright details smaller than the element.
+///
+/// # Arguments
+///
+/// * `src` - The input image with shape (H, W, C).
+/// * `dst` - The output image with shape (H, W, C).
+/// * `kernel` - The structuring element.
+///
+/// # Errors
+///
+/// If the images do not have the same size, an error is returned.
+pub fn top_hat<T, const C: usize>(
+    src: &impl AsImageView<T, C>,
+    dst: &mut impl AsImageViewMut<T, C>,
+    kernel: &StructuringElement,
+) -> Result<(), ImageError>
+where
+    T: Copy + Send + Sync + Default + PartialOrd + Bounded + Zero + std::ops::Sub<Output = T>,
+{
+    let src = src.as_view();
+    let mut opened = Image::from_size_val(src.size(), T::min_value())?;
+    open(&src, &mut opened, kernel)?;
+    difference(&src, &opened, dst)
+}
+
+/// Compute the black-hat of an image, i.e. the closing minus the image.
+///
+/// The black-hat keeps the dark details smaller than the element.
+///
+/// # Arguments
+///
+/// * `src` - The input image with shape (H, W, C).
+/// * `dst` - The output image with shape (H, W, C).
+/// * `kernel` - The structuring element.
+///
+/// # Errors
+///
+/// If the images do not have the same size, an error is returned.
+pub fn black_hat<T, const C: usize>(
+    src: &impl AsImageView<T, C>,
+    dst: &mut impl AsImageViewMut<T, C>,
+    kernel: &StructuringElement,
+) -> Result<(), ImageError>
+where
+    T: Copy + Send + Sync + Default + PartialOrd + Bounded + Zero + std::ops::Sub<Output = T>,
+{
+    let src = src.as_view();
+    let mut closed = Image::from_size_val(src.size(), T::min_value())?;
+    close(&src, &mut closed, kernel)?;
+    difference(&closed, &src, dst)
+}
+
+/// Compute the difference of two images, saturating at zero.
+fn difference<T, const C: usize>(
+    src1: &impl AsImageView<T, C>,
+    src2: &impl AsImageView<T, C>,
+    dst: &mut impl AsImageViewMut<T, C>,
+) -> Result<(), ImageError>
+where
+    T: Copy + Send + Sync + PartialOrd + Zero + std::ops::Sub<Output = T>,
+{
+    let (src1, src2) = (src1.as_view(), src2.as_view());
+    let mut dst = dst.as_view_mut();
+
+    if src1.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src1.cols(),
+            src1.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    parallel::par_iter_rows_val_two(&src1, &src2, &mut dst, |&a, &b, d| {
+        *d = if a > b { a - b } else { T::zero() };
+    });
+
+    Ok(())
+}
+
+/// Reduce the pixels under the structuring element with the given operation.
+fn morph<T, const C: usize>(
+    src: &ImageView<T, C>,
+    dst: &mut ImageViewMut<T, C>,
+    kernel: &StructuringElement,
+    init: T,
+    op: impl Fn(T, T) -> T + Send + Sync,
+) -> Result<(), ImageError>
+where
+    T: Copy + Send + Sync + Default,
+{
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    if kernel.is_rect {
+        // separable fast path: reduce along the rows and then along the columns
+        let (ax, ay) = ((kernel.width / 2) as isize, (kernel.height / 2) as isize);
+        let horizontal = (0..kernel.width as isize)
+            .map(|x| (0, x - ax))
+            .collect::<Vec<_>>();
+        let vertical = (0..kernel.height as isize)
+            .map(|y| (y - ay, 0))
+            .collect::<Vec<_>>();
+
+        let mut tmp = Image::<T, C>::from_size_val(src.size(), init)?;
+        reduce(src, &mut tmp.view_mut(), &horizontal, init, &op);
+        reduce(&tmp.view(), dst, &vertical, init, &op);
+    } else {
+        reduce(src, dst, &kernel.offsets(), init, &op);
+    }
+
+    Ok(())
+}
+
+/// Reduce the pixels at the given (dy, dx) offsets, ignoring the ones outside the image.
+fn reduce<T, const C: usize>(
+    src: &ImageView<T, C>,
+    dst: &mut ImageViewMut<T, C>,
+    offsets: &[(isize, isize)],
+    init: T,
+    op: &(impl Fn(T, T) -> T + Send + Sync),
+) where
+    T: Copy + Send + Sync,
+{
+    let (rows, cols) = (src.rows() as isize, src.cols() as isize);
+
+    parallel::par_iter_rows_indexed_mut(dst, |r, dst_row| {
+        dst_row
+            .chunks_exact_mut(C)
+            .enumerate()
+            .for_each(|(c, dst_pixel)| {
+                let mut acc = [init; C];
+                for &(dy, dx) in offsets {
+                    let (y, x) = (r as isize + dy, c as isize + dx);
+                    if y < 0 || y >= rows || x < 0 || x >= cols {
+                        continue;
+                    }
+                    let x = x as usize * C;
+                    let src_pixel = &src.row(y as usize)[x..x + C];
+                    acc.iter_mut()
+                        .zip(src_pixel)
+                        .for_each(|(a, &v)| *a = op(*a, v));
+                }
+                dst_pixel.copy_from_slice(&acc);
+            });
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StructuringElement;
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    fn square(size: usize, x0: usize, y0: usize, side: usize) -> Result<Image<u8, 1>, ImageError> {
+        let data = (0..size * size)
+            .map(|i| {
+                let (y, x) = (i / size, i % size);
+                match (x0..x0 + side).contains(&x) && (y0..y0 + side).contains(&y) {
+                    true => 255,
+                    false => 0,
+                }
+            })
+            .collect();
+        Image::new(
+            ImageSize {
+                width: size,
+                height: size,
+            },
+            data,
+        )
+    }
+
+    #[test]
+    fn structuring_elements() -> Result<(), ImageError> {
+        let cross = StructuringElement::cross(3, 3)?;
+        assert_eq!(
+            cross.as_slice(),
+            &[false, true, false, true, true, true, false, true, false]
+        );
+
+        let ellipse = StructuringElement::ellipse(5, 5)?;
+        #[rustfmt::skip]
+        let expected = [
+            0, 0, 1, 0, 0,
+            1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1,
+            0, 0, 1, 0, 0,
+        ];
+        let ellipse_mask = ellipse
+            .as_slice()
+            .iter()
+            .map(|&v| v as u8)
+            .collect::<Vec<_>>();
+        assert_eq!(ellipse_mask, expected);
+
+        assert!(StructuringElement::rect(0, 3).is_err());
+        assert!(matches!(
+            StructuringElement::new(2, 2, vec![true; 3]),
+            Err(ImageError::InvalidKernelMask(3, 4))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn erode_dilate_rect_matches_custom() -> Result<(), ImageError> {
+        // the separable path and the generic path must agree
+        let image = Image::<f32, 2>::new(
+            ImageSize {
+                width: 5,
+                height: 4,
+            },
+            (0..40).map(|i| ((i * 37) % 11) as f32).collect(),
+        )?;
+        let rect = StructuringElement::rect(3, 2)?;
+        let mut mask = vec![true; 6];
+        mask[0] = false;
+        let custom = StructuringElement::new(3, 2, mask)?;
+        assert!(rect.is_rect && !custom.is_rect);
+
+        let mut generic = Image::<f32, 2>::from_size_val(image.size(), 0.0)?;
+        let mut separable = Image::<f32, 2>::from_size_val(image.size(), 0.0)?;
+
+        // force the generic path with an all-true custom element
+        let generic_rect = StructuringElement {
+            is_rect: false,
+            ..rect.clone()
+        };
+
+        super::erode(&image, &mut separable, &rect)?;
+        super::erode(&image, &mut generic, &generic_rect)?;
+        assert_eq!(separable.as_slice(), generic.as_slice());
+
+        super::dilate(&image, &mut separable, &rect)?;
+        super::dilate(&image, &mut generic, &generic_rect)?;
+        assert_eq!(separable.as_slice(), generic.as_slice());
+
+        Ok(())
+    }
+
+    #[test]
+    fn open_close() -> Result<(), ImageError> {
+        let kernel = StructuringElement::rect(3, 3)?;
+
+        // a square survives the opening and an isolated pixel does not
+        let mut image = square(8, 2, 2, 4)?;
+        image.as_slice_mut()[7 * 8 + 7] = 255;
+        let mut opened = Image::<u8, 1>::from_size_val(image.size(), 0)?;
+        super::open(&image, &mut opened, &kernel)?;
+        assert_eq!(opened.as_slice(), square(8, 2, 2, 4)?.as_slice());
+
+        // a hole in a square is filled by the closing
+        let mut image = square(8, 2, 2, 4)?;
+        image.as_slice_mut()[3 * 8 + 3] = 0;
+        let mut closed = Image::<u8, 1>::from_size_val(image.size(), 0)?;
+        super::close(&image, &mut closed, &kernel)?;
+        assert_eq!(closed.as_slice(), square(8, 2, 2, 4)?.as_slice());
+
+        Ok(())
+    }
+
+    #[test]
+    fn gradient_top_hat_black_hat() -> Result<(), ImageError> {
+        let kernel = StructuringElement::cross(3, 3)?;
+        let image = square(7, 1, 1, 5)?;
+
+        let mut grad = Image::<u8, 1>::from_size_val(image.size(), 0)?;
+        super::gradient(&image, &mut grad, &kernel)?;
+        // the dilated square with rounded corners minus its eroded 3x3 interior
+        #[rustfmt::skip]
+        let expected = [
+            0, 1, 1, 1, 1, 1, 0,
+            1, 1, 1, 1, 1, 1, 1,
+            1, 1, 0, 0, 0, 1, 1,
+            1, 1, 0, 0, 0, 1, 1,
+            1, 1, 0, 0, 0, 1, 1,
+            1, 1, 1, 1, 1, 1, 1,
+            0, 1, 1, 1, 1, 1, 0,
+        ];
+        let expected = expected.iter().map(|&v| v * 255).collect::<Vec<u8>>();
+        assert_eq!(grad.as_slice(), expected);
+
+        let image = square(6, 2, 2, 2)?;
+
+        // the square is smaller than a 5x5 element, so it is all top-hat
+        let kernel = StructuringElement::rect(5, 5)?;
+        let mut top = Image::<u8, 1>::from_size_val(image.size(), 0)?;
+        super::top_hat(&image, &mut top, &kernel)?;
+        assert_eq!(top.as_slice(), image.as_slice());
+
+        // a dark pixel in a bright image is all black-hat
+        let mut image = Image::<u8, 1>::from_size_val(image.size(), 200)?;
+        image.as_slice_mut()[14] = 50;
+        let mut black = Image::<u8, 1>::from_size_val(image.size(), 0)?;
+        super::black_hat(&image, &mut black, &kernel)?;
+        let expected = (0..36)
+            .map(|i| if i == 14 { 150 } else { 0 })
+            .collect::<Vec<_>>();
+        assert_eq!(black.as_slice(), expected);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,688 @@
+use kornia_image::{Image, ImageError};
+use rayon::prelude::*;
+
+use crate::border::BorderMode;
+use crate::features::fast_feature_detector;
+use crate::filter::spatial_gradient_float_parallel_row;
+use crate::interpolation::{interpolate_pixel, InterpolationMode};
+use crate::pyramid::build_pyramid;
+
+/// The result of the tracking of a point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlowStatus {
+    /// The point was tracked.
+    Tracked,
+    /// The point left the image.
+    OutOfImage,
+    /// The window of the point is not textured enough to be tracked, e.g. a flat region or
+    /// a straight edge.
+    SmallEigenvalue,
+}
+
+/// A point tracked by the Lucas-Kanade optical flow.
+///
+/// # Fields
+///
+/// * `position` - The (x, y) position of the point in the next image
+/// * `status` - Whether the point was tracked
+/// * `error` - The mean absolute difference of the windows of the point in the two images,
+///   infinite if the point was not tracked
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlowPoint {
+    /// The (x, y) position of the point in the next image
+    pub position: [f32; 2],
+    /// Whether the point was tracked
+    pub status: FlowStatus,
+    /// The mean absolute difference of the windows of the point in the two images, infinite
+    /// if the point was not tracked
+    pub error: f32,
+}
+
+/// A builder object of the pyramidal Lucas-Kanade sparse optical flow.
+///
+/// The displacement of each point is estimated from the coarsest to the finest level of the
+/// pyramids of the two images, by iteratively aligning the window around the point. Implements
+/// "Pyramidal Implementation of the Lucas Kanade Feature Tracker", Bouguet, 2000.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::optical_flow::{FlowStatus, LucasKanade};
+///
+/// // a smooth texture moving one pixel to the right
+/// let texture = |dx: f32| {
+///     Image::<f32, 1>::new(
+///         ImageSize { width: 48, height: 48 },
+///         (0..48 * 48)
+///             .map(|i| {
+///                 let (x, y) = ((i % 48) as f32 - dx, (i / 48) as f32);
+///                 (x * 0.3).sin() * (y * 0.25).cos()
+///             })
+///             .collect(),
+///     )
+///     .unwrap()
+/// };
+///
+/// let flow = LucasKanade::new().compute(&texture(0.0), &texture(1.0), &[[24.0, 24.0]]).unwrap();
+///
+/// assert_eq!(flow[0].status, FlowStatus::Tracked);
+/// assert!((flow[0].position[0] - 25.0).abs() < 0.05);
+/// assert!((flow[0].position[1] - 24.0).abs() < 0.05);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LucasKanade {
+    window_size: usize,
+    num_levels: usize,
+    max_iterations: usize,
+    epsilon: f32,
+    min_eigen_threshold: f32,
+}
+
+impl Default for LucasKanade {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LucasKanade {
+    /// Creates a Lucas-Kanade optical flow with a 21x21 window over 3 pyramid levels.
+    pub fn new() -> Self {
+        Self {
+            window_size: 21,
+            num_levels: 3,
+            max_iterations: 30,
+            epsilon: 0.01,
+            min_eigen_threshold: 1e-4,
+        }
+    }
+
+    /// Sets the odd size of the square window around each point.
+    pub fn with_window_size(self, window_size: usize) -> Self {
+        Self {
+            window_size,
+            ..self
+        }
+    }
+
+    /// Sets the number of levels of the pyramids, 1 to track on the images only.
+    pub fn with_num_levels(self, num_levels: usize) -> Self {
+        Self { num_levels, ..self }
+    }
+
+    /// Sets the largest number of iterations at each level.
+    pub fn with_max_iterations(self, max_iterations: usize) -> Self {
+        Self {
+            max_iterations,
+            ..self
+        }
+    }
+
+    /// Sets the update of the displacement in pixels below which the iterations stop.
+    pub fn with_epsilon(self, epsilon: f32) -> Self {
+        Self { epsilon, ..self }
+    }
+
+    /// Sets the smallest eigenvalue of the structure tensor of a window, normalized by the
+    /// window area, below which a point is not tracked.
+    pub fn with_min_eigen_threshold(self, min_eigen_threshold: f32) -> Self {
+        Self {
+            min_eigen_threshold,
+            ..self
+        }
+    }
+
+    /// Computes the positions of points of an image in the next image.
+    ///
+    /// # Arguments
+    ///
+    /// * `prev` - The grayscale image where the points are.
+    /// * `next` - The next grayscale image.
+    /// * `points` - The (x, y) positions of the points in the previous image.
+    ///
+    /// # Returns
+    ///
+    /// The tracked points in the same order.
+    ///
+    /// # Errors
+    ///
+    /// If the images do not have the same size, the window size is not odd or the number of
+    /// levels is zero, an error is returned.
+    pub fn compute(
+        &self,
+        prev: &Image<f32, 1>,
+        next: &Image<f32, 1>,
+        points: &[[f32; 2]],
+    ) -> Result<Vec<FlowPoint>, ImageError> {
+        if prev.size() != next.size() {
+            return Err(ImageError::InvalidImageSize(
+                prev.cols(),
+                prev.rows(),
+                next.cols(),
+                next.rows(),
+            ));
+        }
+
+        if self.num_levels == 0 {
+            return Err(ImageError::InvalidPyramidLevels(self.num_levels));
+        }
+
+        let prev_pyramid = build_pyramid(prev, self.num_levels, BorderMode::Reflect101)?;
+        let next_pyramid = build_pyramid(next, self.num_levels, BorderMode::Reflect101)?;
+
+        self.compute_pyramids(&prev_pyramid, &next_pyramid, points)
+    }
+
+    /// Computes the positions of points between the pyramids of two images.
+    fn compute_pyramids(
+        &self,
+        prev_pyramid: &[Image<f32, 1>],
+        next_pyramid: &[Image<f32, 1>],
+        points: &[[f32; 2]],
+    ) -> Result<Vec<FlowPoint>, ImageError> {
+        if self.window_size % 2 == 0 {
+            return Err(ImageError::InvalidKernelLength(
+                self.window_size,
+                self.window_size,
+            ));
+        }
+
+        if prev_pyramid.is_empty() || next_pyramid.is_empty() {
+            return Err(ImageError::InvalidPyramidLevels(0));
+        }
+
+        // the derivatives of the previous images at the template windows
+        let gradients = prev_pyramid
+            .iter()
+            .map(|level| {
+                let mut dx = Image::<f32, 1>::from_size_val(level.size(), 0.0)?;
+                let mut dy = Image::<f32, 1>::from_size_val(level.size(), 0.0)?;
+                spatial_gradient_float_parallel_row(
+                    level,
+                    &mut dx,
+                    &mut dy,
+                    BorderMode::Replicate,
+                )?;
+                Ok((dx, dy))
+            })
+            .collect::<Result<Vec<_>, ImageError>>()?;
+
+        Ok(points
+            .par_iter()
+            .map(|&point| self.track_point(prev_pyramid, &gradients, next_pyramid, point))
+            .collect())
+    }
+
+    fn track_point(
+        &self,
+        prev_pyramid: &[Image<f32, 1>],
+        gradients: &[(Image<f32, 1>, Image<f32, 1>)],
+        next_pyramid: &[Image<f32, 1>],
+        point: [f32; 2],
+    ) -> FlowPoint {
+        let half = (self.window_size / 2) as isize;
+        let window = (-half..=half)
+            .flat_map(|wy| (-half..=half).map(move |wx| (wx as f32, wy as f32)))
+            .collect::<Vec<_>>();
+        let area = window.len() as f32;
+
+        let sample = |image: &Image<f32, 1>, x: f32, y: f32| {
+            interpolate_pixel(
+                image,
+                x,
+                y,
+                0,
+                InterpolationMode::Bilinear,
+                BorderMode::Replicate,
+            )
+        };
+        let lost = |status| FlowPoint {
+            position: point,
+            status,
+            error: f32::INFINITY,
+        };
+
+        // the points outside the previous image have no template window
+        let base = &prev_pyramid[0];
+        let inside = (0.0..=(base.cols() - 1) as f32).contains(&point[0])
+            && (0.0..=(base.rows() - 1) as f32).contains(&point[1]);
+        if !inside {
+            return lost(FlowStatus::OutOfImage);
+        }
+
+        // the displacement estimated at the coarser levels, in pixels of the current level
+        let mut guess = [0.0f32; 2];
+        let mut template = Vec::with_capacity(window.len());
+
+        for level in (0..prev_pyramid.len().min(next_pyramid.len())).rev() {
+            let (prev, next) = (&prev_pyramid[level], &next_pyramid[level]);
+            let (grad_x, grad_y) = &gradients[level];
+            let scale = 2f32.powi(level as i32);
+            let [px, py] = [point[0] / scale, point[1] / scale];
+
+            // the intensities and derivatives of the template window and its structure tensor
+            template.clear();
+            let (mut gxx, mut gxy, mut gyy) = (0.0, 0.0, 0.0);
+            for &(wx, wy) in &window {
+                let (x, y) = (px + wx, py + wy);
+                let (ix, iy) = (sample(grad_x, x, y), sample(grad_y, x, y));
+                template.push((sample(prev, x, y), ix, iy));
+                gxx += ix * ix;
+                gxy += ix * iy;
+                gyy += iy * iy;
+            }
+
+            let min_eigenvalue = 0.5 * (gxx + gyy - ((gxx - gyy).powi(2) + 4.0 * gxy * gxy).sqrt());
+            let det = gxx * gyy - gxy * gxy;
+            if min_eigenvalue / area < self.min_eigen_threshold || det.abs() < f32::EPSILON {
+                return lost(FlowStatus::SmallEigenvalue);
+            }
+
+            // align the window of the next image with gauss-newton iterations
+            let mut flow = guess;
+            for _ in 0..self.max_iterations {
+                let [qx, qy] = [px + flow[0], py + flow[1]];
+                if !(-(half as f32)..next.cols() as f32 + half as f32).contains(&qx)
+                    || !(-(half as f32)..next.rows() as f32 + half as f32).contains(&qy)
+                {
+                    return lost(FlowStatus::OutOfImage);
+                }
+
+                let (mut bx, mut by) = (0.0, 0.0);
+                for (&(wx, wy), &(i, ix, iy)) in window.iter().zip(&template) {
+                    let diff = i - sample(next, qx + wx, qy + wy);
+                    bx += diff * ix;
+                    by += diff * iy;
+                }
+
+                let step = [(gyy * bx - gxy * by) / det, (gxx * by - gxy * bx) / det];
+                flow = [flow[0] + step[0], flow[1] + step[1]];
+                if step[0] * step[0] + step[1] * step[1] < self.epsilon * self.epsilon {
+                    break;
+                }
+            }
+
+            if level > 0 {
+                guess = [2.0 * flow[0], 2.0 * flow[1]];
+                continue;
+            }
+
+            let position = [px + flow[0], py + flow[1]];
+            let inside = (0.0..=(next.cols() - 1) as f32).contains(&position[0])
+                && (0.0..=(next.rows() - 1) as f32).contains(&position[1]);
+            if !inside {
+                return lost(FlowStatus::OutOfImage);
+            }
+
+            let error = window
+                .iter()
+                .zip(&template)
+                .map(|(&(wx, wy), &(i, _, _))| {
+                    (i - sample(next, position[0] + wx, position[1] + wy)).abs()
+                })
+                .sum::<f32>()
+                / area;
+
+            return FlowPoint {
+                position,
+                status: FlowStatus::Tracked,
+                error,
+            };
+        }
+
+        lost(FlowStatus::OutOfImage)
+    }
+}
+
+/// A feature tracked across the frames of a video.
+///
+/// # Fields
+///
+/// * `id` - The unique identifier of the track
+/// * `position` - The (x, y) position of the feature in the last frame
+/// * `age` - The number of frames the feature was tracked for
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Track {
+    /// The unique identifier of the track
+    pub id: usize,
+    /// The (x, y) position of the feature in the last frame
+    pub position: [f32; 2],
+    /// The number of frames the feature was tracked for
+    pub age: usize,
+}
+
+/// A Kanade-Lucas-Tomasi feature tracker.
+///
+/// The features are tracked from frame to frame with the [`LucasKanade`] optical flow, and
+/// new FAST corners are detected away from the existing tracks when tracks are lost.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::collections::HashMap;
+/// use kornia_image::Image;
+/// use kornia_imgproc::optical_flow::KltTracker;
+///
+/// let mut tracker = KltTracker::new(200);
+///
+/// // the positions of each track over the frames
+/// let mut trajectories = HashMap::<usize, Vec<[f32; 2]>>::new();
+///
+/// # let frames: Vec<Image<u8, 1>> = vec![];
+/// for frame in &frames {
+///     for track in tracker.update(frame).unwrap() {
+///         trajectories.entry(track.id).or_default().push(track.position);
+///     }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct KltTracker {
+    flow: LucasKanade,
+    max_tracks: usize,
+    fast_threshold: u8,
+    min_distance: f32,
+    max_error: f32,
+    tracks: Vec<Track>,
+    prev_pyramid: Vec<Image<f32, 1>>,
+    next_id: usize,
+}
+
+impl KltTracker {
+    /// Creates a tracker of at most `max_tracks` features.
+    pub fn new(max_tracks: usize) -> Self {
+        Self {
+            flow: LucasKanade::new(),
+            max_tracks,
+            fast_threshold: 20,
+            min_distance: 10.0,
+            max_error: f32::INFINITY,
+            tracks: Vec::new(),
+            prev_pyramid: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Sets the optical flow of the tracks.
+    pub fn with_flow(self, flow: LucasKanade) -> Self {
+        Self { flow, ..self }
+    }
+
+    /// Sets the threshold of the FAST detector of the new features.
+    pub fn with_fast_threshold(self, fast_threshold: u8) -> Self {
+        Self {
+            fast_threshold,
+            ..self
+        }
+    }
+
+    /// Sets the smallest distance in pixels between two features.
+    pub fn with_min_distance(self, min_distance: f32) -> Self {
+        Self {
+            min_distance,
+            ..self
+        }
+    }
+
+    /// Sets the largest error of the optical flow, in intensities between 0 and 1, above
+    /// which a track is lost.
+    pub fn with_max_error(self, max_error: f32) -> Self {
+        Self { max_error, ..self }
+    }
+
+    /// The tracks of the last frame.
+    pub fn tracks(&self) -> &[Track] {
+        &self.tracks
+    }
+
+    /// Drops the tracks and the last frame.
+    pub fn reset(&mut self) {
+        self.tracks.clear();
+        self.prev_pyramid.clear();
+    }
+
+    /// Tracks the features in a new frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - The new grayscale frame, of the size of the previous frames.
+    ///
+    /// # Returns
+    ///
+    /// The tracks of the frame, the tracked features first.
+    ///
+    /// # Errors
+    ///
+    /// If the frame does not have the size of the previous frame, an error is returned.
+    pub fn update(&mut self, frame: &Image<u8, 1>) -> Result<&[Track], ImageError> {
+        let image = frame.clone().cast_and_scale::<f32>(1.0 / 255.0)?;
+        let pyramid = build_pyramid(&image, self.flow.num_levels, BorderMode::Reflect101)?;
+
+        if let Some(prev) = self.prev_pyramid.first() {
+            if prev.size() != image.size() {
+                return Err(ImageError::InvalidImageSize(
+                    prev.cols(),
+                    prev.rows(),
+                    image.cols(),
+                    image.rows(),
+                ));
+            }
+
+            let points = self.tracks.iter().map(|t| t.position).collect::<Vec<_>>();
+            let flow = self
+                .flow
+                .compute_pyramids(&self.prev_pyramid, &pyramid, &points)?;
+
+            self.tracks = self
+                .tracks
+                .iter()
+                .zip(flow)
+                .filter(|(_, f)| f.status == FlowStatus::Tracked && f.error <= self.max_error)
+                .map(|(t, f)| Track {
+                    id: t.id,
+                    position: f.position,
+                    age: t.age + 1,
+                })
+                .collect();
+        }
+
+        if self.tracks.len() < self.max_tracks {
+            self.detect_tracks(frame)?;
+        }
+
+        self.prev_pyramid = pyramid;
+
+        Ok(&self.tracks)
+    }
+
+    /// Adds the strongest FAST corners away from the existing tracks.
+    fn detect_tracks(&mut self, frame: &Image<u8, 1>) -> Result<(), ImageError> {
+        let mut corners = fast_feature_detector(frame, self.fast_threshold, 9)?;
+        corners.sort_by(|a, b| b.response.total_cmp(&a.response));
+
+        let min_distance_sq = self.min_distance * self.min_distance;
+        for corner in corners {
+            if self.tracks.len() >= self.max_tracks {
+                break;
+            }
+
+            let [x, y] = corner.position;
+            let is_free = self.tracks.iter().all(|t| {
+                let (dx, dy) = (t.position[0] - x, t.position[1] - y);
+                dx * dx + dy * dy >= min_distance_sq
+            });
+            if is_free {
+                self.tracks.push(Track {
+                    id: self.next_id,
+                    position: corner.position,
+                    age: 0,
+                });
+                self.next_id += 1;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filter::gaussian_blur;
+    use kornia_image::ImageSize;
+
+    /// A smooth texture translated by (dx, dy).
+    fn texture(size: ImageSize, dx: f32, dy: f32) -> Result<Image<f32, 1>, ImageError> {
+        Image::new(
+            size,
+            (0..size.width * size.height)
+                .map(|i| {
+                    let x = (i % size.width) as f32 - dx;
+                    let y = (i / size.width) as f32 - dy;
+                    0.5 + 0.2 * (x * 0.21).sin() * (y * 0.17).cos() + 0.2 * ((x + y) * 0.11).sin()
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_lucas_kanade_translation() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 96,
+            height: 96,
+        };
+        let points = [[30.0, 30.0], [48.5, 50.0], [64.0, 40.0]];
+
+        // a subpixel motion and a motion larger than the window, found with the pyramid
+        for (dx, dy) in [(0.6, -0.3), (7.4, 5.8)] {
+            let prev = texture(size, 0.0, 0.0)?;
+            let next = texture(size, dx, dy)?;
+
+            let flow = LucasKanade::new().compute(&prev, &next, &points)?;
+            for (p, f) in points.iter().zip(&flow) {
+                assert_eq!(f.status, FlowStatus::Tracked);
+                assert!((f.position[0] - p[0] - dx).abs() < 0.05, "{f:?}");
+                assert!((f.position[1] - p[1] - dy).abs() < 0.05, "{f:?}");
+                assert!(f.error < 1e-2);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lucas_kanade_lost() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 64,
+            height: 64,
+        };
+        let prev = texture(size, 0.0, 0.0)?;
+        let next = texture(size, -4.0, 0.0)?;
+
+        // a flat image and a point moving out of the image
+        let flat = Image::<f32, 1>::from_size_val(size, 0.5)?;
+        let flow = LucasKanade::new().compute(&flat, &flat, &[[32.0, 32.0]])?;
+        assert_eq!(flow[0].status, FlowStatus::SmallEigenvalue);
+        assert_eq!(flow[0].error, f32::INFINITY);
+
+        let flow = LucasKanade::new().compute(&prev, &next, &[[1.0, 32.0]])?;
+        assert_eq!(flow[0].status, FlowStatus::OutOfImage);
+
+        // the points that are not finite or outside the previous image
+        let points = [
+            [f32::INFINITY, 5.0],
+            [5.0, f32::NAN],
+            [-1.0, 5.0],
+            [5.0, 64.0],
+        ];
+        let flow = LucasKanade::new().compute(&prev, &prev, &points)?;
+        assert!(flow.iter().all(|f| f.status == FlowStatus::OutOfImage));
+
+        assert!(LucasKanade::new()
+            .with_num_levels(0)
+            .compute(&prev, &next, &[])
+            .is_err());
+
+        let small = Image::<f32, 1>::from_size_val([32, 32].into(), 0.5)?;
+        assert!(LucasKanade::new().compute(&prev, &small, &[]).is_err());
+        assert!(LucasKanade::new()
+            .with_window_size(4)
+            .compute(&prev, &next, &[])
+            .is_err());
+
+        Ok(())
+    }
+
+    /// Blurred bright squares translated by (dx, dy).
+    fn squares(size: ImageSize, dx: usize, dy: usize) -> Result<Image<u8, 1>, ImageError> {
+        let mut image = Image::<f32, 1>::from_size_val(size, 0.1)?;
+        for (x0, y0) in [(12, 10), (40, 14), (20, 40), (46, 44)] {
+            for y in y0 + dy..y0 + dy + 10 {
+                for x in x0 + dx..x0 + dx + 10 {
+                    image.as_slice_mut()[y * size.width + x] = 0.9;
+                }
+            }
+        }
+
+        let mut blurred = Image::<f32, 1>::from_size_val(size, 0.0)?;
+        gaussian_blur(
+            &image,
+            &mut blurred,
+            (7, 7),
+            (1.0, 1.0),
+            BorderMode::Replicate,
+        )?;
+        Image::new(
+            size,
+            blurred
+                .as_slice()
+                .iter()
+                .map(|v| (v * 255.0) as u8)
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_klt_tracker() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 72,
+            height: 72,
+        };
+        let mut tracker = KltTracker::new(16)
+            .with_flow(LucasKanade::new().with_window_size(9).with_num_levels(2))
+            .with_min_distance(5.0)
+            .with_max_error(0.05);
+
+        let first = tracker.update(&squares(size, 0, 0)?)?.to_vec();
+        assert!(first.len() >= 4 && first.len() <= 16, "{first:?}");
+        assert!(first.iter().all(|t| t.age == 0));
+
+        // the corners move with the squares and keep their identifiers
+        let second = tracker.update(&squares(size, 2, 1)?)?.to_vec();
+        assert!(second.len() >= first.len());
+        for (t0, t1) in first.iter().zip(&second) {
+            assert_eq!((t0.id, t1.age), (t1.id, 1));
+            assert!(
+                (t1.position[0] - t0.position[0] - 2.0).abs() < 0.2,
+                "{t1:?}"
+            );
+            assert!(
+                (t1.position[1] - t0.position[1] - 1.0).abs() < 0.2,
+                "{t1:?}"
+            );
+        }
+
+        // the tracks are lost on a flat frame, and new ones are detected afterwards
+        let flat = Image::<u8, 1>::from_size_val(size, 25)?;
+        assert!(tracker.update(&flat)?.is_empty());
+
+        let redetected = tracker.update(&squares(size, 0, 0)?)?.to_vec();
+        assert_eq!(redetected.len(), first.len());
+        assert!(redetected.iter().all(|t| t.id >= first.len() && t.age == 0));
+
+        assert!(tracker
+            .update(&Image::from_size_val([8, 8].into(), 0)?)
+            .is_err());
+
+        Ok(())
+    }
+}
@@ -1,8 +1,28 @@
 use rayon::prelude::*;
 
-use kornia_image::Image;
+use kornia_image::{AsImageView, AsImageViewMut, ImageView, ImageViewMut};
 use kornia_tensor::{CpuAllocator, Tensor2};
 
+/// Iterate over the rows of a view in parallel.
+fn par_rows<'a, T: Sync, const C: usize>(
+    view: &ImageView<'a, T, C>,
+) -> impl IndexedParallelIterator<Item = &'a [T]> {
+    let row_len = view.cols() * C;
+    view.as_slice()
+        .par_chunks(view.stride().max(1))
+        .map(move |row| &row[..row_len])
+}
+
+/// Iterate over the mutable rows of a view in parallel.
+fn par_rows_mut<'a, T: Send, const C: usize>(
+    view: &'a mut ImageViewMut<'_, T, C>,
+) -> impl IndexedParallelIterator<Item = &'a mut [T]> {
+    let (row_len, stride) = (view.cols() * C, view.stride().max(1));
+    view.as_slice_mut()
+        .par_chunks_mut(stride)
+        .map(move |row| &mut row[..row_len])
+}
+
 /// Apply a function to each pixel in the image in parallel.
 ///
 /// # Arguments
@@ -11,16 +31,18 @@ use kornia_tensor::{CpuAllocator, Tensor2};
 /// * `dst` - The output image.
 /// * `f` - The function to apply to each pixel.
 pub fn par_iter_rows<T1, const C1: usize, T2, const C2: usize>(
-    src: &Image<T1, C1>,
-    dst: &mut Image<T2, C2>,
+    src: &impl AsImageView<T1, C1>,
+    dst: &mut impl AsImageViewMut<T2, C2>,
     f: impl Fn(&[T1], &mut [T2]) + Send + Sync,
 ) where
     T1: Clone + Send + Sync,
     T2: Clone + Send + Sync,
 {
-    src.as_slice()
-        .par_chunks_exact(C1 * src.cols())
-        .zip(dst.as_slice_mut().par_chunks_exact_mut(C2 * src.cols()))
+    let src = src.as_view();
+    let mut dst = dst.as_view_mut();
+
+    par_rows(&src)
+        .zip(par_rows_mut(&mut dst))
         .for_each(|(src_chunk, dst_chunk)| {
             src_chunk
                 .chunks_exact(C1)
@@ -33,16 +55,18 @@ pub fn par_iter_rows<T1, const C1: usize, T2, const C2: usize>(
 
 /// Apply a function to each pixel in the image in parallel with a value.
 pub fn par_iter_rows_val<T1, const C1: usize, T2, const C2: usize>(
-    src: &Image<T1, C1>,
-    dst: &mut Image<T2, C2>,
+    src: &impl AsImageView<T1, C1>,
+    dst: &mut impl AsImageViewMut<T2, C2>,
     f: impl Fn(&T1, &mut T2) + Send + Sync,
 ) where
     T1: Clone + Send + Sync,
     T2: Clone + Send + Sync,
 {
-    src.as_slice()
-        .par_chunks_exact(C1 * src.cols())
-        .zip(dst.as_slice_mut().par_chunks_exact_mut(C2 * src.cols()))
+    let src = src.as_view();
+    let mut dst = dst.as_view_mut();
+
+    par_rows(&src)
+        .zip(par_rows_mut(&mut dst))
         .for_each(|(src_chunk, dst_chunk)| {
             src_chunk
                 .iter()
@@ -55,19 +79,22 @@ pub fn par_iter_rows_val<T1, const C1: usize, T2, const C2: usize>(
 
 /// Apply a function to each pixel in the image in parallel with two values.
 pub fn par_iter_rows_val_two<T1, const C1: usize, T2, const C2: usize, T3, const C3: usize>(
-    src1: &Image<T1, C1>,
-    src2: &Image<T2, C2>,
-    dst: &mut Image<T3, C3>,
+    src1: &impl AsImageView<T1, C1>,
+    src2: &impl AsImageView<T2, C2>,
+    dst: &mut impl AsImageViewMut<T3, C3>,
     f: impl Fn(&T1, &T2, &mut T3) + Send + Sync,
 ) where
     T1: Clone + Send + Sync,
     T2: Clone + Send + Sync,
     T3: Clone + Send + Sync,
 {
-    src1.as_slice()
-        .par_chunks_exact(C1 * src1.cols())
-        .zip(src2.as_slice().par_chunks_exact(C2 * src1.cols()))
-        .zip(dst.as_slice_mut().par_chunks_exact_mut(C3 * src1.cols()))
+    let src1 = src1.as_view();
+    let src2 = src2.as_view();
+    let mut dst = dst.as_view_mut();
+
+    par_rows(&src1)
+        .zip(par_rows(&src2))
+        .zip(par_rows_mut(&mut dst))
         .for_each(|((src1_chunk, src2_chunk), dst_chunk)| {
             src1_chunk
                 .iter()
@@ -79,20 +106,33 @@ pub fn par_iter_rows_val_two<T1, const C1: usize, T2, const C2: usize, T3, const
         });
 }
 
+/// Apply a function to each row of the image in parallel with the index of the row.
+pub fn par_iter_rows_indexed_mut<T, const C: usize>(
+    dst: &mut impl AsImageViewMut<T, C>,
+    f: impl Fn(usize, &mut [T]) + Send + Sync,
+) where
+    T: Send,
+{
+    let mut dst = dst.as_view_mut();
+
+    par_rows_mut(&mut dst)
+        .enumerate()
+        .for_each(|(r, dst_row)| f(r, dst_row));
+}
+
 /// Apply a function to each pixel for grid sampling in parallel.
 pub fn par_iter_rows_resample<const C: usize>(
-    dst: &mut Image<f32, C>,
+    dst: &mut impl AsImageViewMut<f32, C>,
     map_x: &Tensor2<f32, CpuAllocator>,
     map_y: &Tensor2<f32, CpuAllocator>,
     f: impl Fn(&f32, &f32, &mut [f32]) + Send + Sync,
 ) {
+    let mut dst = dst.as_view_mut();
     let cols = dst.cols();
-    let dst_slice = dst.as_slice_mut();
     let map_x_slice = map_x.as_slice();
     let map_y_slice = map_y.as_slice();
 
-    dst_slice
-        .par_chunks_exact_mut(C * cols)
+    par_rows_mut(&mut dst)
         .zip(map_x_slice.par_chunks_exact(cols))
         .zip(map_y_slice.par_chunks_exact(cols))
         .for_each(|((dst_chunk, map_x_chunk), map_y_chunk)| {
@@ -104,3 +144,34 @@ pub fn par_iter_rows_resample<const C: usize>(
                 });
         });
 }
+
+#[cfg(test)]
+mod tests {
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    #[test]
+    fn par_iter_rows_roi() -> Result<(), ImageError> {
+        let src = Image::<u8, 1>::new(
+            ImageSize {
+                width: 4,
+                height: 3,
+            },
+            (0..12).collect(),
+        )?;
+        let mut dst = Image::<u8, 1>::from_size_val(src.size(), 0)?;
+
+        let size = ImageSize {
+            width: 2,
+            height: 2,
+        };
+        super::par_iter_rows(
+            &src.roi(1, 1, size)?,
+            &mut dst.roi_mut(2, 0, size)?,
+            |src_pixel, dst_pixel| dst_pixel[0] = src_pixel[0] * 2,
+        );
+
+        assert_eq!(dst.as_slice(), &[0, 0, 10, 12, 0, 0, 18, 20, 0, 0, 0, 0]);
+
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
-use crate::filter::separable_filter;
+use crate::border::BorderMode;
+use crate::filter::{gaussian_blur, separable_filter};
 use crate::interpolation::InterpolationMode;
+use crate::parallel;
 use crate::resize::resize_native;
-use kornia_image::{Image, ImageError};
+use kornia_image::{Image, ImageError, ImageSize};
 
 fn get_pyramid_gaussian_kernel() -> (Vec<f32>, Vec<f32>) {
     // The 2D kernel is:
@@ -33,7 +35,9 @@ fn get_pyramid_gaussian_kernel() -> (Vec<f32>, Vec<f32>) {
 /// # Arguments
 ///
 /// * `src` - The source image to be upsampled.
-/// * `dst` - The destination image to store the result.
+/// * `dst` - The destination image to store the result, twice the size of the source or one
+///   pixel less to upsample back the images downsampled from an odd size by [`pyrdown`].
+/// * `border` - The extrapolation of the pixels outside the image.
 ///
 /// # Returns
 ///
@@ -43,6 +47,7 @@ fn get_pyramid_gaussian_kernel() -> (Vec<f32>, Vec<f32>) {
 ///
 /// ```
 /// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::border::BorderMode;
 /// use kornia_imgproc::pyramid::pyrup;
 ///
 /// let image = Image::<f32, 3>::new(
@@ -61,16 +66,19 @@ fn get_pyramid_gaussian_kernel() -> (Vec<f32>, Vec<f32>) {
 ///     0.0,
 /// ).unwrap();
 ///
-/// pyrup(&image, &mut upsampled).unwrap();
+/// pyrup(&image, &mut upsampled, BorderMode::Reflect101).unwrap();
 /// ```
 pub fn pyrup<const C: usize>(
     src: &Image<f32, C>,
     dst: &mut Image<f32, C>,
+    border: BorderMode,
 ) -> Result<(), ImageError> {
     let expected_width = src.width() * 2;
     let expected_height = src.height() * 2;
 
-    if dst.width() != expected_width || dst.height() != expected_height {
+    if !(expected_width.saturating_sub(1)..=expected_width).contains(&dst.width())
+        || !(expected_height.saturating_sub(1)..=expected_height).contains(&dst.height())
+    {
         return Err(ImageError::InvalidImageSize(
             expected_width,
             expected_height,
@@ -84,11 +92,409 @@ pub fn pyrup<const C: usize>(
     resize_native(src, &mut upsampled, InterpolationMode::Bilinear)?;
 
     let (kernel_x, kernel_y) = get_pyramid_gaussian_kernel();
-    separable_filter(&upsampled, dst, &kernel_x, &kernel_y)?;
+    separable_filter(&upsampled, dst, &kernel_x, &kernel_y, border)?;
+
+    Ok(())
+}
+
+/// Blur an image and then downsample it.
+///
+/// This function applies a Gaussian blur to the input image and then keeps every second
+/// pixel, halving its size.
+///
+/// # Arguments
+///
+/// * `src` - The source image to be downsampled.
+/// * `dst` - The destination image to store the result, of size `((W + 1) / 2, (H + 1) / 2)`.
+/// * `border` - The extrapolation of the pixels outside the image.
+///
+/// # Errors
+///
+/// If the destination image does not have the downsampled size, an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::border::BorderMode;
+/// use kornia_imgproc::pyramid::pyrdown;
+///
+/// let image = Image::<f32, 1>::from_size_val(
+///     ImageSize {
+///         width: 5,
+///         height: 4,
+///     },
+///     1.0,
+/// ).unwrap();
+///
+/// let mut downsampled = Image::<f32, 1>::from_size_val(
+///     ImageSize {
+///         width: 3,
+///         height: 2,
+///     },
+///     0.0,
+/// ).unwrap();
+///
+/// pyrdown(&image, &mut downsampled, BorderMode::Reflect101).unwrap();
+///
+/// assert_eq!(downsampled.as_slice(), &[1.0; 6]);
+/// ```
+pub fn pyrdown<const C: usize>(
+    src: &Image<f32, C>,
+    dst: &mut Image<f32, C>,
+    border: BorderMode,
+) -> Result<(), ImageError> {
+    let expected_width = src.width().div_ceil(2);
+    let expected_height = src.height().div_ceil(2);
+
+    if dst.width() != expected_width || dst.height() != expected_height {
+        return Err(ImageError::InvalidImageSize(
+            expected_width,
+            expected_height,
+            dst.width(),
+            dst.height(),
+        ));
+    }
+
+    let mut blurred = Image::<f32, C>::from_size_val(src.size(), 0.0)?;
+
+    let (kernel_x, kernel_y) = get_pyramid_gaussian_kernel();
+    separable_filter(src, &mut blurred, &kernel_x, &kernel_y, border)?;
+
+    subsample(&blurred, dst);
 
     Ok(())
 }
 
+/// Keep the pixels of the even rows and columns of an image.
+fn subsample<const C: usize>(src: &Image<f32, C>, dst: &mut Image<f32, C>) {
+    let src_data = src.as_slice();
+    let src_cols = src.cols();
+    parallel::par_iter_rows_indexed_mut(dst, |r, dst_row| {
+        let src_row = &src_data[2 * r * src_cols * C..(2 * r + 1) * src_cols * C];
+        for (c, dst_pixel) in dst_row.chunks_exact_mut(C).enumerate() {
+            dst_pixel.copy_from_slice(&src_row[2 * c * C..(2 * c + 1) * C]);
+        }
+    });
+}
+
+/// The size of an image after `level` downsamplings by [`pyrdown`].
+fn pyramid_level_size(size: ImageSize, level: usize) -> ImageSize {
+    (0..level).fold(size, |size, _| ImageSize {
+        width: size.width.div_ceil(2),
+        height: size.height.div_ceil(2),
+    })
+}
+
+/// Build a Gaussian pyramid by downsampling an image repeatedly.
+///
+/// # Arguments
+///
+/// * `src` - The source image, the first level of the pyramid.
+/// * `num_levels` - The number of levels of the pyramid, including the source image.
+/// * `border` - The extrapolation of the pixels outside the images.
+///
+/// # Returns
+///
+/// The levels of the pyramid from the largest to the smallest, each one downsampled from the
+/// previous one by [`pyrdown`].
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::border::BorderMode;
+/// use kornia_imgproc::pyramid::build_pyramid;
+///
+/// let image = Image::<f32, 1>::from_size_val(
+///     ImageSize {
+///         width: 9,
+///         height: 8,
+///     },
+///     0.0,
+/// ).unwrap();
+///
+/// let pyramid = build_pyramid(&image, 3, BorderMode::Reflect101).unwrap();
+///
+/// assert_eq!(pyramid.len(), 3);
+/// assert_eq!(pyramid[2].size(), ImageSize { width: 3, height: 2 });
+/// ```
+pub fn build_pyramid<const C: usize>(
+    src: &Image<f32, C>,
+    num_levels: usize,
+    border: BorderMode,
+) -> Result<Vec<Image<f32, C>>, ImageError> {
+    let mut pyramid: Vec<Image<f32, C>> = Vec::with_capacity(num_levels);
+
+    for level in 0..num_levels {
+        let next = match pyramid.last() {
+            None => src.clone(),
+            Some(prev) => {
+                let size = pyramid_level_size(src.size(), level);
+                let mut next = Image::<f32, C>::from_size_val(size, 0.0)?;
+                pyrdown(prev, &mut next, border)?;
+                next
+            }
+        };
+        pyramid.push(next);
+    }
+
+    Ok(pyramid)
+}
+
+/// Build a Laplacian pyramid, the band-pass details of the levels of a Gaussian pyramid.
+///
+/// Each level is the difference between a level of the Gaussian pyramid and the next level
+/// upsampled by [`pyrup`]. The last level is the smallest level of the Gaussian pyramid.
+///
+/// # Arguments
+///
+/// * `src` - The source image.
+/// * `num_levels` - The number of levels of the pyramid.
+/// * `border` - The extrapolation of the pixels outside the images.
+///
+/// # Returns
+///
+/// The levels of the pyramid from the largest to the smallest.
+pub fn build_laplacian_pyramid<const C: usize>(
+    src: &Image<f32, C>,
+    num_levels: usize,
+    border: BorderMode,
+) -> Result<Vec<Image<f32, C>>, ImageError> {
+    let mut pyramid = build_pyramid(src, num_levels, border)?;
+
+    for level in 0..pyramid.len().saturating_sub(1) {
+        let mut upsampled = Image::<f32, C>::from_size_val(pyramid[level].size(), 0.0)?;
+        pyrup(&pyramid[level + 1], &mut upsampled, border)?;
+
+        let mut detail = Image::<f32, C>::from_size_val(upsampled.size(), 0.0)?;
+        parallel::par_iter_rows_val_two(&pyramid[level], &upsampled, &mut detail, |&g, &u, d| {
+            *d = g - u;
+        });
+        pyramid[level] = detail;
+    }
+
+    Ok(pyramid)
+}
+
+/// Reconstruct an image from its Laplacian pyramid.
+///
+/// # Arguments
+///
+/// * `pyramid` - The levels of the Laplacian pyramid from the largest to the smallest, as
+///   built by [`build_laplacian_pyramid`].
+/// * `border` - The extrapolation of the pixels outside the images, the same as the one used
+///   to build the pyramid for an exact reconstruction.
+///
+/// # Returns
+///
+/// The image of the size of the first level.
+///
+/// # Errors
+///
+/// If the pyramid is empty or its levels do not have consecutive sizes, an error is returned.
+pub fn collapse_laplacian_pyramid<const C: usize>(
+    pyramid: &[Image<f32, C>],
+    border: BorderMode,
+) -> Result<Image<f32, C>, ImageError> {
+    let Some((smallest, details)) = pyramid.split_last() else {
+        return Err(ImageError::ImageDataNotInitialized);
+    };
+
+    details
+        .iter()
+        .rev()
+        .try_fold(smallest.clone(), |image, detail| {
+            let mut upsampled = Image::<f32, C>::from_size_val(detail.size(), 0.0)?;
+            pyrup(&image, &mut upsampled, border)?;
+
+            let mut collapsed = Image::<f32, C>::from_size_val(detail.size(), 0.0)?;
+            parallel::par_iter_rows_val_two(detail, &upsampled, &mut collapsed, |&d, &u, c| {
+                *c = d + u;
+            });
+            Ok(collapsed)
+        })
+}
+
+/// An octave of a Gaussian scale space, the images of a same resolution.
+///
+/// # Fields
+///
+/// * `gaussians` - The images blurred with an increasing sigma.
+/// * `dogs` - The differences of the consecutive gaussian images.
+/// * `sigmas` - The sigma of each gaussian image in pixels of the source image.
+/// * `scale` - The downsampling factor of the images with respect to the source image.
+pub struct ScaleSpaceOctave<const C: usize> {
+    /// The images blurred with an increasing sigma.
+    pub gaussians: Vec<Image<f32, C>>,
+    /// The differences of the consecutive gaussian images.
+    pub dogs: Vec<Image<f32, C>>,
+    /// The sigma of each gaussian image in pixels of the source image.
+    pub sigmas: Vec<f32>,
+    /// The downsampling factor of the images with respect to the source image.
+    pub scale: f32,
+}
+
+/// A builder object of the SIFT-style Gaussian scale space of an image.
+///
+/// Each octave has `num_scales + 3` gaussian images whose sigma grows by a factor
+/// `2^(1 / num_scales)`, so that the `num_scales + 2` differences of gaussians cover the
+/// `num_scales` scales of the octave with a neighbour scale on each side. The next octave
+/// starts from the image with twice the initial sigma, downsampled by two. Implements the
+/// scale space of "Distinctive Image Features from Scale-Invariant Keypoints", Lowe, IJCV 2004.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::border::BorderMode;
+/// use kornia_imgproc::pyramid::ScaleSpace;
+///
+/// let image = Image::<f32, 1>::from_size_val(
+///     ImageSize {
+///         width: 32,
+///         height: 32,
+///     },
+///     0.0,
+/// ).unwrap();
+///
+/// let octaves = ScaleSpace::new(3, 2).build(&image, BorderMode::Reflect101).unwrap();
+///
+/// assert_eq!(octaves.len(), 3);
+/// assert_eq!(octaves[1].gaussians.len(), 5);
+/// assert_eq!(octaves[1].dogs.len(), 4);
+/// assert_eq!(octaves[2].gaussians[0].size(), ImageSize { width: 8, height: 8 });
+/// ```
+pub struct ScaleSpace {
+    num_octaves: usize,
+    num_scales: usize,
+    sigma: f32,
+    assumed_blur: f32,
+}
+
+impl ScaleSpace {
+    /// Creates a scale space builder with a sigma of 1.6 and an assumed blur of 0.5.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_octaves` - The number of octaves.
+    /// * `num_scales` - The number of scales of each octave.
+    pub fn new(num_octaves: usize, num_scales: usize) -> Self {
+        Self {
+            num_octaves,
+            num_scales,
+            sigma: 1.6,
+            assumed_blur: 0.5,
+        }
+    }
+
+    /// Sets the sigma of the first image of each octave.
+    pub fn with_sigma(self, sigma: f32) -> Self {
+        Self { sigma, ..self }
+    }
+
+    /// Sets the blur of the source image, e.g. from the camera.
+    pub fn with_assumed_blur(self, assumed_blur: f32) -> Self {
+        Self {
+            assumed_blur,
+            ..self
+        }
+    }
+
+    /// Builds the octaves of the scale space of an image.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - The source image.
+    /// * `border` - The extrapolation of the pixels outside the images.
+    ///
+    /// # Returns
+    ///
+    /// The octaves from the finest to the coarsest.
+    ///
+    /// # Errors
+    ///
+    /// If the number of scales is zero, an error is returned.
+    pub fn build<const C: usize>(
+        &self,
+        src: &Image<f32, C>,
+        border: BorderMode,
+    ) -> Result<Vec<ScaleSpaceOctave<C>>, ImageError> {
+        if self.num_scales == 0 {
+            return Err(ImageError::InvalidKernelLength(self.num_scales, 1));
+        }
+
+        let k = 2f32.powf(1.0 / self.num_scales as f32);
+        let octave_sigmas = (0..self.num_scales + 3)
+            .map(|i| self.sigma * k.powi(i as i32))
+            .collect::<Vec<_>>();
+
+        let mut base = Image::<f32, C>::from_size_val(src.size(), 0.0)?;
+        let initial_blur = (self.sigma.powi(2) - self.assumed_blur.powi(2)).max(0.0);
+        blur(src, &mut base, initial_blur.sqrt(), border)?;
+
+        let mut octaves: Vec<ScaleSpaceOctave<C>> = Vec::with_capacity(self.num_octaves);
+        for octave in 0..self.num_octaves {
+            // the next octave starts from the image with twice the sigma of the previous one
+            if let Some(prev) = octaves.last() {
+                let mut next =
+                    Image::<f32, C>::from_size_val(pyramid_level_size(src.size(), octave), 0.0)?;
+                subsample(&prev.gaussians[self.num_scales], &mut next);
+                base = next;
+            }
+
+            let mut gaussians = vec![base.clone()];
+            for pair in octave_sigmas.windows(2) {
+                let mut blurred = Image::<f32, C>::from_size_val(base.size(), 0.0)?;
+                let increment = (pair[1] * pair[1] - pair[0] * pair[0]).sqrt();
+                blur(
+                    &gaussians[gaussians.len() - 1],
+                    &mut blurred,
+                    increment,
+                    border,
+                )?;
+                gaussians.push(blurred);
+            }
+
+            let dogs = gaussians
+                .windows(2)
+                .map(|pair| {
+                    let mut dog = Image::<f32, C>::from_size_val(base.size(), 0.0)?;
+                    parallel::par_iter_rows_val_two(&pair[1], &pair[0], &mut dog, |&a, &b, d| {
+                        *d = a - b;
+                    });
+                    Ok(dog)
+                })
+                .collect::<Result<Vec<_>, ImageError>>()?;
+
+            let scale = 2f32.powi(octave as i32);
+            octaves.push(ScaleSpaceOctave {
+                gaussians,
+                dogs,
+                sigmas: octave_sigmas.iter().map(|s| s * scale).collect(),
+                scale,
+            });
+        }
+
+        Ok(octaves)
+    }
+}
+
+/// Blur an image with a gaussian kernel truncated at three sigmas.
+fn blur<const C: usize>(
+    src: &Image<f32, C>,
+    dst: &mut Image<f32, C>,
+    sigma: f32,
+    border: BorderMode,
+) -> Result<(), ImageError> {
+    if sigma <= 0.0 {
+        dst.as_slice_mut().copy_from_slice(src.as_slice());
+        return Ok(());
+    }
+    let kernel_size = 2 * (3.0 * sigma).ceil() as usize + 1;
+    gaussian_blur(src, dst, (kernel_size, kernel_size), (sigma, sigma), border)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -112,7 +518,7 @@ mod tests {
             0.0,
         )?;
 
-        pyrup(&src, &mut dst)?;
+        pyrup(&src, &mut dst, BorderMode::Reflect101)?;
 
         assert_eq!(dst.width(), 4);
         assert_eq!(dst.height(), 4);
@@ -123,4 +529,124 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_pyrup_odd_size() -> Result<(), ImageError> {
+        let src = Image::<f32, 1>::from_size_val([3, 2].into(), 1.0)?;
+
+        let mut dst = Image::<f32, 1>::from_size_val([5, 4].into(), 0.0)?;
+        pyrup(&src, &mut dst, BorderMode::Reflect101)?;
+
+        let mut dst = Image::<f32, 1>::from_size_val([4, 4].into(), 0.0)?;
+        assert!(pyrup(&src, &mut dst, BorderMode::Reflect101).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pyrdown() -> Result<(), ImageError> {
+        // a ramp is preserved by the symmetric kernel away from the border
+        let src = Image::<f32, 1>::new(
+            ImageSize {
+                width: 9,
+                height: 3,
+            },
+            (0..27).map(|i| (i % 9) as f32).collect(),
+        )?;
+
+        let mut dst = Image::<f32, 1>::from_size_val([5, 2].into(), 0.0)?;
+        pyrdown(&src, &mut dst, BorderMode::Reflect101)?;
+        for row in dst.as_slice().chunks_exact(5) {
+            assert_eq!(&row[1..4], &[2.0, 4.0, 6.0]);
+        }
+
+        let mut dst = Image::<f32, 1>::from_size_val([4, 2].into(), 0.0)?;
+        assert!(pyrdown(&src, &mut dst, BorderMode::Reflect101).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_pyramid() -> Result<(), ImageError> {
+        let src = Image::<f32, 2>::from_size_val([13, 8].into(), 0.5)?;
+
+        let pyramid = build_pyramid(&src, 4, BorderMode::Reflect101)?;
+        let sizes = pyramid
+            .iter()
+            .map(|level| (level.width(), level.height()))
+            .collect::<Vec<_>>();
+        assert_eq!(sizes, [(13, 8), (7, 4), (4, 2), (2, 1)]);
+        assert!(pyramid[3]
+            .as_slice()
+            .iter()
+            .all(|&v| (v - 0.5).abs() < 1e-6));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_laplacian_pyramid_roundtrip() -> Result<(), ImageError> {
+        let src = Image::<f32, 1>::new(
+            ImageSize {
+                width: 13,
+                height: 10,
+            },
+            (0..130).map(|i| ((i * 37) % 17) as f32 / 17.0).collect(),
+        )?;
+
+        let laplacian = build_laplacian_pyramid(&src, 3, BorderMode::Reflect101)?;
+        let gaussian = build_pyramid(&src, 3, BorderMode::Reflect101)?;
+        assert_eq!(laplacian.len(), 3);
+        assert_eq!(laplacian[2].as_slice(), gaussian[2].as_slice());
+
+        let collapsed = collapse_laplacian_pyramid(&laplacian, BorderMode::Reflect101)?;
+        for (c, s) in collapsed.as_slice().iter().zip(src.as_slice()) {
+            assert!((c - s).abs() < 1e-5);
+        }
+
+        assert!(collapse_laplacian_pyramid::<1>(&[], BorderMode::Reflect101).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scale_space_sigmas() -> Result<(), ImageError> {
+        // the variance of the blurred impulse is the square of the sigma of the image
+        let mut src = Image::<f32, 1>::from_size_val([41, 41].into(), 0.0)?;
+        src.as_slice_mut()[20 * 41 + 20] = 1.0;
+
+        let octaves = ScaleSpace::new(2, 3)
+            .with_assumed_blur(0.0)
+            .build(&src, BorderMode::Constant(0.0))?;
+
+        let first = &octaves[0];
+        assert_eq!(first.gaussians.len(), 6);
+        assert_eq!(first.dogs.len(), 5);
+        for (i, (image, sigma)) in first.gaussians.iter().zip(&first.sigmas).enumerate() {
+            assert!((sigma - 1.6 * 2f32.powf(i as f32 / 3.0)).abs() < 1e-5);
+
+            let variance = image
+                .as_slice()
+                .iter()
+                .enumerate()
+                .map(|(j, v)| ((j % 41) as f32 - 20.0).powi(2) * v)
+                .sum::<f32>();
+            assert!(
+                (variance.sqrt() / sigma - 1.0).abs() < 0.02,
+                "{i}: {variance}"
+            );
+        }
+
+        // the second octave starts from the image of twice the initial sigma
+        let second = &octaves[1];
+        assert_eq!(second.scale, 2.0);
+        assert_eq!(second.gaussians[0].size(), [21, 21].into());
+        assert!((second.sigmas[0] - 3.2).abs() < 1e-5);
+        assert_eq!(
+            second.gaussians[0].as_slice()[10 * 21 + 10],
+            first.gaussians[3].as_slice()[20 * 41 + 20]
+        );
+
+        Ok(())
+    }
 }
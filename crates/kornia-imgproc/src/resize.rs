@@ -1,15 +1,20 @@
 use crate::{
+    border::BorderMode,
     interpolation::{grid::meshgrid_from_fn, interpolate_pixel, InterpolationMode},
     parallel,
 };
 use fast_image_resize::{self as fr};
-use kornia_image::{Image, ImageError};
+use kornia_image::{AsImageView, AsImageViewMut, Image, ImageError};
 
 /// Resize an image to a new size.
 ///
 /// The function resizes an image to a new size using the specified interpolation mode.
 /// It supports any number of channels and data types.
 ///
+/// The function does not take a border mode: the sampling grid maps the corners of the
+/// output onto the corners of the input, so only the outer taps of the bicubic and lanczos
+/// kernels fall outside the image, and those always replicate the edge pixels.
+///
 /// # Arguments
 ///
 /// * `src` - The input image container.
@@ -55,17 +60,19 @@ use kornia_image::{Image, ImageError};
 /// assert_eq!(image_resized.size().height, 3);
 /// ```
 pub fn resize_native<const C: usize>(
-    src: &Image<f32, C>,
-    dst: &mut Image<f32, C>,
+    src: &impl AsImageView<f32, C>,
+    dst: &mut impl AsImageViewMut<f32, C>,
     interpolation: InterpolationMode,
 ) -> Result<(), ImageError>
 where
 {
+    let src = src.as_view();
+    let mut dst = dst.as_view_mut();
+
     // check if the input and output images have the same size
     // and copy the input image to the output image if they have the same size
     if src.size() == dst.size() {
-        dst.as_slice_mut().copy_from_slice(src.as_slice());
-        return Ok(());
+        return dst.copy_from(&src);
     }
 
     // create a grid of x and y coordinates for the output image
@@ -78,10 +85,10 @@ where
     })?;
 
     // iterate over the output image and interpolate the pixel values
-    parallel::par_iter_rows_resample(dst, &map_x, &map_y, |&x, &y, dst_pixel| {
+    parallel::par_iter_rows_resample(&mut dst, &map_x, &map_y, |&x, &y, dst_pixel| {
         // interpolate the pixel values for each channel
         dst_pixel.iter_mut().enumerate().for_each(|(k, pixel)| {
-            *pixel = interpolate_pixel(src, x, y, k, interpolation);
+            *pixel = interpolate_pixel(&src, x, y, k, interpolation, BorderMode::Replicate);
         });
     });
 
@@ -183,6 +190,8 @@ pub fn resize_fast(
     options.algorithm = match interpolation {
         InterpolationMode::Bilinear => fr::ResizeAlg::Convolution(fr::FilterType::Bilinear),
         InterpolationMode::Nearest => fr::ResizeAlg::Nearest,
+        InterpolationMode::Bicubic => fr::ResizeAlg::Convolution(fr::FilterType::CatmullRom),
+        InterpolationMode::Lanczos3 => fr::ResizeAlg::Convolution(fr::FilterType::Lanczos3),
     };
 
     let mut resizer = fr::Resizer::new();
@@ -269,6 +278,40 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn resize_bicubic_lanczos() -> Result<(), ImageError> {
+        let image = Image::<_, 1>::new(
+            ImageSize {
+                width: 4,
+                height: 2,
+            },
+            vec![0.0f32, 10.0, 20.0, 30.0, 0.0, 10.0, 20.0, 30.0],
+        )?;
+
+        let new_size = ImageSize {
+            width: 7,
+            height: 3,
+        };
+
+        for mode in [
+            super::InterpolationMode::Bicubic,
+            super::InterpolationMode::Lanczos3,
+        ] {
+            let mut image_resized = Image::<_, 1>::from_size_val(new_size, 0.0f32)?;
+            super::resize_native(&image, &mut image_resized, mode)?;
+
+            // the output pixels at even columns fall on the input pixels
+            for row in image_resized.as_slice().chunks_exact(7) {
+                for (x, expected) in [(0, 0.0), (2, 10.0), (4, 20.0), (6, 30.0)] {
+                    assert!((row[x] - expected).abs() < 1e-4, "{mode:?}");
+                }
+                assert!(row.windows(2).all(|w| w[1] > w[0]), "{mode:?}");
+            }
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn meshgrid() -> Result<(), TensorError> {
         let (map_x, map_y) =
@@ -1,7 +1,7 @@
 use num_traits::Zero;
 use std::cmp::PartialOrd;
 
-use kornia_image::{Image, ImageError};
+use kornia_image::{AsImageView, AsImageViewMut, ImageError};
 
 use crate::parallel;
 
@@ -35,14 +35,17 @@ use crate::parallel;
 /// assert_eq!(thresholded.size().height, 3);
 /// ```
 pub fn threshold_binary<T, const C: usize>(
-    src: &Image<T, C>,
-    dst: &mut Image<T, C>,
+    src: &impl AsImageView<T, C>,
+    dst: &mut impl AsImageViewMut<T, C>,
     threshold: T,
     max_value: T,
 ) -> Result<(), ImageError>
 where
     T: Copy + Send + Sync + PartialOrd + Zero,
 {
+    let src = src.as_view();
+    let mut dst = dst.as_view_mut();
+
     if src.size() != dst.size() {
         return Err(ImageError::InvalidImageSize(
             src.cols(),
@@ -53,7 +56,7 @@ where
     }
 
     // run the thresholding operation in parallel
-    parallel::par_iter_rows_val(src, dst, |src_pixel, dst_pixel| {
+    parallel::par_iter_rows_val(&src, &mut dst, |src_pixel, dst_pixel| {
         *dst_pixel = if *src_pixel > threshold {
             max_value
         } else {
@@ -94,14 +97,17 @@ where
 /// assert_eq!(thresholded.size().height, 3);
 /// ```
 pub fn threshold_binary_inverse<T, const C: usize>(
-    src: &Image<T, C>,
-    dst: &mut Image<T, C>,
+    src: &impl AsImageView<T, C>,
+    dst: &mut impl AsImageViewMut<T, C>,
     threshold: T,
     max_value: T,
 ) -> Result<(), ImageError>
 where
     T: Copy + Send + Sync + PartialOrd + Zero,
 {
+    let src = src.as_view();
+    let mut dst = dst.as_view_mut();
+
     if src.size() != dst.size() {
         return Err(ImageError::InvalidImageSize(
             src.cols(),
@@ -112,7 +118,7 @@ where
     }
 
     // run the thresholding operation in parallel
-    parallel::par_iter_rows_val(src, dst, |src_pixel, dst_pixel| {
+    parallel::par_iter_rows_val(&src, &mut dst, |src_pixel, dst_pixel| {
         *dst_pixel = if *src_pixel > threshold {
             T::zero()
         } else {
@@ -151,13 +157,16 @@ where
 /// assert_eq!(thresholded.size().height, 3);
 /// ```
 pub fn threshold_truncate<T, const C: usize>(
-    src: &Image<T, C>,
-    dst: &mut Image<T, C>,
+    src: &impl AsImageView<T, C>,
+    dst: &mut impl AsImageViewMut<T, C>,
     threshold: T,
 ) -> Result<(), ImageError>
 where
     T: Copy + Send + Sync + PartialOrd + Zero,
 {
+    let src = src.as_view();
+    let mut dst = dst.as_view_mut();
+
     if src.size() != dst.size() {
         return Err(ImageError::InvalidImageSize(
             src.cols(),
@@ -168,7 +177,7 @@ where
     }
 
     // run the thresholding operation in parallel
-    parallel::par_iter_rows_val(src, dst, |src_pixel, dst_pixel| {
+    parallel::par_iter_rows_val(&src, &mut dst, |src_pixel, dst_pixel| {
         *dst_pixel = if *src_pixel > threshold {
             threshold
         } else {
@@ -207,13 +216,16 @@ where
 /// assert_eq!(thresholded.size().height, 1);
 /// ```
 pub fn threshold_to_zero<T, const C: usize>(
-    src: &Image<T, C>,
-    dst: &mut Image<T, C>,
+    src: &impl AsImageView<T, C>,
+    dst: &mut impl AsImageViewMut<T, C>,
     threshold: T,
 ) -> Result<(), ImageError>
 where
     T: Copy + Send + Sync + PartialOrd + Zero,
 {
+    let src = src.as_view();
+    let mut dst = dst.as_view_mut();
+
     if src.size() != dst.size() {
         return Err(ImageError::InvalidImageSize(
             src.cols(),
@@ -224,7 +236,7 @@ where
     }
 
     // run the thresholding operation in parallel
-    parallel::par_iter_rows_val(src, dst, |src_pixel, dst_pixel| {
+    parallel::par_iter_rows_val(&src, &mut dst, |src_pixel, dst_pixel| {
         *dst_pixel = if *src_pixel > threshold {
             *src_pixel
         } else {
@@ -263,13 +275,16 @@ where
 /// assert_eq!(thresholded.size().height, 1);
 /// ```
 pub fn threshold_to_zero_inverse<T, const C: usize>(
-    src: &Image<T, C>,
-    dst: &mut Image<T, C>,
+    src: &impl AsImageView<T, C>,
+    dst: &mut impl AsImageViewMut<T, C>,
     threshold: T,
 ) -> Result<(), ImageError>
 where
     T: Copy + Send + Sync + PartialOrd + Zero,
 {
+    let src = src.as_view();
+    let mut dst = dst.as_view_mut();
+
     if src.size() != dst.size() {
         return Err(ImageError::InvalidImageSize(
             src.cols(),
@@ -280,7 +295,7 @@ where
     }
 
     // run the thresholding operation in parallel
-    parallel::par_iter_rows_val(src, dst, |src_pixel, dst_pixel| {
+    parallel::par_iter_rows_val(&src, &mut dst, |src_pixel, dst_pixel| {
         *dst_pixel = if *src_pixel > threshold {
             T::zero()
         } else {
@@ -333,14 +348,17 @@ where
 /// assert_eq!(thresholded.get_pixel(1, 0, 0).unwrap(), &0);
 /// ```
 pub fn in_range<T, const C: usize>(
-    src: &Image<T, C>,
-    dst: &mut Image<u8, 1>,
+    src: &impl AsImageView<T, C>,
+    dst: &mut impl AsImageViewMut<u8, 1>,
     lower_bound: &[T; C],
     upper_bound: &[T; C],
 ) -> Result<(), ImageError>
 where
     T: Clone + Send + Sync + PartialOrd + Zero,
 {
+    let src = src.as_view();
+    let mut dst = dst.as_view_mut();
+
     if src.size() != dst.size() {
         return Err(ImageError::InvalidImageSize(
             src.cols(),
@@ -351,7 +369,7 @@ where
     }
 
     // parallelize the operation by rows
-    parallel::par_iter_rows(src, dst, |src_pixel, dst_pixel| {
+    parallel::par_iter_rows(&src, &mut dst, |src_pixel, dst_pixel| {
         let mut is_in_range = true;
         src_pixel
             .iter()
@@ -520,4 +538,26 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn threshold_binary_roi() -> Result<(), ImageError> {
+        let mut image = Image::<u8, 1>::new(
+            ImageSize {
+                width: 3,
+                height: 2,
+            },
+            vec![10, 200, 30, 40, 50, 160],
+        )?;
+
+        let size = ImageSize {
+            width: 2,
+            height: 2,
+        };
+        let src = image.roi(1, 0, size)?.to_image()?;
+        super::threshold_binary(&src, &mut image.roi_mut(1, 0, size)?, 100, 255)?;
+
+        assert_eq!(image.as_slice(), &[10, 255, 0, 40, 0, 255]);
+
+        Ok(())
+    }
 }
@@ -1,7 +1,8 @@
 use std::f32::consts::PI;
 
-use kornia_image::{Image, ImageError};
+use kornia_image::{AsImageView, AsImageViewMut, ImageError};
 
+use crate::border::BorderMode;
 use crate::interpolation::{grid::meshgrid_from_fn, interpolate_pixel, InterpolationMode};
 use crate::parallel;
 
@@ -92,6 +93,7 @@ fn transform_point(x: f32, y: f32, m: &[f32; 6]) -> (f32, f32) {
 /// * `dst` - The output image with shape (height, width, channels).
 /// * `m` - The 2x3 affine transformation matrix.
 /// * `interpolation` - The interpolation mode to use.
+/// * `border` - The extrapolation of the pixels outside the input image.
 ///
 /// # Returns
 ///
@@ -101,6 +103,7 @@ fn transform_point(x: f32, y: f32, m: &[f32; 6]) -> (f32, f32) {
 ///
 /// ```
 /// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::border::BorderMode;
 /// use kornia_imgproc::interpolation::InterpolationMode;
 /// use kornia_imgproc::warp::warp_affine;
 ///
@@ -120,17 +123,21 @@ fn transform_point(x: f32, y: f32, m: &[f32; 6]) -> (f32, f32) {
 ///
 /// let mut dst = Image::<_, 3>::from_size_val(new_size, 0.0).unwrap();
 ///
-/// warp_affine(&src, &mut dst, &m, InterpolationMode::Nearest).unwrap();
+/// warp_affine(&src, &mut dst, &m, InterpolationMode::Nearest, BorderMode::Constant(0.0)).unwrap();
 ///
 /// assert_eq!(dst.size().width, 4);
 /// assert_eq!(dst.size().height, 5);
 /// ```
 pub fn warp_affine<const C: usize>(
-    src: &Image<f32, C>,
-    dst: &mut Image<f32, C>,
+    src: &impl AsImageView<f32, C>,
+    dst: &mut impl AsImageViewMut<f32, C>,
     m: &[f32; 6],
     interpolation: InterpolationMode,
+    border: BorderMode,
 ) -> Result<(), ImageError> {
+    let src = src.as_view();
+    let mut dst = dst.as_view_mut();
+
     // invert affine transform matrix to find corresponding positions in src from dst
     let m_inv = invert_affine_transform(m);
 
@@ -142,15 +149,12 @@ pub fn warp_affine<const C: usize>(
     })?;
 
     // apply affine transformation
-    parallel::par_iter_rows_resample(dst, &map_x, &map_y, |&x, &y, dst_pixel| {
-        // check if the position is within the bounds of the src image
-        if x >= 0.0f32 && x < src.cols() as f32 && y >= 0.0f32 && y < src.rows() as f32 {
-            // interpolate the pixel value for each channel
-            dst_pixel
-                .iter_mut()
-                .enumerate()
-                .for_each(|(k, pixel)| *pixel = interpolate_pixel(src, x, y, k, interpolation));
-        }
+    parallel::par_iter_rows_resample(&mut dst, &map_x, &map_y, |&x, &y, dst_pixel| {
+        // interpolate the pixel value for each channel, the border mode handles the
+        // positions outside the src image
+        dst_pixel.iter_mut().enumerate().for_each(|(k, pixel)| {
+            *pixel = interpolate_pixel(&src, x, y, k, interpolation, border)
+        });
     });
 
     Ok(())
@@ -182,6 +186,7 @@ mod tests {
             &mut image_transformed,
             &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0],
             super::InterpolationMode::Bilinear,
+            super::BorderMode::Constant(0.0),
         )?;
 
         assert_eq!(image_transformed.num_channels(), 3);
@@ -214,6 +219,7 @@ mod tests {
             &mut image_transformed,
             &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0],
             super::InterpolationMode::Nearest,
+            super::BorderMode::Constant(0.0),
         )?;
 
         assert_eq!(image_transformed.num_channels(), 1);
@@ -246,6 +252,7 @@ mod tests {
             &mut image_transformed,
             &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0],
             super::InterpolationMode::Nearest,
+            super::BorderMode::Constant(0.0),
         )?;
 
         assert_eq!(image_transformed.as_slice(), image.as_slice());
@@ -277,6 +284,7 @@ mod tests {
             &mut image_transformed,
             &super::get_rotation_matrix2d((0.5, 0.5), 90.0, 1.0),
             super::InterpolationMode::Nearest,
+            super::BorderMode::Constant(0.0),
         )?;
 
         assert_eq!(
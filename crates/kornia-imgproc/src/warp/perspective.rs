@@ -1,9 +1,10 @@
 use crate::{
+    border::BorderMode,
     interpolation::{grid::meshgrid_from_fn, interpolate_pixel, InterpolationMode},
     parallel,
 };
 
-use kornia_image::{Image, ImageError};
+use kornia_image::{AsImageView, AsImageViewMut, ImageError};
 
 #[rustfmt::skip]
 fn determinant3x3(m: &[f32; 9]) -> f32 {
@@ -60,6 +61,7 @@ fn transform_point(x: f32, y: f32, m: &[f32; 9]) -> (f32, f32) {
 /// * `dst` - The output image with shape (height, width, channels).
 /// * `m` - The 3x3 perspective transformation matrix src -> dst.
 /// * `interpolation` - The interpolation mode to use.
+/// * `border` - The extrapolation of the pixels outside the input image.
 ///
 /// # Returns
 ///
@@ -69,6 +71,7 @@ fn transform_point(x: f32, y: f32, m: &[f32; 9]) -> (f32, f32) {
 ///
 /// ```
 /// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::border::BorderMode;
 /// use kornia_imgproc::interpolation::InterpolationMode;
 /// use kornia_imgproc::warp::warp_perspective;
 ///
@@ -90,17 +93,21 @@ fn transform_point(x: f32, y: f32, m: &[f32; 9]) -> (f32, f32) {
 ///   0.0
 /// ).unwrap();
 ///
-/// warp_perspective(&src, &mut dst, &m, InterpolationMode::Bilinear).unwrap();
+/// warp_perspective(&src, &mut dst, &m, InterpolationMode::Bilinear, BorderMode::Constant(0.0)).unwrap();
 ///
 /// assert_eq!(dst.size().width, 2);
 /// assert_eq!(dst.size().height, 3);
 /// ```
 pub fn warp_perspective<const C: usize>(
-    src: &Image<f32, C>,
-    dst: &mut Image<f32, C>,
+    src: &impl AsImageView<f32, C>,
+    dst: &mut impl AsImageViewMut<f32, C>,
     m: &[f32; 9],
     interpolation: InterpolationMode,
+    border: BorderMode,
 ) -> Result<(), ImageError> {
+    let src = src.as_view();
+    let mut dst = dst.as_view_mut();
+
     // inverse perspective matrix
     // TODO: allow later to skip the inverse calculation if user provides it
     let inv_m = inverse_perspective_matrix(m)?;
@@ -113,13 +120,10 @@ pub fn warp_perspective<const C: usize>(
     })?;
 
     // apply affine transformation
-    parallel::par_iter_rows_resample(dst, &map_x, &map_y, |&x, &y, dst_pixel| {
-        if x >= 0.0f32 && x < src.cols() as f32 && y >= 0.0f32 && y < src.rows() as f32 {
-            dst_pixel
-                .iter_mut()
-                .enumerate()
-                .for_each(|(k, pixel)| *pixel = interpolate_pixel(src, x, y, k, interpolation));
-        }
+    parallel::par_iter_rows_resample(&mut dst, &map_x, &map_y, |&x, &y, dst_pixel| {
+        dst_pixel.iter_mut().enumerate().for_each(|(k, pixel)| {
+            *pixel = interpolate_pixel(&src, x, y, k, interpolation, border)
+        });
     });
 
     Ok(())
@@ -172,6 +176,7 @@ mod tests {
             &mut image_transformed,
             &m,
             super::InterpolationMode::Bilinear,
+            super::BorderMode::Constant(0.0),
         )?;
 
         assert_eq!(image_transformed.num_channels(), 3);
@@ -208,6 +213,7 @@ mod tests {
             &mut image_transformed,
             &m,
             super::InterpolationMode::Bilinear,
+            super::BorderMode::Constant(0.0),
         )?;
 
         assert_eq!(image_transformed.num_channels(), 1);
@@ -249,6 +255,7 @@ mod tests {
             &mut image_transformed,
             &m,
             super::InterpolationMode::Bilinear,
+            super::BorderMode::Replicate,
         )?;
 
         let mut image_resized = Image::<_, 1>::from_size_val(new_size, 0.0)?;
@@ -302,6 +309,7 @@ mod tests {
             &mut image_transformed,
             &m,
             super::InterpolationMode::Bilinear,
+            super::BorderMode::Constant(0.0),
         )?;
 
         assert_eq!(image_transformed.num_channels(), 1);
@@ -312,4 +320,35 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn warp_perspective_degenerate() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 4,
+            height: 4,
+        };
+        let image = Image::<_, 1>::from_size_val(size, 1.0f32)?;
+
+        // the column x = 1 is mapped to the line at infinity
+        let m = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0, -1.0];
+
+        for interpolation in [
+            super::InterpolationMode::Bilinear,
+            super::InterpolationMode::Nearest,
+            super::InterpolationMode::Bicubic,
+            super::InterpolationMode::Lanczos3,
+        ] {
+            let mut image_transformed = Image::<_, 1>::from_size_val(size, 0.0)?;
+            super::warp_perspective(
+                &image,
+                &mut image_transformed,
+                &m,
+                interpolation,
+                super::BorderMode::Constant(0.0),
+            )?;
+            assert!(image_transformed.as_slice().iter().all(|v| v.is_finite()));
+        }
+
+        Ok(())
+    }
 }
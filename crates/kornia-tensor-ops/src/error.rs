@@ -20,4 +20,12 @@ pub enum TensorOpsError {
     /// Shape mismatch
     #[error("Shape mismatch: {0:?} != {1:?}")]
     ShapeMismatch(Vec<usize>, Vec<usize>),
+
+    /// The rank of the output tensor does not match the requested reduction.
+    #[error("Rank mismatch: expected an output of rank {0}, got {1}")]
+    RankMismatch(usize, usize),
+
+    /// The dimension to reduce over has no elements.
+    #[error("Cannot reduce over the empty dimension {0}")]
+    EmptyDimension(usize),
 }
@@ -1,6 +1,6 @@
-use kernels::ops::{cosine_similarity_float_kernel, dot_product1_kernel};
+use kernels::ops::{cosine_similarity_float_kernel, dot_product1_kernel, matmul_kernel};
 use kornia_tensor::{storage::TensorStorage, CpuAllocator, Tensor, TensorAllocator, TensorError};
-use num_traits::{Float, Zero};
+use num_traits::{Float, One, Zero};
 
 use crate::error::TensorOpsError;
 
@@ -176,11 +176,544 @@ where
 
     Ok(mean)
 }
-/// Perform an element-wise addition on two tensors.
+
+/// A lane of elements along the reduced dimension of a tensor.
+type Lane<'a, T> = std::iter::Take<std::iter::StepBy<std::slice::Iter<'a, T>>>;
+
+/// Compute the shape resulting from reducing `shape` over dimension `dim`.
+///
+/// With `keepdim` the reduced dimension is kept with size 1 and the output rank `M` must be
+/// equal to `N`, otherwise the dimension is removed and `M` must be equal to `N - 1`.
+fn reduced_shape<const N: usize, const M: usize>(
+    shape: [usize; N],
+    dim: usize,
+    keepdim: bool,
+) -> Result<[usize; M], TensorOpsError> {
+    if dim >= N {
+        return Err(TensorOpsError::DimOutOfBounds(dim, N.saturating_sub(1)));
+    }
+
+    let expected_rank = if keepdim { N } else { N - 1 };
+    if M != expected_rank {
+        return Err(TensorOpsError::RankMismatch(expected_rank, M));
+    }
+
+    let mut out_shape = [1; M];
+    if keepdim {
+        out_shape.copy_from_slice(&shape);
+        out_shape[dim] = 1;
+    } else {
+        shape
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != dim)
+            .zip(out_shape.iter_mut())
+            .for_each(|((_, &s), o)| *o = s);
+    }
+
+    Ok(out_shape)
+}
+
+/// Reduce a tensor over dimension `dim` applying `op` to each lane along that dimension.
+///
+/// The operation returns `None` if the lane cannot be reduced, e.g. an arg-reduction over an
+/// empty dimension, in which case an error is returned.
+fn reduce_dim<T, U, const N: usize, const M: usize, F>(
+    tensor: &Tensor<T, N, CpuAllocator>,
+    dim: usize,
+    keepdim: bool,
+    op: F,
+) -> Result<Tensor<U, M, CpuAllocator>, TensorOpsError>
+where
+    F: Fn(Lane<'_, T>) -> Option<U>,
+{
+    let out_shape = reduced_shape::<N, M>(tensor.shape, dim, keepdim)?;
+
+    let len = tensor.shape[dim];
+    let outer = tensor.shape[..dim].iter().product::<usize>();
+    let inner = tensor.shape[dim + 1..].iter().product::<usize>();
+
+    let data = tensor.as_slice();
+    let mut out = Vec::with_capacity(outer * inner);
+
+    for o in 0..outer {
+        let base = o * len * inner;
+        for i in 0..inner {
+            let lane = data
+                .get(base + i..)
+                .unwrap_or_default()
+                .iter()
+                .step_by(inner)
+                .take(len);
+            out.push(op(lane).ok_or(TensorOpsError::EmptyDimension(dim))?);
+        }
+    }
+
+    Ok(Tensor::from_shape_vec(out_shape, out, CpuAllocator)?)
+}
+
+/// Find the index and value of the element that is preferred by `better` in a lane.
+///
+/// Ties are resolved in favour of the first occurrence.
+fn arg_extremum<'a, T>(
+    lane: Lane<'a, T>,
+    better: impl Fn(&T, &T) -> bool,
+) -> Option<(usize, &'a T)> {
+    lane.enumerate().fold(None, |acc, (i, x)| match acc {
+        Some((_, best)) if !better(x, best) => acc,
+        _ => Some((i, x)),
+    })
+}
+
+/// Compute the maximum of the elements in the tensor along dimension `dim`.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor to reduce.
+/// * `dim` - The index of the dimension/axis to reduce.
+/// * `keepdim` - Whether to keep the reduced dimension with size 1.
+///
+/// # Returns
+///
+/// A new `Tensor` of rank `M` containing the maximum values.
+///
+/// # Errors
+///
+/// If the dimension is out of bounds, empty, or `M` does not match `keepdim`, an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{Tensor, CpuAllocator};
+/// use kornia_tensor_ops::TensorOps;
+///
+/// let data: [u8; 6] = [1, 5, 3, 4, 2, 6];
+/// let t = Tensor::<u8, 2, CpuAllocator>::from_shape_slice([2, 3], &data, CpuAllocator).unwrap();
+///
+/// let max = t.max_dim::<1>(1, false).unwrap();
+/// assert_eq!(max.shape, [2]);
+/// assert_eq!(max.as_slice(), [5, 6]);
+///
+/// let max = t.max_dim::<2>(0, true).unwrap();
+/// assert_eq!(max.shape, [1, 3]);
+/// assert_eq!(max.as_slice(), [4, 5, 6]);
+/// ```
+fn max_dim<T, const N: usize, const M: usize>(
+    tensor: &Tensor<T, N, CpuAllocator>,
+    dim: usize,
+    keepdim: bool,
+) -> Result<Tensor<T, M, CpuAllocator>, TensorOpsError>
+where
+    T: PartialOrd + Clone,
+{
+    reduce_dim(tensor, dim, keepdim, |lane| {
+        arg_extremum(lane, |a, b| a > b).map(|(_, v)| v.clone())
+    })
+}
+
+/// Compute the minimum of the elements in the tensor along dimension `dim`.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor to reduce.
+/// * `dim` - The index of the dimension/axis to reduce.
+/// * `keepdim` - Whether to keep the reduced dimension with size 1.
+///
+/// # Returns
+///
+/// A new `Tensor` of rank `M` containing the minimum values.
+///
+/// # Errors
+///
+/// If the dimension is out of bounds, empty, or `M` does not match `keepdim`, an error is returned.
+fn min_dim<T, const N: usize, const M: usize>(
+    tensor: &Tensor<T, N, CpuAllocator>,
+    dim: usize,
+    keepdim: bool,
+) -> Result<Tensor<T, M, CpuAllocator>, TensorOpsError>
+where
+    T: PartialOrd + Clone,
+{
+    reduce_dim(tensor, dim, keepdim, |lane| {
+        arg_extremum(lane, |a, b| a < b).map(|(_, v)| v.clone())
+    })
+}
+
+/// Compute the indices of the maximum elements in the tensor along dimension `dim`.
+///
+/// If the maximum occurs several times, the index of the first occurrence is returned.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor to reduce.
+/// * `dim` - The index of the dimension/axis to reduce.
+/// * `keepdim` - Whether to keep the reduced dimension with size 1.
+///
+/// # Returns
+///
+/// A new `Tensor` of rank `M` containing the indices of the maximum values.
+///
+/// # Errors
+///
+/// If the dimension is out of bounds, empty, or `M` does not match `keepdim`, an error is returned.
+fn argmax_dim<T, const N: usize, const M: usize>(
+    tensor: &Tensor<T, N, CpuAllocator>,
+    dim: usize,
+    keepdim: bool,
+) -> Result<Tensor<usize, M, CpuAllocator>, TensorOpsError>
+where
+    T: PartialOrd,
+{
+    reduce_dim(tensor, dim, keepdim, |lane| {
+        arg_extremum(lane, |a, b| a > b).map(|(i, _)| i)
+    })
+}
+
+/// Compute the indices of the minimum elements in the tensor along dimension `dim`.
+///
+/// If the minimum occurs several times, the index of the first occurrence is returned.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor to reduce.
+/// * `dim` - The index of the dimension/axis to reduce.
+/// * `keepdim` - Whether to keep the reduced dimension with size 1.
+///
+/// # Returns
+///
+/// A new `Tensor` of rank `M` containing the indices of the minimum values.
+///
+/// # Errors
+///
+/// If the dimension is out of bounds, empty, or `M` does not match `keepdim`, an error is returned.
+fn argmin_dim<T, const N: usize, const M: usize>(
+    tensor: &Tensor<T, N, CpuAllocator>,
+    dim: usize,
+    keepdim: bool,
+) -> Result<Tensor<usize, M, CpuAllocator>, TensorOpsError>
+where
+    T: PartialOrd,
+{
+    reduce_dim(tensor, dim, keepdim, |lane| {
+        arg_extremum(lane, |a, b| a < b).map(|(i, _)| i)
+    })
+}
+
+/// Compute the mean of the elements in the tensor along dimension `dim`.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor to reduce.
+/// * `dim` - The index of the dimension/axis to reduce.
+/// * `keepdim` - Whether to keep the reduced dimension with size 1.
+///
+/// # Returns
+///
+/// A new `Tensor` of rank `M` containing the mean values.
+///
+/// # Errors
+///
+/// If the dimension is out of bounds or `M` does not match `keepdim`, an error is returned.
+fn mean_dim<T, const N: usize, const M: usize>(
+    tensor: &Tensor<T, N, CpuAllocator>,
+    dim: usize,
+    keepdim: bool,
+) -> Result<Tensor<T, M, CpuAllocator>, TensorOpsError>
+where
+    T: Float,
+{
+    let n = lane_len::<T, N>(tensor, dim)?;
+    reduce_dim(tensor, dim, keepdim, |lane| {
+        Some(lane.fold(T::zero(), |acc, &x| acc + x) / n)
+    })
+}
+
+/// Compute the population variance of the elements in the tensor along dimension `dim`.
+///
+/// The variance is computed in two passes over each lane and normalized by the number of
+/// elements in the reduced dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor to reduce.
+/// * `dim` - The index of the dimension/axis to reduce.
+/// * `keepdim` - Whether to keep the reduced dimension with size 1.
+///
+/// # Returns
+///
+/// A new `Tensor` of rank `M` containing the variances.
+///
+/// # Errors
+///
+/// If the dimension is out of bounds or `M` does not match `keepdim`, an error is returned.
+fn var_dim<T, const N: usize, const M: usize>(
+    tensor: &Tensor<T, N, CpuAllocator>,
+    dim: usize,
+    keepdim: bool,
+) -> Result<Tensor<T, M, CpuAllocator>, TensorOpsError>
+where
+    T: Float,
+{
+    let n = lane_len::<T, N>(tensor, dim)?;
+    reduce_dim(tensor, dim, keepdim, |lane| {
+        let mean = lane.clone().fold(T::zero(), |acc, &x| acc + x) / n;
+        Some(lane.fold(T::zero(), |acc, &x| acc + (x - mean) * (x - mean)) / n)
+    })
+}
+
+/// Compute the population standard deviation of the elements in the tensor along dimension `dim`.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor to reduce.
+/// * `dim` - The index of the dimension/axis to reduce.
+/// * `keepdim` - Whether to keep the reduced dimension with size 1.
+///
+/// # Returns
+///
+/// A new `Tensor` of rank `M` containing the standard deviations.
+///
+/// # Errors
+///
+/// If the dimension is out of bounds or `M` does not match `keepdim`, an error is returned.
+fn std_dim<T, const N: usize, const M: usize>(
+    tensor: &Tensor<T, N, CpuAllocator>,
+    dim: usize,
+    keepdim: bool,
+) -> Result<Tensor<T, M, CpuAllocator>, TensorOpsError>
+where
+    T: Float,
+{
+    Ok(var_dim(tensor, dim, keepdim)?.map(|x| x.sqrt()))
+}
+
+/// Compute the product of the elements in the tensor along dimension `dim`.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor to reduce.
+/// * `dim` - The index of the dimension/axis to reduce.
+/// * `keepdim` - Whether to keep the reduced dimension with size 1.
+///
+/// # Returns
+///
+/// A new `Tensor` of rank `M` containing the products.
+///
+/// # Errors
+///
+/// If the dimension is out of bounds or `M` does not match `keepdim`, an error is returned.
+fn prod_dim<T, const N: usize, const M: usize>(
+    tensor: &Tensor<T, N, CpuAllocator>,
+    dim: usize,
+    keepdim: bool,
+) -> Result<Tensor<T, M, CpuAllocator>, TensorOpsError>
+where
+    T: One + Clone + std::ops::Mul<Output = T>,
+{
+    reduce_dim(tensor, dim, keepdim, |lane| {
+        Some(lane.fold(T::one(), |acc, x| acc * x.clone()))
+    })
+}
+
+/// Test whether any element is non-zero along dimension `dim`.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor to reduce.
+/// * `dim` - The index of the dimension/axis to reduce.
+/// * `keepdim` - Whether to keep the reduced dimension with size 1.
+///
+/// # Returns
+///
+/// A new boolean `Tensor` of rank `M`.
+///
+/// # Errors
+///
+/// If the dimension is out of bounds or `M` does not match `keepdim`, an error is returned.
+fn any_dim<T, const N: usize, const M: usize>(
+    tensor: &Tensor<T, N, CpuAllocator>,
+    dim: usize,
+    keepdim: bool,
+) -> Result<Tensor<bool, M, CpuAllocator>, TensorOpsError>
+where
+    T: Zero + PartialEq,
+{
+    reduce_dim(tensor, dim, keepdim, |mut lane| {
+        Some(lane.any(|x| !x.is_zero()))
+    })
+}
+
+/// Test whether all the elements are non-zero along dimension `dim`.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor to reduce.
+/// * `dim` - The index of the dimension/axis to reduce.
+/// * `keepdim` - Whether to keep the reduced dimension with size 1.
+///
+/// # Returns
+///
+/// A new boolean `Tensor` of rank `M`.
+///
+/// # Errors
+///
+/// If the dimension is out of bounds or `M` does not match `keepdim`, an error is returned.
+fn all_dim<T, const N: usize, const M: usize>(
+    tensor: &Tensor<T, N, CpuAllocator>,
+    dim: usize,
+    keepdim: bool,
+) -> Result<Tensor<bool, M, CpuAllocator>, TensorOpsError>
+where
+    T: Zero + PartialEq,
+{
+    reduce_dim(tensor, dim, keepdim, |mut lane| {
+        Some(lane.all(|x| !x.is_zero()))
+    })
+}
+
+/// Number of elements along dimension `dim` converted to the tensor type.
+fn lane_len<T: Float, const N: usize>(
+    tensor: &Tensor<T, N, CpuAllocator>,
+    dim: usize,
+) -> Result<T, TensorOpsError> {
+    let len = tensor
+        .shape
+        .get(dim)
+        .ok_or(TensorOpsError::DimOutOfBounds(dim, N.saturating_sub(1)))?;
+    Ok(T::from(*len).ok_or(TensorError::CastError)?)
+}
+
+/// Compute the shape resulting from broadcasting two shapes together.
+///
+/// The shapes are aligned on their trailing dimensions following the NumPy broadcasting
+/// rules: the shorter shape is padded with leading dimensions of size 1, and two dimensions
+/// are compatible when they are equal or one of them is 1. The output has the rank `K` of the
+/// longer shape.
+///
+/// # Arguments
+///
+/// * `lhs` - The shape of the first tensor.
+/// * `rhs` - The shape of the second tensor.
+///
+/// # Returns
+///
+/// The broadcasted shape.
+///
+/// # Errors
+///
+/// If the shapes are not broadcastable, or `K` is not the largest of the two ranks, an error
+/// is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor_ops::ops::broadcast_shapes;
+///
+/// assert_eq!(broadcast_shapes::<3, 2, 3>([4, 1, 3], [2, 1]).unwrap(), [4, 2, 3]);
+/// assert_eq!(broadcast_shapes::<1, 2, 2>([3], [2, 3]).unwrap(), [2, 3]);
+/// assert!(broadcast_shapes::<2, 1, 2>([4, 3], [2]).is_err());
+/// ```
+pub fn broadcast_shapes<const N: usize, const M: usize, const K: usize>(
+    lhs: [usize; N],
+    rhs: [usize; M],
+) -> Result<[usize; K], TensorOpsError> {
+    if K != N.max(M) {
+        return Err(TensorOpsError::RankMismatch(N.max(M), K));
+    }
+
+    let mut out_shape = [1; K];
+    for (i, out_dim) in out_shape.iter_mut().enumerate() {
+        let (lhs_dim, rhs_dim) = (padded_dim(&lhs, i, K), padded_dim(&rhs, i, K));
+        *out_dim = match (lhs_dim, rhs_dim) {
+            (a, b) if a == b => a,
+            (1, b) => b,
+            (a, 1) => a,
+            _ => return Err(TensorOpsError::ShapeMismatch(lhs.to_vec(), rhs.to_vec())),
+        };
+    }
+
+    Ok(out_shape)
+}
+
+/// Returns the dimension `i` of a shape left-padded with 1s to the rank `k`.
+fn padded_dim(shape: &[usize], i: usize, k: usize) -> usize {
+    match (i + shape.len()).checked_sub(k) {
+        Some(j) => shape[j],
+        None => 1,
+    }
+}
+
+/// Apply a binary operation between two tensors with broadcasting.
+///
+/// The tensors are broadcasted to a common shape with `broadcast_shapes` and the elements
+/// are visited in row-major order, using zero strides for the broadcasted dimensions.
+fn broadcast_op<T, U, const N: usize, const M: usize, const K: usize, F>(
+    lhs: &Tensor<T, N, CpuAllocator>,
+    rhs: &Tensor<T, M, CpuAllocator>,
+    op: F,
+) -> Result<Tensor<U, K, CpuAllocator>, TensorOpsError>
+where
+    F: Fn(&T, &T) -> U,
+{
+    let out_shape = broadcast_shapes::<N, M, K>(lhs.shape, rhs.shape)?;
+
+    // fast path: same shapes, no broadcasting needed
+    if lhs.shape.as_slice() == rhs.shape.as_slice() {
+        let data = lhs
+            .as_slice()
+            .iter()
+            .zip(rhs.as_slice().iter())
+            .map(|(a, b)| op(a, b))
+            .collect();
+        return Ok(Tensor::from_shape_vec(out_shape, data, CpuAllocator)?);
+    }
+
+    // strides of each input aligned to the output dimensions, zero where broadcasted
+    let aligned_strides = |shape: &[usize], strides: &[usize]| {
+        let mut aligned = [0; K];
+        for (i, s) in aligned.iter_mut().enumerate() {
+            if let Some(j) = (i + shape.len()).checked_sub(K) {
+                if shape[j] == out_shape[i] {
+                    *s = strides[j];
+                }
+            }
+        }
+        aligned
+    };
+    let lhs_strides = aligned_strides(&lhs.shape, &lhs.strides);
+    let rhs_strides = aligned_strides(&rhs.shape, &rhs.strides);
+
+    let numel = out_shape.iter().product::<usize>();
+    let (lhs_data, rhs_data) = (lhs.as_slice(), rhs.as_slice());
+    let mut data = Vec::with_capacity(numel);
+
+    let mut index = [0; K];
+    let (mut lhs_offset, mut rhs_offset) = (0, 0);
+    for _ in 0..numel {
+        data.push(op(&lhs_data[lhs_offset], &rhs_data[rhs_offset]));
+
+        // increment the output index and the input offsets
+        for d in (0..K).rev() {
+            index[d] += 1;
+            lhs_offset += lhs_strides[d];
+            rhs_offset += rhs_strides[d];
+            if index[d] < out_shape[d] {
+                break;
+            }
+            lhs_offset -= lhs_strides[d] * out_shape[d];
+            rhs_offset -= rhs_strides[d] * out_shape[d];
+            index[d] = 0;
+        }
+    }
+
+    Ok(Tensor::from_shape_vec(out_shape, data, CpuAllocator)?)
+}
+
+/// Perform an element-wise addition on two tensors with broadcasting.
 ///
 /// # Arguments
 ///
-/// * `other` - The other tensor to add.
+/// * `other` - The other tensor to add. Its shape must be broadcastable with the tensor shape.
 ///
 /// # Returns
 ///
@@ -198,26 +731,34 @@ where
 /// let data2: Vec<u8> = vec![1, 2, 3, 4];
 /// let t2 = Tensor::<u8, 1, CpuAllocator>::from_shape_vec([4], data2, CpuAllocator).unwrap();
 ///
-/// let t3 = t1.add(&t2).unwrap();
+/// let t3: Tensor<u8, 1, _> = t1.add(&t2).unwrap();
 /// assert_eq!(t3.as_slice(), vec![2, 4, 6, 8]);
+///
+/// // add a bias row to every row of a 2D tensor
+/// let t4 = Tensor::<u8, 2, CpuAllocator>::from_shape_vec([2, 2], vec![1, 2, 3, 4], CpuAllocator).unwrap();
+/// let bias = Tensor::<u8, 1, CpuAllocator>::from_shape_vec([2], vec![10, 20], CpuAllocator).unwrap();
+/// let t5: Tensor<u8, 2, _> = t4.add(&bias).unwrap();
+/// assert_eq!(t5.as_slice(), vec![11, 22, 13, 24]);
+///
+/// // the lower rank operand is padded with leading dimensions of size 1
+/// let t6: Tensor<u8, 2, _> = bias.add(&t4).unwrap();
+/// assert_eq!(t6.shape, [2, 2]);
 /// ```
-fn add<T, const N: usize>(
+fn add<T, const N: usize, const M: usize, const K: usize>(
     tensor: &Tensor<T, N, CpuAllocator>,
-    other: &Tensor<T, N, CpuAllocator>,
-) -> Result<Tensor<T, N, CpuAllocator>, TensorOpsError>
+    other: &Tensor<T, M, CpuAllocator>,
+) -> Result<Tensor<T, K, CpuAllocator>, TensorOpsError>
 where
     T: std::ops::Add<Output = T> + Clone,
 {
-    tensor
-        .element_wise_op(other, |a, b| a.clone() + b.clone())
-        .map_err(|_| TensorOpsError::ShapeMismatch(tensor.shape.to_vec(), other.shape.to_vec()))
+    broadcast_op(tensor, other, |a, b| a.clone() + b.clone())
 }
 
-/// Perform an element-wise subtraction on two tensors.
+/// Perform an element-wise subtraction on two tensors with broadcasting.
 ///
 /// # Arguments
 ///
-/// * `other` - The other tensor to subtract.
+/// * `other` - The other tensor to subtract. Its shape must be broadcastable with the tensor shape.
 ///
 /// # Returns
 ///
@@ -235,26 +776,24 @@ where
 /// let data2: Vec<u8> = vec![1, 2, 3, 4];
 /// let t2 = Tensor::<u8, 1, CpuAllocator>::from_shape_vec([4], data2, CpuAllocator).unwrap();
 ///
-/// let t3 = t1.sub(&t2).unwrap();
+/// let t3: Tensor<u8, 1, _> = t1.sub(&t2).unwrap();
 /// assert_eq!(t3.as_slice(), vec![0, 0, 0, 0]);
 /// ```
-fn sub<T, const N: usize>(
+fn sub<T, const N: usize, const M: usize, const K: usize>(
     tensor: &Tensor<T, N, CpuAllocator>,
-    other: &Tensor<T, N, CpuAllocator>,
-) -> Result<Tensor<T, N, CpuAllocator>, TensorOpsError>
+    other: &Tensor<T, M, CpuAllocator>,
+) -> Result<Tensor<T, K, CpuAllocator>, TensorOpsError>
 where
     T: std::ops::Sub<Output = T> + Clone,
 {
-    tensor
-        .element_wise_op(other, |a, b| a.clone() - b.clone())
-        .map_err(|_| TensorOpsError::ShapeMismatch(tensor.shape.to_vec(), other.shape.to_vec()))
+    broadcast_op(tensor, other, |a, b| a.clone() - b.clone())
 }
 
-/// Perform an element-wise multiplication on two tensors.
+/// Perform an element-wise multiplication on two tensors with broadcasting.
 ///
 /// # Arguments
 ///
-/// * `other` - The other tensor to multiply.
+/// * `other` - The other tensor to multiply. Its shape must be broadcastable with the tensor shape.
 ///
 /// # Returns
 ///
@@ -272,26 +811,24 @@ where
 /// let data2: Vec<u8> = vec![1, 2, 3, 4];
 /// let t2 = Tensor::<u8, 1, CpuAllocator>::from_shape_vec([4], data2, CpuAllocator).unwrap();
 ///
-/// let t3 = t1.mul(&t2).unwrap();
+/// let t3: Tensor<u8, 1, _> = t1.mul(&t2).unwrap();
 /// assert_eq!(t3.as_slice(), vec![1, 4, 9, 16]);
 /// ```
-fn mul<T, const N: usize>(
+fn mul<T, const N: usize, const M: usize, const K: usize>(
     tensor: &Tensor<T, N, CpuAllocator>,
-    other: &Tensor<T, N, CpuAllocator>,
-) -> Result<Tensor<T, N, CpuAllocator>, TensorOpsError>
+    other: &Tensor<T, M, CpuAllocator>,
+) -> Result<Tensor<T, K, CpuAllocator>, TensorOpsError>
 where
     T: std::ops::Mul<Output = T> + Clone,
 {
-    tensor
-        .element_wise_op(other, |a, b| a.clone() * b.clone())
-        .map_err(|_| TensorOpsError::ShapeMismatch(tensor.shape.to_vec(), other.shape.to_vec()))
+    broadcast_op(tensor, other, |a, b| a.clone() * b.clone())
 }
 
-/// Perform an element-wise division on two tensors.
+/// Perform an element-wise division on two tensors with broadcasting.
 ///
 /// # Arguments
 ///
-/// * `other` - The other tensor to divide.
+/// * `other` - The other tensor to divide. Its shape must be broadcastable with the tensor shape.
 ///
 /// # Returns
 ///
@@ -309,34 +846,168 @@ where
 /// let data2: Vec<u8> = vec![1, 2, 3, 4];
 /// let t2 = Tensor::<u8, 1, CpuAllocator>::from_shape_vec([4], data2, CpuAllocator).unwrap();
 ///
-/// let t3 = t1.div(&t2).unwrap();
+/// let t3: Tensor<u8, 1, _> = t1.div(&t2).unwrap();
 /// assert_eq!(t3.as_slice(), vec![1, 1, 1, 1]);
 /// ```
-fn div<T, const N: usize>(
+fn div<T, const N: usize, const M: usize, const K: usize>(
     tensor: &Tensor<T, N, CpuAllocator>,
-    other: &Tensor<T, N, CpuAllocator>,
-) -> Result<Tensor<T, N, CpuAllocator>, TensorOpsError>
+    other: &Tensor<T, M, CpuAllocator>,
+) -> Result<Tensor<T, K, CpuAllocator>, TensorOpsError>
 where
     T: std::ops::Div<Output = T> + Clone,
 {
-    tensor
-        .element_wise_op(other, |a, b| a.clone() / b.clone())
-        .map_err(|_| TensorOpsError::ShapeMismatch(tensor.shape.to_vec(), other.shape.to_vec()))
+    broadcast_op(tensor, other, |a, b| a.clone() / b.clone())
+}
+/// Compute the dot product between two 1D tensors
+///
+/// # Arguments
+///
+/// * `a` - First tensor
+/// * `b` - Second tensor
+///
+/// # Returns
+///
+/// A scalar value representing the dot product of the two tensors.
+///
+/// # Errors
+///
+/// If the shapes of the tensors don't match, an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{Tensor, CpuAllocator};
+/// use kornia_tensor_ops::TensorOps;
+///
+/// let a = Tensor::<i32, 1, CpuAllocator>::from_shape_slice([3], &[1, 2, 3], CpuAllocator).unwrap();
+/// let b = Tensor::<i32, 1, CpuAllocator>::from_shape_slice([3], &[4, 5, 6], CpuAllocator).unwrap();
+/// let result = Tensor::<i32,1,CpuAllocator>::dot_product1(&a, &b).unwrap();
+/// assert_eq!(result, 32); // 1*4 + 2*5 + 3*6 = 4 + 10 + 18 = 32
+/// ```
+fn dot_product1<T, A>(a: &Tensor<T, 1, A>, b: &Tensor<T, 1, A>) -> Result<T, TensorOpsError>
+where
+    T: Zero + Clone + std::ops::Add<Output = T> + std::ops::Mul<Output = T> + Copy,
+    A: TensorAllocator + Clone + 'static,
+{
+    if a.shape != b.shape {
+        return Err(TensorOpsError::ShapeMismatch(
+            a.shape.to_vec(),
+            b.shape.to_vec(),
+        ));
+    }
+
+    dot_product1_kernel(a.as_slice(), b.as_slice()).map_err(|e| e.into())
+}
+
+/// Compute the matrix product of two 2D tensors.
+///
+/// # Arguments
+///
+/// * `a` - The left matrix with shape `[m, k]`.
+/// * `b` - The right matrix with shape `[k, n]`.
+///
+/// # Returns
+///
+/// A new `Tensor` with shape `[m, n]` containing the matrix product.
+///
+/// # Errors
+///
+/// If the inner dimensions of the matrices don't match, an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{Tensor, CpuAllocator};
+/// use kornia_tensor_ops::TensorOps;
+///
+/// let a = Tensor::<f32, 2, CpuAllocator>::from_shape_slice([2, 3], &[1., 2., 3., 4., 5., 6.], CpuAllocator).unwrap();
+/// let b = Tensor::<f32, 2, CpuAllocator>::from_shape_slice([3, 1], &[1., 0., -1.], CpuAllocator).unwrap();
+/// let c = Tensor::<f32, 2, CpuAllocator>::matmul(&a, &b).unwrap();
+/// assert_eq!(c.shape, [2, 1]);
+/// assert_eq!(c.as_slice(), [-2., -2.]);
+/// ```
+fn matmul<T>(
+    a: &Tensor<T, 2, CpuAllocator>,
+    b: &Tensor<T, 2, CpuAllocator>,
+) -> Result<Tensor<T, 2, CpuAllocator>, TensorOpsError>
+where
+    T: Zero + Copy + std::ops::Add<Output = T> + std::ops::Mul<Output = T>,
+{
+    let ([m, k], [k2, n]) = (a.shape, b.shape);
+    if k != k2 {
+        return Err(TensorOpsError::ShapeMismatch(
+            a.shape.to_vec(),
+            b.shape.to_vec(),
+        ));
+    }
+
+    let mut out = Tensor::zeros([m, n], CpuAllocator);
+    matmul_kernel(a.as_slice(), b.as_slice(), out.as_slice_mut(), m, k, n)?;
+
+    Ok(out)
+}
+
+/// Compute the batched matrix product of two 3D tensors.
+///
+/// # Arguments
+///
+/// * `a` - The batch of left matrices with shape `[batch, m, k]`.
+/// * `b` - The batch of right matrices with shape `[batch, k, n]`.
+///
+/// # Returns
+///
+/// A new `Tensor` with shape `[batch, m, n]` containing the matrix product of each pair.
+///
+/// # Errors
+///
+/// If the batch sizes or the inner dimensions of the matrices don't match, an error is returned.
+fn bmm<T>(
+    a: &Tensor<T, 3, CpuAllocator>,
+    b: &Tensor<T, 3, CpuAllocator>,
+) -> Result<Tensor<T, 3, CpuAllocator>, TensorOpsError>
+where
+    T: Zero + Copy + std::ops::Add<Output = T> + std::ops::Mul<Output = T>,
+{
+    let ([batch, m, k], [batch2, k2, n]) = (a.shape, b.shape);
+    if batch != batch2 || k != k2 {
+        return Err(TensorOpsError::ShapeMismatch(
+            a.shape.to_vec(),
+            b.shape.to_vec(),
+        ));
+    }
+
+    let mut out = Tensor::zeros([batch, m, n], CpuAllocator);
+    if m * n == 0 {
+        return Ok(out);
+    }
+
+    let a_chunks = a.as_slice().chunks_exact((m * k).max(1));
+    let b_chunks = b.as_slice().chunks_exact((k * n).max(1));
+    for ((a_i, b_i), c_i) in a_chunks
+        .zip(b_chunks)
+        .zip(out.as_slice_mut().chunks_exact_mut(m * n))
+    {
+        matmul_kernel(a_i, b_i, c_i, m, k, n)?;
+    }
+
+    Ok(out)
 }
-/// Compute the dot product between two 1D tensors
+
+/// Swap two dimensions of a tensor.
 ///
 /// # Arguments
 ///
-/// * `a` - First tensor
-/// * `b` - Second tensor
+/// * `tensor` - The tensor to transpose.
+/// * `dim0` - The first dimension to swap.
+/// * `dim1` - The second dimension to swap.
 ///
 /// # Returns
 ///
-/// A scalar value representing the dot product of the two tensors.
+/// A new contiguous `Tensor` with the dimensions `dim0` and `dim1` swapped.
 ///
 /// # Errors
 ///
-/// If the shapes of the tensors don't match, an error is returned.
+/// If a dimension is out of bounds, an error is returned.
 ///
 /// # Example
 ///
@@ -344,24 +1015,27 @@ where
 /// use kornia_tensor::{Tensor, CpuAllocator};
 /// use kornia_tensor_ops::TensorOps;
 ///
-/// let a = Tensor::<i32, 1, CpuAllocator>::from_shape_slice([3], &[1, 2, 3], CpuAllocator).unwrap();
-/// let b = Tensor::<i32, 1, CpuAllocator>::from_shape_slice([3], &[4, 5, 6], CpuAllocator).unwrap();
-/// let result = Tensor::<i32,1,CpuAllocator>::dot_product1(&a, &b).unwrap();
-/// assert_eq!(result, 32); // 1*4 + 2*5 + 3*6 = 4 + 10 + 18 = 32
+/// let t = Tensor::<u8, 2, CpuAllocator>::from_shape_slice([2, 3], &[1, 2, 3, 4, 5, 6], CpuAllocator).unwrap();
+/// let t_t = t.transpose(0, 1).unwrap();
+/// assert_eq!(t_t.shape, [3, 2]);
+/// assert_eq!(t_t.as_slice(), [1, 4, 2, 5, 3, 6]);
 /// ```
-fn dot_product1<T, A>(a: &Tensor<T, 1, A>, b: &Tensor<T, 1, A>) -> Result<T, TensorOpsError>
+fn transpose<T, const N: usize>(
+    tensor: &Tensor<T, N, CpuAllocator>,
+    dim0: usize,
+    dim1: usize,
+) -> Result<Tensor<T, N, CpuAllocator>, TensorOpsError>
 where
-    T: Zero + Clone + std::ops::Add<Output = T> + std::ops::Mul<Output = T> + Copy,
-    A: TensorAllocator + Clone + 'static,
+    T: Clone,
 {
-    if a.shape != b.shape {
-        return Err(TensorOpsError::ShapeMismatch(
-            a.shape.to_vec(),
-            b.shape.to_vec(),
-        ));
+    if let Some(&dim) = [dim0, dim1].iter().find(|&&d| d >= N) {
+        return Err(TensorOpsError::DimOutOfBounds(dim, N.saturating_sub(1)));
     }
 
-    dot_product1_kernel(a.as_slice(), b.as_slice()).map_err(|e| e.into())
+    let mut axes: [usize; N] = std::array::from_fn(|i| i);
+    axes.swap(dim0, dim1);
+
+    Ok(tensor.permute_axes(axes).as_contiguous())
 }
 
 /// Compute the cosine similarity between two tensors with optimized computation
@@ -521,35 +1195,125 @@ pub trait TensorOps<T, const N: usize> {
     where
         T: Float + Clone;
 
-    /// Perform an element-wise addition on two tensors.
-    fn add(
+    /// Compute the maximum of the elements along dimension `dim`.
+    fn max_dim<const M: usize>(
         &self,
-        other: &Tensor<T, N, CpuAllocator>,
-    ) -> Result<Tensor<T, N, CpuAllocator>, TensorOpsError>
+        dim: usize,
+        keepdim: bool,
+    ) -> Result<Tensor<T, M, CpuAllocator>, TensorOpsError>
+    where
+        T: PartialOrd + Clone;
+
+    /// Compute the minimum of the elements along dimension `dim`.
+    fn min_dim<const M: usize>(
+        &self,
+        dim: usize,
+        keepdim: bool,
+    ) -> Result<Tensor<T, M, CpuAllocator>, TensorOpsError>
+    where
+        T: PartialOrd + Clone;
+
+    /// Compute the indices of the maximum elements along dimension `dim`.
+    fn argmax_dim<const M: usize>(
+        &self,
+        dim: usize,
+        keepdim: bool,
+    ) -> Result<Tensor<usize, M, CpuAllocator>, TensorOpsError>
+    where
+        T: PartialOrd;
+
+    /// Compute the indices of the minimum elements along dimension `dim`.
+    fn argmin_dim<const M: usize>(
+        &self,
+        dim: usize,
+        keepdim: bool,
+    ) -> Result<Tensor<usize, M, CpuAllocator>, TensorOpsError>
+    where
+        T: PartialOrd;
+
+    /// Compute the mean of the elements along dimension `dim`.
+    fn mean_dim<const M: usize>(
+        &self,
+        dim: usize,
+        keepdim: bool,
+    ) -> Result<Tensor<T, M, CpuAllocator>, TensorOpsError>
+    where
+        T: Float;
+
+    /// Compute the population variance of the elements along dimension `dim`.
+    fn var_dim<const M: usize>(
+        &self,
+        dim: usize,
+        keepdim: bool,
+    ) -> Result<Tensor<T, M, CpuAllocator>, TensorOpsError>
+    where
+        T: Float;
+
+    /// Compute the population standard deviation of the elements along dimension `dim`.
+    fn std_dim<const M: usize>(
+        &self,
+        dim: usize,
+        keepdim: bool,
+    ) -> Result<Tensor<T, M, CpuAllocator>, TensorOpsError>
+    where
+        T: Float;
+
+    /// Compute the product of the elements along dimension `dim`.
+    fn prod_dim<const M: usize>(
+        &self,
+        dim: usize,
+        keepdim: bool,
+    ) -> Result<Tensor<T, M, CpuAllocator>, TensorOpsError>
+    where
+        T: One + Clone + std::ops::Mul<Output = T>;
+
+    /// Test whether any element is non-zero along dimension `dim`.
+    fn any_dim<const M: usize>(
+        &self,
+        dim: usize,
+        keepdim: bool,
+    ) -> Result<Tensor<bool, M, CpuAllocator>, TensorOpsError>
+    where
+        T: Zero + PartialEq;
+
+    /// Test whether all the elements are non-zero along dimension `dim`.
+    fn all_dim<const M: usize>(
+        &self,
+        dim: usize,
+        keepdim: bool,
+    ) -> Result<Tensor<bool, M, CpuAllocator>, TensorOpsError>
+    where
+        T: Zero + PartialEq;
+
+    /// Perform an element-wise addition on two tensors with broadcasting.
+    fn add<const M: usize, const K: usize>(
+        &self,
+        other: &Tensor<T, M, CpuAllocator>,
+    ) -> Result<Tensor<T, K, CpuAllocator>, TensorOpsError>
     where
         T: std::ops::Add<Output = T> + Clone;
 
-    /// Perform an element-wise subtraction on two tensors.
-    fn sub(
+    /// Perform an element-wise subtraction on two tensors with broadcasting.
+    fn sub<const M: usize, const K: usize>(
         &self,
-        other: &Tensor<T, N, CpuAllocator>,
-    ) -> Result<Tensor<T, N, CpuAllocator>, TensorOpsError>
+        other: &Tensor<T, M, CpuAllocator>,
+    ) -> Result<Tensor<T, K, CpuAllocator>, TensorOpsError>
     where
         T: std::ops::Sub<Output = T> + Clone;
 
-    /// Perform an element-wise division on two tensors.
-    fn div(
+    /// Perform an element-wise division on two tensors with broadcasting.
+    fn div<const M: usize, const K: usize>(
         &self,
-        other: &Tensor<T, N, CpuAllocator>,
-    ) -> Result<Tensor<T, N, CpuAllocator>, TensorOpsError>
+        other: &Tensor<T, M, CpuAllocator>,
+    ) -> Result<Tensor<T, K, CpuAllocator>, TensorOpsError>
     where
         T: std::ops::Div<Output = T> + Clone;
 
-    /// Perform an element-wise multiplication on two tensors.
-    fn mul(
+    /// Perform an element-wise multiplication on two tensors with broadcasting.
+    fn mul<const M: usize, const K: usize>(
         &self,
-        other: &Tensor<T, N, CpuAllocator>,
-    ) -> Result<Tensor<T, N, CpuAllocator>, TensorOpsError>
+        other: &Tensor<T, M, CpuAllocator>,
+    ) -> Result<Tensor<T, K, CpuAllocator>, TensorOpsError>
     where
         T: std::ops::Mul<Output = T> + Clone;
 
@@ -561,6 +1325,31 @@ pub trait TensorOps<T, const N: usize> {
     where
         T: Zero + Clone + std::ops::Add<Output = T> + std::ops::Mul<Output = T> + Copy;
 
+    /// Compute the matrix product of two 2D tensors
+    fn matmul(
+        a: &Tensor<T, 2, CpuAllocator>,
+        b: &Tensor<T, 2, CpuAllocator>,
+    ) -> Result<Tensor<T, 2, CpuAllocator>, TensorOpsError>
+    where
+        T: Zero + Copy + std::ops::Add<Output = T> + std::ops::Mul<Output = T>;
+
+    /// Compute the batched matrix product of two 3D tensors
+    fn bmm(
+        a: &Tensor<T, 3, CpuAllocator>,
+        b: &Tensor<T, 3, CpuAllocator>,
+    ) -> Result<Tensor<T, 3, CpuAllocator>, TensorOpsError>
+    where
+        T: Zero + Copy + std::ops::Add<Output = T> + std::ops::Mul<Output = T>;
+
+    /// Swap the dimensions `dim0` and `dim1` of the tensor.
+    fn transpose(
+        &self,
+        dim0: usize,
+        dim1: usize,
+    ) -> Result<Tensor<T, N, CpuAllocator>, TensorOpsError>
+    where
+        T: Clone;
+
     /// Compute the cosine similarity between two tensors
     fn cosine_similarity(
         a: &Tensor<T, N, CpuAllocator>,
@@ -633,40 +1422,150 @@ impl<T, const N: usize> TensorOps<T, N> for Tensor<T, N, CpuAllocator> {
         mean(self)
     }
 
-    fn add(
+    fn max_dim<const M: usize>(
         &self,
-        other: &Tensor<T, N, CpuAllocator>,
-    ) -> Result<Tensor<T, N, CpuAllocator>, TensorOpsError>
+        dim: usize,
+        keepdim: bool,
+    ) -> Result<Tensor<T, M, CpuAllocator>, TensorOpsError>
+    where
+        T: PartialOrd + Clone,
+    {
+        max_dim(self, dim, keepdim)
+    }
+
+    fn min_dim<const M: usize>(
+        &self,
+        dim: usize,
+        keepdim: bool,
+    ) -> Result<Tensor<T, M, CpuAllocator>, TensorOpsError>
+    where
+        T: PartialOrd + Clone,
+    {
+        min_dim(self, dim, keepdim)
+    }
+
+    fn argmax_dim<const M: usize>(
+        &self,
+        dim: usize,
+        keepdim: bool,
+    ) -> Result<Tensor<usize, M, CpuAllocator>, TensorOpsError>
+    where
+        T: PartialOrd,
+    {
+        argmax_dim(self, dim, keepdim)
+    }
+
+    fn argmin_dim<const M: usize>(
+        &self,
+        dim: usize,
+        keepdim: bool,
+    ) -> Result<Tensor<usize, M, CpuAllocator>, TensorOpsError>
+    where
+        T: PartialOrd,
+    {
+        argmin_dim(self, dim, keepdim)
+    }
+
+    fn mean_dim<const M: usize>(
+        &self,
+        dim: usize,
+        keepdim: bool,
+    ) -> Result<Tensor<T, M, CpuAllocator>, TensorOpsError>
+    where
+        T: Float,
+    {
+        mean_dim(self, dim, keepdim)
+    }
+
+    fn var_dim<const M: usize>(
+        &self,
+        dim: usize,
+        keepdim: bool,
+    ) -> Result<Tensor<T, M, CpuAllocator>, TensorOpsError>
+    where
+        T: Float,
+    {
+        var_dim(self, dim, keepdim)
+    }
+
+    fn std_dim<const M: usize>(
+        &self,
+        dim: usize,
+        keepdim: bool,
+    ) -> Result<Tensor<T, M, CpuAllocator>, TensorOpsError>
+    where
+        T: Float,
+    {
+        std_dim(self, dim, keepdim)
+    }
+
+    fn prod_dim<const M: usize>(
+        &self,
+        dim: usize,
+        keepdim: bool,
+    ) -> Result<Tensor<T, M, CpuAllocator>, TensorOpsError>
+    where
+        T: One + Clone + std::ops::Mul<Output = T>,
+    {
+        prod_dim(self, dim, keepdim)
+    }
+
+    fn any_dim<const M: usize>(
+        &self,
+        dim: usize,
+        keepdim: bool,
+    ) -> Result<Tensor<bool, M, CpuAllocator>, TensorOpsError>
+    where
+        T: Zero + PartialEq,
+    {
+        any_dim(self, dim, keepdim)
+    }
+
+    fn all_dim<const M: usize>(
+        &self,
+        dim: usize,
+        keepdim: bool,
+    ) -> Result<Tensor<bool, M, CpuAllocator>, TensorOpsError>
+    where
+        T: Zero + PartialEq,
+    {
+        all_dim(self, dim, keepdim)
+    }
+
+    fn add<const M: usize, const K: usize>(
+        &self,
+        other: &Tensor<T, M, CpuAllocator>,
+    ) -> Result<Tensor<T, K, CpuAllocator>, TensorOpsError>
     where
         T: std::ops::Add<Output = T> + Clone,
     {
         add(self, other)
     }
 
-    fn sub(
+    fn sub<const M: usize, const K: usize>(
         &self,
-        other: &Tensor<T, N, CpuAllocator>,
-    ) -> Result<Tensor<T, N, CpuAllocator>, TensorOpsError>
+        other: &Tensor<T, M, CpuAllocator>,
+    ) -> Result<Tensor<T, K, CpuAllocator>, TensorOpsError>
     where
         T: std::ops::Sub<Output = T> + Clone,
     {
         sub(self, other)
     }
 
-    fn div(
+    fn div<const M: usize, const K: usize>(
         &self,
-        other: &Tensor<T, N, CpuAllocator>,
-    ) -> Result<Tensor<T, N, CpuAllocator>, TensorOpsError>
+        other: &Tensor<T, M, CpuAllocator>,
+    ) -> Result<Tensor<T, K, CpuAllocator>, TensorOpsError>
     where
         T: std::ops::Div<Output = T> + Clone,
     {
         div(self, other)
     }
 
-    fn mul(
+    fn mul<const M: usize, const K: usize>(
         &self,
-        other: &Tensor<T, N, CpuAllocator>,
-    ) -> Result<Tensor<T, N, CpuAllocator>, TensorOpsError>
+        other: &Tensor<T, M, CpuAllocator>,
+    ) -> Result<Tensor<T, K, CpuAllocator>, TensorOpsError>
     where
         T: std::ops::Mul<Output = T> + Clone,
     {
@@ -683,6 +1582,37 @@ impl<T, const N: usize> TensorOps<T, N> for Tensor<T, N, CpuAllocator> {
         dot_product1(a, b)
     }
 
+    fn matmul(
+        a: &Tensor<T, 2, CpuAllocator>,
+        b: &Tensor<T, 2, CpuAllocator>,
+    ) -> Result<Tensor<T, 2, CpuAllocator>, TensorOpsError>
+    where
+        T: Zero + Copy + std::ops::Add<Output = T> + std::ops::Mul<Output = T>,
+    {
+        matmul(a, b)
+    }
+
+    fn bmm(
+        a: &Tensor<T, 3, CpuAllocator>,
+        b: &Tensor<T, 3, CpuAllocator>,
+    ) -> Result<Tensor<T, 3, CpuAllocator>, TensorOpsError>
+    where
+        T: Zero + Copy + std::ops::Add<Output = T> + std::ops::Mul<Output = T>,
+    {
+        bmm(a, b)
+    }
+
+    fn transpose(
+        &self,
+        dim0: usize,
+        dim1: usize,
+    ) -> Result<Tensor<T, N, CpuAllocator>, TensorOpsError>
+    where
+        T: Clone,
+    {
+        transpose(self, dim0, dim1)
+    }
+
     fn cosine_similarity(
         a: &Tensor<T, N, CpuAllocator>,
         b: &Tensor<T, N, CpuAllocator>,
@@ -848,7 +1778,7 @@ mod tests {
         let t1 = Tensor::<u8, 1, _>::from_shape_vec([4], data1, CpuAllocator)?;
         let data2: Vec<u8> = vec![1, 2, 3, 4];
         let t2 = Tensor::<u8, 1, _>::from_shape_vec([4], data2, CpuAllocator)?;
-        let t3 = add(&t1, &t2)?;
+        let t3: Tensor<u8, 1, _> = add(&t1, &t2)?;
         assert_eq!(t3.as_slice(), vec![2, 4, 6, 8]);
         Ok(())
     }
@@ -859,7 +1789,7 @@ mod tests {
         let t1 = Tensor::<u8, 2, _>::from_shape_vec([2, 2], data1, CpuAllocator)?;
         let data2: Vec<u8> = vec![1, 2, 3, 4];
         let t2 = Tensor::<u8, 2, _>::from_shape_vec([2, 2], data2, CpuAllocator)?;
-        let t3 = add(&t1, &t2)?;
+        let t3: Tensor<u8, 2, _> = add(&t1, &t2)?;
         assert_eq!(t3.as_slice(), vec![2, 4, 6, 8]);
         Ok(())
     }
@@ -870,7 +1800,7 @@ mod tests {
         let t1 = Tensor::<u8, 3, _>::from_shape_vec([2, 1, 3], data1, CpuAllocator)?;
         let data2: Vec<u8> = vec![1, 2, 3, 4, 5, 6];
         let t2 = Tensor::<u8, 3, _>::from_shape_vec([2, 1, 3], data2, CpuAllocator)?;
-        let t3 = add(&t1, &t2)?;
+        let t3: Tensor<u8, 3, _> = add(&t1, &t2)?;
         assert_eq!(t3.as_slice(), vec![2, 4, 6, 8, 10, 12]);
         Ok(())
     }
@@ -881,7 +1811,7 @@ mod tests {
         let t1 = Tensor::<u8, 1, _>::from_shape_vec([4], data1, CpuAllocator)?;
         let data2: Vec<u8> = vec![1, 2, 3, 4];
         let t2 = Tensor::<u8, 1, _>::from_shape_vec([4], data2, CpuAllocator)?;
-        let t3 = sub(&t1, &t2)?;
+        let t3: Tensor<u8, 1, _> = sub(&t1, &t2)?;
         assert_eq!(t3.as_slice(), vec![0, 0, 0, 0]);
         Ok(())
     }
@@ -892,7 +1822,7 @@ mod tests {
         let t1 = Tensor::<u8, 2, _>::from_shape_vec([2, 2], data1, CpuAllocator)?;
         let data2: Vec<u8> = vec![1, 2, 3, 4];
         let t2 = Tensor::<u8, 2, _>::from_shape_vec([2, 2], data2, CpuAllocator)?;
-        let t3 = sub(&t1, &t2)?;
+        let t3: Tensor<u8, 2, _> = sub(&t1, &t2)?;
         assert_eq!(t3.as_slice(), vec![0, 0, 0, 0]);
         Ok(())
     }
@@ -903,7 +1833,7 @@ mod tests {
         let t1 = Tensor::<u8, 1, _>::from_shape_vec([4], data1, CpuAllocator)?;
         let data2: Vec<u8> = vec![1, 2, 3, 4];
         let t2 = Tensor::<u8, 1, _>::from_shape_vec([4], data2, CpuAllocator)?;
-        let t3 = div(&t1, &t2)?;
+        let t3: Tensor<u8, 1, _> = div(&t1, &t2)?;
         assert_eq!(t3.as_slice(), vec![1, 1, 1, 1]);
         Ok(())
     }
@@ -914,7 +1844,7 @@ mod tests {
         let t1 = Tensor::<u8, 2, _>::from_shape_vec([2, 2], data1, CpuAllocator)?;
         let data2: Vec<u8> = vec![1, 2, 3, 4];
         let t2 = Tensor::<u8, 2, _>::from_shape_vec([2, 2], data2, CpuAllocator)?;
-        let t3 = div(&t1, &t2)?;
+        let t3: Tensor<u8, 2, _> = div(&t1, &t2)?;
         assert_eq!(t3.as_slice(), vec![1, 1, 1, 1]);
         Ok(())
     }
@@ -925,7 +1855,7 @@ mod tests {
         let t1 = Tensor::<u8, 1, _>::from_shape_vec([4], data1, CpuAllocator)?;
         let data2: Vec<u8> = vec![1, 2, 3, 4];
         let t2 = Tensor::<u8, 1, _>::from_shape_vec([4], data2, CpuAllocator)?;
-        let t3 = mul(&t1, &t2)?;
+        let t3: Tensor<u8, 1, _> = mul(&t1, &t2)?;
         assert_eq!(t3.as_slice(), vec![1, 4, 9, 16]);
         Ok(())
     }
@@ -936,11 +1866,95 @@ mod tests {
         let t1 = Tensor::<u8, 2, _>::from_shape_vec([2, 2], data1, CpuAllocator)?;
         let data2: Vec<u8> = vec![1, 2, 3, 4];
         let t2 = Tensor::<u8, 2, _>::from_shape_vec([2, 2], data2, CpuAllocator)?;
-        let t3 = mul(&t1, &t2)?;
+        let t3: Tensor<u8, 2, _> = mul(&t1, &t2)?;
         assert_eq!(t3.as_slice(), vec![1, 4, 9, 16]);
         Ok(())
     }
 
+    #[test]
+    fn test_broadcast_shapes() -> Result<(), TensorOpsError> {
+        assert_eq!(broadcast_shapes([2, 3], [2, 3])?, [2, 3]);
+        assert_eq!(broadcast_shapes([2, 3], [3])?, [2, 3]);
+        assert_eq!(broadcast_shapes([3], [2, 3])?, [2, 3]);
+        assert_eq!(broadcast_shapes([2, 1], [1, 3])?, [2, 3]);
+        assert_eq!(broadcast_shapes([4, 1, 3], [2, 1])?, [4, 2, 3]);
+        assert_eq!(broadcast_shapes([3], [1, 1, 3])?, [1, 1, 3]);
+        assert!(broadcast_shapes::<2, 1, 2>([2, 3], [2])
+            .is_err_and(|e| e == TensorOpsError::ShapeMismatch(vec![2, 3], vec![2])));
+        assert!(broadcast_shapes::<1, 2, 1>([3], [2, 3])
+            .is_err_and(|e| e == TensorOpsError::RankMismatch(2, 1)));
+        Ok(())
+    }
+
+    #[test]
+    fn add_broadcast_row() -> Result<(), TensorOpsError> {
+        let t1 = Tensor::<u8, 2, _>::from_shape_vec([2, 3], vec![1, 2, 3, 4, 5, 6], CpuAllocator)?;
+        let bias = Tensor::<u8, 1, _>::from_shape_vec([3], vec![10, 20, 30], CpuAllocator)?;
+        let t2: Tensor<u8, 2, _> = add(&t1, &bias)?;
+        assert_eq!(t2.shape, [2, 3]);
+        assert_eq!(t2.as_slice(), vec![11, 22, 33, 14, 25, 36]);
+        Ok(())
+    }
+
+    #[test]
+    fn add_broadcast_lower_rank_lhs() -> Result<(), TensorOpsError> {
+        let row = Tensor::<i32, 1, _>::from_shape_vec([3], vec![10, 20, 30], CpuAllocator)?;
+        let t = Tensor::<i32, 2, _>::from_shape_vec([2, 3], vec![1, 2, 3, 4, 5, 6], CpuAllocator)?;
+
+        let sum: Tensor<i32, 2, _> = add(&row, &t)?;
+        assert_eq!(sum.shape, [2, 3]);
+        assert_eq!(sum.as_slice(), vec![11, 22, 33, 14, 25, 36]);
+
+        let diff: Tensor<i32, 2, _> = row.sub(&t)?;
+        assert_eq!(diff.shape, [2, 3]);
+        assert_eq!(diff.as_slice(), vec![9, 18, 27, 6, 15, 24]);
+        Ok(())
+    }
+
+    #[test]
+    fn add_broadcast_both() -> Result<(), TensorOpsError> {
+        let col = Tensor::<i32, 2, _>::from_shape_vec([3, 1], vec![0, 10, 20], CpuAllocator)?;
+        let row = Tensor::<i32, 2, _>::from_shape_vec([1, 2], vec![1, 2], CpuAllocator)?;
+        let t: Tensor<i32, 2, _> = add(&col, &row)?;
+        assert_eq!(t.shape, [3, 2]);
+        assert_eq!(t.as_slice(), vec![1, 2, 11, 12, 21, 22]);
+
+        // the output takes the rank of the higher rank operand
+        let row3 = Tensor::<i32, 3, _>::from_shape_vec([1, 1, 2], vec![1, 2], CpuAllocator)?;
+        let t: Tensor<i32, 3, _> = sub(&col, &row3)?;
+        assert_eq!(t.shape, [1, 3, 2]);
+        assert_eq!(t.as_slice(), vec![-1, -2, 9, 8, 19, 18]);
+
+        let t: Tensor<i32, 2, _> = add(&row, &col)?;
+        assert_eq!(t.shape, [3, 2]);
+        assert_eq!(t.as_slice(), vec![1, 2, 11, 12, 21, 22]);
+
+        let wrong = Tensor::<i32, 1, _>::from_shape_vec([3], vec![1, 2, 3], CpuAllocator)?;
+        assert!(add::<_, 2, 1, 2>(&row, &wrong).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn normalize_per_channel_broadcast() -> Result<(), TensorOpsError> {
+        let image = Tensor::<f32, 3, _>::from_shape_vec(
+            [1, 2, 3],
+            vec![1.0, 4.0, 9.0, 3.0, 8.0, 15.0],
+            CpuAllocator,
+        )?;
+        let mean =
+            Tensor::<f32, 3, _>::from_shape_vec([1, 1, 3], vec![1.0, 2.0, 3.0], CpuAllocator)?;
+        let std = Tensor::<f32, 1, _>::from_shape_vec([3], vec![1.0, 2.0, 3.0], CpuAllocator)?;
+
+        let centered: Tensor<f32, 3, _> = sub(&image, &mean)?;
+        let normalized: Tensor<f32, 3, _> = div(&centered, &std)?;
+        assert_eq!(normalized.shape, [1, 2, 3]);
+        assert_eq!(normalized.as_slice(), vec![0.0, 1.0, 2.0, 2.0, 3.0, 4.0]);
+
+        let scaled: Tensor<f32, 3, _> = mul(&image, &std)?;
+        assert_eq!(scaled.as_slice(), vec![1.0, 8.0, 27.0, 3.0, 16.0, 45.0]);
+        Ok(())
+    }
+
     #[test]
     fn test_dot_product1_f32() -> Result<(), TensorOpsError> {
         let a_f32 =
@@ -1036,4 +2050,178 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_reduced_shape() -> Result<(), TensorOpsError> {
+        assert_eq!(reduced_shape::<3, 3>([2, 3, 4], 1, true)?, [2, 1, 4]);
+        assert_eq!(reduced_shape::<3, 2>([2, 3, 4], 1, false)?, [2, 4]);
+        assert_eq!(reduced_shape::<1, 0>([5], 0, false)?, []);
+        assert_eq!(
+            reduced_shape::<3, 3>([2, 3, 4], 1, false),
+            Err(TensorOpsError::RankMismatch(2, 3))
+        );
+        assert_eq!(
+            reduced_shape::<3, 2>([2, 3, 4], 3, false),
+            Err(TensorOpsError::DimOutOfBounds(3, 2))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_min_dim() -> Result<(), TensorOpsError> {
+        let data: [i32; 6] = [3, -1, 2, 0, 7, 2];
+        let t = Tensor::<i32, 2, CpuAllocator>::from_shape_slice([2, 3], &data, CpuAllocator)?;
+
+        let max = t.max_dim::<1>(0, false)?;
+        assert_eq!(max.shape, [3]);
+        assert_eq!(max.as_slice(), [3, 7, 2]);
+
+        let min = t.min_dim::<2>(1, true)?;
+        assert_eq!(min.shape, [2, 1]);
+        assert_eq!(min.as_slice(), [-1, 0]);
+
+        let argmax = t.argmax_dim::<1>(1, false)?;
+        assert_eq!(argmax.as_slice(), [0, 1]);
+
+        // ties resolve to the first occurrence
+        let argmin = t.argmin_dim::<1>(0, false)?;
+        assert_eq!(argmin.as_slice(), [1, 0, 0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_reduce_3d() -> Result<(), TensorOpsError> {
+        let data = (0..24).map(|x| x as f32).collect::<Vec<_>>();
+        let t = Tensor::<f32, 3, CpuAllocator>::from_shape_vec([2, 3, 4], data, CpuAllocator)?;
+
+        let max = t.max_dim::<2>(1, false)?;
+        assert_eq!(max.shape, [2, 4]);
+        assert_eq!(max.as_slice(), [8., 9., 10., 11., 20., 21., 22., 23.]);
+
+        let argmax = t.argmax_dim::<3>(2, true)?;
+        assert_eq!(argmax.shape, [2, 3, 1]);
+        assert_eq!(argmax.as_slice(), [3; 6]);
+
+        let mean = t.mean_dim::<2>(0, false)?;
+        assert_eq!(mean.shape, [3, 4]);
+        assert_eq!(
+            mean.as_slice(),
+            (6..18).map(|x| x as f32).collect::<Vec<_>>()
+        );
+
+        let var = t.var_dim::<3>(0, true)?;
+        assert_eq!(var.shape, [1, 3, 4]);
+        assert_eq!(var.as_slice(), [36.0; 12]);
+
+        let std = t.std_dim::<2>(0, false)?;
+        assert_eq!(std.as_slice(), [6.0; 12]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_prod_any_all_dim() -> Result<(), TensorOpsError> {
+        let data: [u8; 6] = [1, 2, 3, 0, 5, 6];
+        let t = Tensor::<u8, 2, CpuAllocator>::from_shape_slice([2, 3], &data, CpuAllocator)?;
+
+        let prod = t.prod_dim::<1>(1, false)?;
+        assert_eq!(prod.as_slice(), [6, 0]);
+
+        let prod = t.prod_dim::<2>(0, true)?;
+        assert_eq!(prod.shape, [1, 3]);
+        assert_eq!(prod.as_slice(), [0, 10, 18]);
+
+        let any = t.any_dim::<1>(0, false)?;
+        assert_eq!(any.as_slice(), [true, true, true]);
+
+        let all = t.all_dim::<1>(1, false)?;
+        assert_eq!(all.as_slice(), [true, false]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_reduce_errors() -> Result<(), TensorOpsError> {
+        let t = Tensor::<f32, 2, CpuAllocator>::from_shape_vec([2, 0], vec![], CpuAllocator)?;
+        assert!(t
+            .max_dim::<1>(1, false)
+            .is_err_and(|e| e == TensorOpsError::EmptyDimension(1)));
+        assert!(t
+            .argmin_dim::<2>(2, true)
+            .is_err_and(|e| e == TensorOpsError::DimOutOfBounds(2, 1)));
+        assert!(t
+            .any_dim::<2>(0, false)
+            .is_err_and(|e| e == TensorOpsError::RankMismatch(1, 2)));
+
+        // no lanes are left to reduce, so the result is simply empty
+        let prod = t.prod_dim::<1>(0, false)?;
+        assert_eq!(prod.shape, [0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_matmul() -> Result<(), TensorOpsError> {
+        let a = Tensor::<i32, 2, CpuAllocator>::from_shape_slice(
+            [2, 3],
+            &[1, 2, 3, 4, 5, 6],
+            CpuAllocator,
+        )?;
+        let b = Tensor::<i32, 2, CpuAllocator>::from_shape_slice(
+            [3, 2],
+            &[7, 8, 9, 10, 11, 12],
+            CpuAllocator,
+        )?;
+
+        let c = matmul(&a, &b)?;
+        assert_eq!(c.shape, [2, 2]);
+        assert_eq!(c.as_slice(), [58, 64, 139, 154]);
+
+        // (a * b)^T == b^T * a^T
+        let c_t = matmul(&b.transpose(0, 1)?, &a.transpose(0, 1)?)?;
+        assert_eq!(c_t.as_slice(), c.transpose(0, 1)?.as_slice());
+
+        assert!(matmul(&a, &a)
+            .is_err_and(|e| e == TensorOpsError::ShapeMismatch(vec![2, 3], vec![2, 3])));
+        Ok(())
+    }
+
+    #[test]
+    fn test_bmm() -> Result<(), TensorOpsError> {
+        let a =
+            Tensor::<f32, 3, CpuAllocator>::from_shape_fn([2, 2, 2], CpuAllocator, |[b, i, j]| {
+                ((b + 1) * (i * 2 + j + 1)) as f32
+            });
+        let eye =
+            Tensor::<f32, 3, CpuAllocator>::from_shape_fn([2, 2, 2], CpuAllocator, |[_, i, j]| {
+                (i == j) as u8 as f32
+            });
+
+        let c = bmm(&a, &eye)?;
+        assert_eq!(c.shape, [2, 2, 2]);
+        assert_eq!(c.as_slice(), a.as_slice());
+
+        let c = bmm(&a, &a)?;
+        assert_eq!(c.as_slice(), [7., 10., 15., 22., 28., 40., 60., 88.]);
+
+        let b = Tensor::<f32, 3, CpuAllocator>::zeros([3, 2, 2], CpuAllocator);
+        assert!(bmm(&a, &b).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_transpose() -> Result<(), TensorOpsError> {
+        let t =
+            Tensor::<u8, 3, CpuAllocator>::from_shape_fn([2, 3, 4], CpuAllocator, |[i, j, k]| {
+                (i * 100 + j * 10 + k) as u8
+            });
+
+        let t_t = t.transpose(0, 2)?;
+        assert_eq!(t_t.shape, [4, 3, 2]);
+        assert_eq!(t_t.get([3, 1, 0]), Some(&13));
+        assert_eq!(t_t.get([2, 2, 1]), Some(&122));
+
+        assert_eq!(t.transpose(1, 1)?.as_slice(), t.as_slice());
+        assert!(t
+            .transpose(0, 3)
+            .is_err_and(|e| e == TensorOpsError::DimOutOfBounds(3, 2)));
+        Ok(())
+    }
 }
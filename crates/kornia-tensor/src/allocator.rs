@@ -1,5 +1,7 @@
 use std::alloc;
 use std::alloc::Layout;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
 
 use thiserror::Error;
 
@@ -25,12 +27,21 @@ pub enum TensorAllocatorError {
 ///
 /// * `alloc` - Allocates memory for a tensor with the given layout.
 /// * `dealloc` - Deallocates memory for a tensor with the given layout.
+/// * `alignment` - The minimum alignment of the memory returned by the allocator.
 pub trait TensorAllocator: Clone {
     /// Allocates memory for a tensor with the given layout.
     fn alloc(&self, layout: Layout) -> Result<*mut u8, TensorAllocatorError>;
 
     /// Deallocates memory for a tensor with the given layout.
     fn dealloc(&self, ptr: *mut u8, layout: Layout);
+
+    /// Returns the minimum alignment in bytes of the memory returned by the allocator.
+    ///
+    /// Allocators with the default alignment of 1 can take ownership of memory coming from the
+    /// global allocator, e.g. a `Vec`, without copying it.
+    fn alignment(&self) -> usize {
+        1
+    }
 }
 
 #[derive(Clone)]
@@ -72,15 +83,212 @@ impl TensorAllocator for CpuAllocator {
     ///
     /// # Safety
     ///
-    /// The pointer must be non-null and the layout must be correct.
+    /// The pointer must be non-null and the layout must be correct. Zero-sized layouts
+    /// (e.g. coming from an empty `Vec`) are never deallocated.
     #[allow(clippy::not_unsafe_ptr_arg_deref)]
     fn dealloc(&self, ptr: *mut u8, layout: Layout) {
-        if !ptr.is_null() {
+        if !ptr.is_null() && layout.size() != 0 {
             unsafe { alloc::dealloc(ptr, layout) }
         }
     }
 }
 
+/// A tensor allocator that aligns the memory to `ALIGN` bytes.
+///
+/// The default alignment of 64 bytes matches the cache line size and the widest SIMD registers
+/// of most CPUs. `ALIGN` must be a power of two.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{AlignedAllocator, Tensor};
+///
+/// let t = Tensor::<f32, 2, _>::from_shape_val([3, 5], 1.0, AlignedAllocator::<64>);
+/// assert_eq!(t.as_ptr() as usize % 64, 0);
+/// ```
+#[derive(Clone, Default)]
+pub struct AlignedAllocator<const ALIGN: usize = 64>;
+
+impl<const ALIGN: usize> TensorAllocator for AlignedAllocator<ALIGN> {
+    /// Allocates memory for a tensor with the given layout aligned to `ALIGN` bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `layout` - The layout of the tensor.
+    ///
+    /// # Returns
+    ///
+    /// A non-null pointer to the allocated memory if successful, otherwise an error.
+    fn alloc(&self, layout: Layout) -> Result<*mut u8, TensorAllocatorError> {
+        let layout = layout
+            .align_to(ALIGN)
+            .map_err(TensorAllocatorError::LayoutError)?;
+        if layout.size() == 0 {
+            return Ok(layout.align() as *mut u8);
+        }
+        CpuAllocator.alloc(layout)
+    }
+
+    /// Deallocates memory for a tensor with the given layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `ptr` - A non-null pointer to the allocated memory.
+    /// * `layout` - The layout of the tensor used to allocate the memory.
+    fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if let Ok(layout) = layout.align_to(ALIGN) {
+            CpuAllocator.dealloc(ptr, layout);
+        }
+    }
+
+    fn alignment(&self) -> usize {
+        ALIGN
+    }
+}
+
+/// The shared state of a [`PoolAllocator`].
+struct BufferPool<A: TensorAllocator> {
+    /// The cached buffers grouped by their layout.
+    buffers: Mutex<HashMap<Layout, Vec<usize>>>,
+    /// The maximum number of buffers cached per layout.
+    max_buffers: usize,
+    /// The allocator used to allocate/deallocate the buffers.
+    alloc: A,
+}
+
+impl<A: TensorAllocator> BufferPool<A> {
+    fn lock(&self) -> MutexGuard<'_, HashMap<Layout, Vec<usize>>> {
+        // the pool only holds plain pointers, so a poisoned lock is still consistent
+        self.buffers.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl<A: TensorAllocator> Drop for BufferPool<A> {
+    fn drop(&mut self) {
+        for (layout, ptrs) in self.lock().drain() {
+            ptrs.into_iter()
+                .for_each(|ptr| self.alloc.dealloc(ptr as *mut u8, layout));
+        }
+    }
+}
+
+/// A tensor allocator that recycles buffers with the same layout.
+///
+/// Deallocated buffers are kept in a pool shared by all the clones of the allocator and
+/// handed out again to allocations with the same layout, e.g. the images of a video stream
+/// allocated frame after frame. Up to `max_buffers` buffers are cached per layout, the rest
+/// are released to the underlying allocator `A`.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{PoolAllocator, Tensor};
+///
+/// let pool = PoolAllocator::default();
+///
+/// let t = Tensor::<u8, 2, _>::from_shape_val([480, 640], 0, pool.clone());
+/// let ptr = t.as_ptr();
+/// drop(t);
+/// assert_eq!(pool.num_cached(), 1);
+///
+/// // the buffer is reused by the next tensor of the same size
+/// let t = Tensor::<u8, 2, _>::from_shape_val([480, 640], 255, pool.clone());
+/// assert_eq!(t.as_ptr(), ptr);
+/// assert_eq!(pool.num_cached(), 0);
+/// ```
+pub struct PoolAllocator<A: TensorAllocator = CpuAllocator> {
+    pool: Arc<BufferPool<A>>,
+}
+
+impl<A: TensorAllocator> Clone for PoolAllocator<A> {
+    fn clone(&self) -> Self {
+        Self {
+            pool: self.pool.clone(),
+        }
+    }
+}
+
+impl Default for PoolAllocator<CpuAllocator> {
+    fn default() -> Self {
+        Self::new(CpuAllocator, 8)
+    }
+}
+
+impl<A: TensorAllocator> PoolAllocator<A> {
+    /// Creates a new pool allocator.
+    ///
+    /// # Arguments
+    ///
+    /// * `alloc` - The allocator used to allocate the buffers of the pool.
+    /// * `max_buffers` - The maximum number of buffers cached per layout.
+    pub fn new(alloc: A, max_buffers: usize) -> Self {
+        Self {
+            pool: Arc::new(BufferPool {
+                buffers: Mutex::new(HashMap::new()),
+                max_buffers,
+                alloc,
+            }),
+        }
+    }
+
+    /// Returns the number of buffers currently cached in the pool.
+    pub fn num_cached(&self) -> usize {
+        self.pool.lock().values().map(Vec::len).sum()
+    }
+
+    /// Releases all the cached buffers to the underlying allocator.
+    pub fn clear(&self) {
+        for (layout, ptrs) in self.pool.lock().drain() {
+            ptrs.into_iter()
+                .for_each(|ptr| self.pool.alloc.dealloc(ptr as *mut u8, layout));
+        }
+    }
+}
+
+impl<A: TensorAllocator> TensorAllocator for PoolAllocator<A> {
+    /// Allocates memory for a tensor with the given layout, reusing a cached buffer if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `layout` - The layout of the tensor.
+    ///
+    /// # Returns
+    ///
+    /// A non-null pointer to the allocated memory if successful, otherwise an error.
+    fn alloc(&self, layout: Layout) -> Result<*mut u8, TensorAllocatorError> {
+        let cached = self.pool.lock().get_mut(&layout).and_then(Vec::pop);
+        match cached {
+            Some(ptr) => Ok(ptr as *mut u8),
+            None => self.pool.alloc.alloc(layout),
+        }
+    }
+
+    /// Returns the memory to the pool or deallocates it if the pool is full.
+    ///
+    /// # Arguments
+    ///
+    /// * `ptr` - A non-null pointer to the allocated memory.
+    /// * `layout` - The layout of the tensor.
+    fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if ptr.is_null() || layout.size() == 0 {
+            return self.pool.alloc.dealloc(ptr, layout);
+        }
+
+        let mut buffers = self.pool.lock();
+        let cached = buffers.entry(layout).or_default();
+        if cached.len() < self.pool.max_buffers {
+            cached.push(ptr as usize);
+        } else {
+            drop(buffers);
+            self.pool.alloc.dealloc(ptr, layout);
+        }
+    }
+
+    fn alignment(&self) -> usize {
+        self.pool.alloc.alignment()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -93,4 +301,51 @@ mod tests {
         allocator.dealloc(ptr, layout);
         Ok(())
     }
+
+    #[test]
+    fn test_aligned_allocator() -> Result<(), TensorAllocatorError> {
+        let allocator = AlignedAllocator::<128>;
+        assert_eq!(allocator.alignment(), 128);
+        for size in [1, 3, 1024] {
+            let layout = Layout::array::<u8>(size).map_err(TensorAllocatorError::LayoutError)?;
+            let ptr = allocator.alloc(layout)?;
+            assert_eq!(ptr as usize % 128, 0);
+            allocator.dealloc(ptr, layout);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_pool_allocator() -> Result<(), TensorAllocatorError> {
+        let allocator = PoolAllocator::new(AlignedAllocator::<64>, 2);
+        assert_eq!(allocator.alignment(), 64);
+
+        let layout = Layout::from_size_align(1024, 4).map_err(TensorAllocatorError::LayoutError)?;
+        let ptrs = (0..3)
+            .map(|_| allocator.alloc(layout))
+            .collect::<Result<Vec<_>, _>>()?;
+        ptrs.iter().for_each(|&ptr| allocator.dealloc(ptr, layout));
+
+        // only two buffers are kept for the layout
+        assert_eq!(allocator.num_cached(), 2);
+
+        // the clones share the same pool
+        let other = allocator.clone();
+        let ptr = other.alloc(layout)?;
+        assert!(ptrs.contains(&ptr));
+        assert_eq!(allocator.num_cached(), 1);
+
+        // a different layout is not served from the pool
+        let layout2 = Layout::from_size_align(512, 4).map_err(TensorAllocatorError::LayoutError)?;
+        let ptr2 = other.alloc(layout2)?;
+        assert_eq!(allocator.num_cached(), 1);
+
+        other.dealloc(ptr, layout);
+        other.dealloc(ptr2, layout2);
+        assert_eq!(allocator.num_cached(), 3);
+
+        allocator.clear();
+        assert_eq!(allocator.num_cached(), 0);
+        Ok(())
+    }
 }
@@ -0,0 +1,431 @@
+//! Zero-copy conversion between tensors and the [DLPack](https://dmlc.github.io/dlpack/latest/)
+//! exchange format.
+//!
+//! The types in this module mirror the C definitions of `dlpack.h` so that a `DLManagedTensor`
+//! can be passed as a raw pointer to any library implementing the protocol, e.g. PyTorch,
+//! ONNX Runtime or candle.
+
+use std::ffi::c_void;
+
+use crate::{allocator::TensorAllocator, storage::TensorStorage, Tensor, TensorError};
+
+/// The device type code of the CPU in DLPack.
+pub const DL_DEVICE_CPU: i32 = 1;
+
+/// The type code of signed integers in DLPack.
+pub const DL_INT: u8 = 0;
+
+/// The type code of unsigned integers in DLPack.
+pub const DL_UINT: u8 = 1;
+
+/// The type code of IEEE floating point numbers in DLPack.
+pub const DL_FLOAT: u8 = 2;
+
+/// The type code of booleans in DLPack.
+pub const DL_BOOL: u8 = 6;
+
+/// The device where the tensor data is allocated.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DLDevice {
+    /// The device type, e.g. [`DL_DEVICE_CPU`].
+    pub device_type: i32,
+    /// The index of the device.
+    pub device_id: i32,
+}
+
+/// The data type of the tensor elements.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DLDataType {
+    /// The type code, e.g. [`DL_FLOAT`].
+    pub code: u8,
+    /// The number of bits of the type.
+    pub bits: u8,
+    /// The number of lanes of the type, 1 for scalars.
+    pub lanes: u16,
+}
+
+/// A tensor described by its data pointer, shape and strides.
+#[repr(C)]
+#[derive(Debug)]
+pub struct DLTensor {
+    /// The pointer to the data of the tensor.
+    pub data: *mut c_void,
+    /// The device of the tensor data.
+    pub device: DLDevice,
+    /// The number of dimensions of the tensor.
+    pub ndim: i32,
+    /// The data type of the tensor elements.
+    pub dtype: DLDataType,
+    /// The shape of the tensor with `ndim` elements.
+    pub shape: *mut i64,
+    /// The strides of the tensor in number of elements, or null for a compact row-major tensor.
+    pub strides: *mut i64,
+    /// The offset in bytes from `data` to the first element.
+    pub byte_offset: u64,
+}
+
+/// A tensor together with the context and deleter managing its memory.
+#[repr(C)]
+#[derive(Debug)]
+pub struct DLManagedTensor {
+    /// The described tensor.
+    pub dl_tensor: DLTensor,
+    /// The context of the producer of the tensor.
+    pub manager_ctx: *mut c_void,
+    /// The function called by the consumer to release the tensor.
+    pub deleter: Option<unsafe extern "C" fn(*mut DLManagedTensor)>,
+}
+
+/// A trait for the tensor element types that have a DLPack data type.
+pub trait DLPackType {
+    /// The DLPack data type of the element type.
+    const DTYPE: DLDataType;
+}
+
+macro_rules! impl_dlpack_type {
+    ($($t:ty => $code:expr),* $(,)?) => {
+        $(
+            impl DLPackType for $t {
+                const DTYPE: DLDataType = DLDataType {
+                    code: $code,
+                    bits: (std::mem::size_of::<$t>() * 8) as u8,
+                    lanes: 1,
+                };
+            }
+        )*
+    };
+}
+
+impl_dlpack_type!(
+    u8 => DL_UINT,
+    u16 => DL_UINT,
+    u32 => DL_UINT,
+    u64 => DL_UINT,
+    i8 => DL_INT,
+    i16 => DL_INT,
+    i32 => DL_INT,
+    i64 => DL_INT,
+    f32 => DL_FLOAT,
+    f64 => DL_FLOAT,
+    bool => DL_BOOL,
+);
+
+/// The context of an exported tensor, which owns the tensor and its shape and strides.
+struct ExportContext<T, const N: usize, A: TensorAllocator> {
+    managed: DLManagedTensor,
+    _tensor: Tensor<T, N, A>,
+    shape: [i64; N],
+    strides: [i64; N],
+}
+
+unsafe extern "C" fn export_deleter<T, const N: usize, A: TensorAllocator>(
+    managed: *mut DLManagedTensor,
+) {
+    if !managed.is_null() {
+        // Safety: the context was created by `Tensor::into_dlpack` and is released only once.
+        drop(Box::from_raw(
+            (*managed).manager_ctx as *mut ExportContext<T, N, A>,
+        ));
+    }
+}
+
+/// The managed tensor of an imported tensor, released with its deleter when dropped.
+struct ImportGuard(*mut DLManagedTensor);
+
+// Safety: the DLPack protocol allows the deleter to be called from any thread.
+unsafe impl Send for ImportGuard {}
+
+impl Drop for ImportGuard {
+    fn drop(&mut self) {
+        // Safety: the managed tensor is valid until its deleter is called.
+        unsafe {
+            if let Some(deleter) = (*self.0).deleter {
+                deleter(self.0);
+            }
+        }
+    }
+}
+
+impl<T, const N: usize, A> Tensor<T, N, A>
+where
+    T: DLPackType,
+    A: TensorAllocator + 'static,
+{
+    /// Converts the tensor into a DLPack managed tensor without copying the data.
+    ///
+    /// The tensor is kept alive until the consumer calls the deleter of the managed tensor.
+    ///
+    /// # Returns
+    ///
+    /// A pointer to the managed tensor owned by the consumer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let t = Tensor::<f32, 2, _>::from_shape_vec([2, 2], vec![1.0, 2.0, 3.0, 4.0], CpuAllocator).unwrap();
+    /// let managed = t.into_dlpack();
+    ///
+    /// let t = unsafe { Tensor::<f32, 2, _>::from_dlpack(managed, CpuAllocator).unwrap() };
+    /// assert_eq!(t.as_slice(), [1.0, 2.0, 3.0, 4.0]);
+    /// ```
+    pub fn into_dlpack(self) -> *mut DLManagedTensor {
+        let shape = self.shape.map(|s| s as i64);
+        let strides = self.strides.map(|s| s as i64);
+        let data = self.as_ptr() as *mut c_void;
+
+        let ctx = Box::into_raw(Box::new(ExportContext {
+            managed: DLManagedTensor {
+                dl_tensor: DLTensor {
+                    data,
+                    device: DLDevice {
+                        device_type: DL_DEVICE_CPU,
+                        device_id: 0,
+                    },
+                    ndim: N as i32,
+                    dtype: T::DTYPE,
+                    shape: std::ptr::null_mut(),
+                    strides: std::ptr::null_mut(),
+                    byte_offset: 0,
+                },
+                manager_ctx: std::ptr::null_mut(),
+                deleter: Some(export_deleter::<T, N, A>),
+            },
+            _tensor: self,
+            shape,
+            strides,
+        }));
+
+        // Safety: the context was just allocated and its fields don't move until it is freed.
+        unsafe {
+            (*ctx).managed.dl_tensor.shape = (*ctx).shape.as_mut_ptr();
+            (*ctx).managed.dl_tensor.strides = (*ctx).strides.as_mut_ptr();
+            (*ctx).managed.manager_ctx = ctx as *mut c_void;
+            &mut (*ctx).managed
+        }
+    }
+
+    /// Creates a tensor from a DLPack managed tensor without copying the data.
+    ///
+    /// The tensor takes ownership of the managed tensor and calls its deleter when dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `managed` - The managed tensor to import.
+    /// * `alloc` - The allocator used when the tensor is cloned.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the managed tensor is not a compact row-major CPU tensor of rank
+    /// `N` with elements of type `T`. On error the managed tensor is not consumed.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must point to a valid managed tensor following the DLPack protocol.
+    pub unsafe fn from_dlpack(
+        managed: *mut DLManagedTensor,
+        alloc: A,
+    ) -> Result<Self, TensorError> {
+        let dl_tensor = match managed.as_ref() {
+            Some(m) => &m.dl_tensor,
+            None => Err(TensorError::UnsupportedOperation(
+                "null DLPack tensor".to_string(),
+            ))?,
+        };
+
+        if dl_tensor.device.device_type != DL_DEVICE_CPU {
+            return Err(TensorError::UnsupportedOperation(format!(
+                "DLPack device type {} is not the CPU",
+                dl_tensor.device.device_type
+            )));
+        }
+
+        if dl_tensor.dtype != T::DTYPE {
+            return Err(TensorError::UnsupportedOperation(format!(
+                "DLPack data type {:?} does not match {:?}",
+                dl_tensor.dtype,
+                T::DTYPE
+            )));
+        }
+
+        if dl_tensor.ndim != N as i32 {
+            return Err(TensorError::DimensionMismatch(format!(
+                "DLPack tensor has {} dimensions, expected {}",
+                dl_tensor.ndim, N
+            )));
+        }
+
+        let mut shape = [0usize; N];
+        for (i, s) in shape.iter_mut().enumerate() {
+            *s = usize::try_from(*dl_tensor.shape.add(i)).map_err(|_| TensorError::CastError)?;
+        }
+
+        let strides = crate::get_strides_from_shape(shape);
+        if !dl_tensor.strides.is_null() {
+            // strides of dimensions of size 1 are irrelevant for the memory layout
+            let compact =
+                (0..N).all(|i| shape[i] == 1 || *dl_tensor.strides.add(i) == strides[i] as i64);
+            if !compact {
+                return Err(TensorError::UnsupportedOperation(
+                    "DLPack tensor is not compact row-major".to_string(),
+                ));
+            }
+        }
+
+        let data = (dl_tensor.data as *mut u8).add(dl_tensor.byte_offset as usize) as *mut T;
+        if data.is_null() || data.align_offset(std::mem::align_of::<T>()) != 0 {
+            return Err(TensorError::UnsupportedOperation(
+                "DLPack tensor data is null or misaligned".to_string(),
+            ));
+        }
+
+        let numel = shape.iter().product::<usize>();
+        let guard = ImportGuard(managed);
+        let storage =
+            TensorStorage::from_raw_parts_with_drop(data, numel, alloc, move || drop(guard));
+
+        Ok(Self {
+            storage,
+            shape,
+            strides,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CpuAllocator;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static DELETED: AtomicUsize = AtomicUsize::new(0);
+
+    struct Foreign {
+        managed: DLManagedTensor,
+        data: Vec<u8>,
+        shape: [i64; 2],
+        strides: [i64; 2],
+    }
+
+    unsafe extern "C" fn foreign_deleter(managed: *mut DLManagedTensor) {
+        drop(Box::from_raw((*managed).manager_ctx as *mut Foreign));
+        DELETED.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn foreign_tensor(dtype: DLDataType, strides: [i64; 2]) -> *mut DLManagedTensor {
+        let ctx = Box::into_raw(Box::new(Foreign {
+            managed: DLManagedTensor {
+                dl_tensor: DLTensor {
+                    data: std::ptr::null_mut(),
+                    device: DLDevice {
+                        device_type: DL_DEVICE_CPU,
+                        device_id: 0,
+                    },
+                    ndim: 2,
+                    dtype,
+                    shape: std::ptr::null_mut(),
+                    strides: std::ptr::null_mut(),
+                    byte_offset: 2,
+                },
+                manager_ctx: std::ptr::null_mut(),
+                deleter: Some(foreign_deleter),
+            },
+            // two bytes of header followed by a 2x3 image
+            data: vec![255, 255, 0, 1, 2, 3, 4, 5],
+            shape: [2, 3],
+            strides,
+        }));
+        unsafe {
+            (*ctx).managed.dl_tensor.data = (*ctx).data.as_mut_ptr() as *mut c_void;
+            (*ctx).managed.dl_tensor.shape = (*ctx).shape.as_mut_ptr();
+            (*ctx).managed.dl_tensor.strides = (*ctx).strides.as_mut_ptr();
+            (*ctx).managed.manager_ctx = ctx as *mut c_void;
+            &mut (*ctx).managed
+        }
+    }
+
+    #[test]
+    fn test_dlpack_dtypes() {
+        assert_eq!(
+            u8::DTYPE,
+            DLDataType {
+                code: 1,
+                bits: 8,
+                lanes: 1
+            }
+        );
+        assert_eq!(
+            i16::DTYPE,
+            DLDataType {
+                code: 0,
+                bits: 16,
+                lanes: 1
+            }
+        );
+        assert_eq!(
+            f64::DTYPE,
+            DLDataType {
+                code: 2,
+                bits: 64,
+                lanes: 1
+            }
+        );
+        assert_eq!(
+            bool::DTYPE,
+            DLDataType {
+                code: 6,
+                bits: 8,
+                lanes: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_dlpack_export() -> Result<(), TensorError> {
+        let t = Tensor::<i32, 3, _>::from_shape_fn([2, 3, 4], CpuAllocator, |[i, j, k]| {
+            (i * 12 + j * 4 + k) as i32
+        });
+        let ptr = t.as_ptr();
+
+        let managed = t.into_dlpack();
+        let dl_tensor = unsafe { &(*managed).dl_tensor };
+        assert_eq!(dl_tensor.data as *const i32, ptr);
+        assert_eq!(dl_tensor.ndim, 3);
+        assert_eq!(dl_tensor.dtype, i32::DTYPE);
+        let shape = unsafe { std::slice::from_raw_parts(dl_tensor.shape, 3) };
+        let strides = unsafe { std::slice::from_raw_parts(dl_tensor.strides, 3) };
+        assert_eq!(shape, [2, 3, 4]);
+        assert_eq!(strides, [12, 4, 1]);
+
+        // importing with a different type or rank fails without consuming the tensor
+        assert!(unsafe { Tensor::<f32, 3, _>::from_dlpack(managed, CpuAllocator) }.is_err());
+        assert!(unsafe { Tensor::<i32, 2, _>::from_dlpack(managed, CpuAllocator) }.is_err());
+
+        let t = unsafe { Tensor::<i32, 3, _>::from_dlpack(managed, CpuAllocator)? };
+        assert_eq!(t.as_ptr(), ptr);
+        assert_eq!(t.get([1, 2, 3]), Some(&23));
+        Ok(())
+    }
+
+    #[test]
+    fn test_dlpack_import() -> Result<(), TensorError> {
+        let managed = foreign_tensor(u8::DTYPE, [3, 1]);
+        let t = unsafe { Tensor::<u8, 2, _>::from_dlpack(managed, CpuAllocator)? };
+        assert_eq!(t.shape, [2, 3]);
+        assert_eq!(t.as_slice(), [0, 1, 2, 3, 4, 5]);
+
+        let deleted = DELETED.load(Ordering::SeqCst);
+        drop(t);
+        assert_eq!(DELETED.load(Ordering::SeqCst), deleted + 1);
+
+        // column-major tensors are rejected
+        let managed = foreign_tensor(u8::DTYPE, [1, 2]);
+        let res = unsafe { Tensor::<u8, 2, _>::from_dlpack(managed, CpuAllocator) };
+        assert!(res.is_err());
+        unsafe { (*managed).deleter.unwrap()(managed) };
+        Ok(())
+    }
+}
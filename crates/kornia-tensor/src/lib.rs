@@ -8,6 +8,14 @@ pub mod allocator;
 #[cfg(feature = "bincode")]
 pub mod bincode;
 
+/// dlpack module containing the zero-copy conversion from/to DLPack tensors.
+#[cfg(feature = "dlpack")]
+pub mod dlpack;
+
+/// npy module containing the reading and writing of NumPy `.npy` and `.npz` files.
+#[cfg(feature = "npy")]
+pub mod npy;
+
 /// tensor module containing the tensor and storage implementations.
 pub mod tensor;
 
@@ -21,7 +29,7 @@ pub mod storage;
 /// view module containing the view implementations.
 pub mod view;
 
-pub use crate::allocator::{CpuAllocator, TensorAllocator};
+pub use crate::allocator::{AlignedAllocator, CpuAllocator, PoolAllocator, TensorAllocator};
 pub(crate) use crate::tensor::get_strides_from_shape;
 pub use crate::tensor::{Tensor, TensorError};
 
@@ -0,0 +1,773 @@
+//! Read and write tensors in the NumPy `.npy` and `.npz` formats.
+//!
+//! Reference: <https://numpy.org/doc/stable/reference/generated/numpy.lib.format.html>
+
+use std::fs::File;
+use std::io::{Read, Seek, Write};
+use std::marker::PhantomData;
+use std::path::Path;
+
+use thiserror::Error;
+
+use crate::{allocator::TensorAllocator, CpuAllocator, Tensor, TensorError};
+
+/// The magic string at the beginning of every `.npy` file.
+const NPY_MAGIC: &[u8] = b"\x93NUMPY";
+
+/// The alignment in bytes of the start of the data of a `.npy` file.
+const NPY_ALIGN: usize = 64;
+
+/// An error type for the npy module.
+#[derive(Debug, Error)]
+pub enum NpyError {
+    /// Error reading or writing the file.
+    #[error("Failed to read or write the file: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Error reading or writing the npz archive.
+    #[error("Failed to read or write the npz archive: {0}")]
+    Zip(#[from] zip::result::ZipError),
+
+    /// The header of the file is not valid.
+    #[error("Invalid npy header: {0}")]
+    InvalidHeader(String),
+
+    /// The data type of the file does not match the tensor type.
+    #[error("Data type mismatch: the file contains '{0}', expected '{1}'")]
+    DtypeMismatch(String, &'static str),
+
+    /// The rank of the array does not match the tensor rank.
+    #[error("Rank mismatch: the file contains {0} dimensions, expected {1}")]
+    RankMismatch(usize, usize),
+
+    /// The array is stored in Fortran (column-major) order.
+    #[error("Arrays in Fortran order are not supported")]
+    FortranOrder,
+
+    /// The data of the array is not valid for the tensor type.
+    #[error("Invalid data for the type '{0}'")]
+    InvalidData(&'static str),
+
+    /// Error creating the tensor.
+    #[error(transparent)]
+    TensorError(#[from] TensorError),
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A trait for the tensor element types that can be stored in `.npy` files.
+///
+/// This trait is sealed and implemented for the primitive numeric types and `bool`.
+pub trait NpyType: Copy + Default + private::Sealed {
+    /// The NumPy type descriptor of the type, e.g. `<f4`.
+    const DESCR: &'static str;
+
+    /// Returns true if the raw bytes are a valid sequence of values of the type.
+    fn is_valid(_bytes: &[u8]) -> bool {
+        true
+    }
+}
+
+macro_rules! impl_npy_type {
+    ($($t:ty => $descr:expr),* $(,)?) => {
+        $(
+            impl private::Sealed for $t {}
+            impl NpyType for $t {
+                const DESCR: &'static str = $descr;
+            }
+        )*
+    };
+}
+
+#[cfg(target_endian = "little")]
+impl_npy_type!(
+    u8 => "|u1",
+    i8 => "|i1",
+    u16 => "<u2",
+    i16 => "<i2",
+    u32 => "<u4",
+    i32 => "<i4",
+    u64 => "<u8",
+    i64 => "<i8",
+    f32 => "<f4",
+    f64 => "<f8",
+);
+
+#[cfg(target_endian = "big")]
+impl_npy_type!(
+    u8 => "|u1",
+    i8 => "|i1",
+    u16 => ">u2",
+    i16 => ">i2",
+    u32 => ">u4",
+    i32 => ">i4",
+    u64 => ">u8",
+    i64 => ">i8",
+    f32 => ">f4",
+    f64 => ">f8",
+);
+
+impl private::Sealed for bool {}
+impl NpyType for bool {
+    const DESCR: &'static str = "|b1";
+
+    fn is_valid(bytes: &[u8]) -> bool {
+        bytes.iter().all(|&b| b <= 1)
+    }
+}
+
+/// The parsed header of a `.npy` file.
+#[derive(Debug, PartialEq)]
+struct NpyHeader {
+    descr: String,
+    fortran_order: bool,
+    shape: Vec<usize>,
+}
+
+impl NpyHeader {
+    /// Checks that the header describes a C-ordered array of `T` with rank `N`.
+    fn shape_of<T: NpyType, const N: usize>(&self) -> Result<[usize; N], NpyError> {
+        // native endianness may also be written as '=' by NumPy
+        let descr = self.descr.replacen('=', &T::DESCR[..1], 1);
+        if descr != T::DESCR {
+            return Err(NpyError::DtypeMismatch(self.descr.clone(), T::DESCR));
+        }
+        if self.fortran_order {
+            return Err(NpyError::FortranOrder);
+        }
+        self.shape
+            .as_slice()
+            .try_into()
+            .map_err(|_| NpyError::RankMismatch(self.shape.len(), N))
+    }
+}
+
+/// The number of bytes read at a time by [`read_npy`].
+const NPY_READ_CHUNK_BYTES: usize = 1 << 20;
+
+/// Returns the number of elements of `shape` and their size in bytes.
+///
+/// The shape comes from the file header, so the sizes are checked for overflow.
+fn data_len<T: NpyType>(shape: &[usize]) -> Result<(usize, usize), NpyError> {
+    let overflow = || NpyError::InvalidHeader(format!("shape {shape:?} is too large"));
+    let numel = shape
+        .iter()
+        .try_fold(1usize, |acc, &s| acc.checked_mul(s))
+        .ok_or_else(overflow)?;
+    let num_bytes = numel
+        .checked_mul(std::mem::size_of::<T>())
+        .ok_or_else(overflow)?;
+    Ok((numel, num_bytes))
+}
+
+/// Returns the value of `key` in the python dictionary literal of a header.
+fn header_value<'a>(header: &'a str, key: &str) -> Result<&'a str, NpyError> {
+    let missing = || NpyError::InvalidHeader(format!("missing key '{key}'"));
+    let start = header.find(&format!("'{key}'")).ok_or_else(missing)? + key.len() + 2;
+    let value = header[start..]
+        .trim_start()
+        .strip_prefix(':')
+        .ok_or_else(missing)?;
+    let value = value.trim_start();
+
+    // the value ends at the closing parenthesis of a tuple or at the next comma
+    let end = match value.starts_with('(') {
+        true => value.find(')').map(|i| i + 1),
+        false => value.find([',', '}']),
+    };
+    Ok(value[..end.ok_or_else(missing)?].trim())
+}
+
+/// Parses the python dictionary literal of a `.npy` header.
+fn parse_header(header: &str) -> Result<NpyHeader, NpyError> {
+    let descr = header_value(header, "descr")?
+        .trim_matches(|c| c == '\'' || c == '"')
+        .to_string();
+
+    let fortran_order = match header_value(header, "fortran_order")? {
+        "True" => true,
+        "False" => false,
+        v => return Err(NpyError::InvalidHeader(format!("fortran_order '{v}'"))),
+    };
+
+    let shape = header_value(header, "shape")?
+        .trim_start_matches('(')
+        .trim_end_matches(')')
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            s.parse::<usize>()
+                .map_err(|_| NpyError::InvalidHeader(format!("shape dimension '{s}'")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(NpyHeader {
+        descr,
+        fortran_order,
+        shape,
+    })
+}
+
+/// Reads the magic string and header of a `.npy` stream.
+///
+/// Returns the header and the total number of bytes before the data.
+fn read_header<R: Read>(reader: &mut R) -> Result<(NpyHeader, usize), NpyError> {
+    let mut preamble = [0u8; 8];
+    reader.read_exact(&mut preamble)?;
+    if &preamble[..6] != NPY_MAGIC {
+        return Err(NpyError::InvalidHeader("missing magic string".to_string()));
+    }
+
+    let (header_len, preamble_len) = match preamble[6] {
+        1 => {
+            let mut len = [0u8; 2];
+            reader.read_exact(&mut len)?;
+            (u16::from_le_bytes(len) as usize, 10)
+        }
+        2 | 3 => {
+            let mut len = [0u8; 4];
+            reader.read_exact(&mut len)?;
+            (u32::from_le_bytes(len) as usize, 12)
+        }
+        v => return Err(NpyError::InvalidHeader(format!("unsupported version {v}"))),
+    };
+
+    let mut header = vec![0u8; header_len];
+    reader.read_exact(&mut header)?;
+    let header = String::from_utf8(header)
+        .map_err(|_| NpyError::InvalidHeader("header is not valid utf-8".to_string()))?;
+
+    Ok((parse_header(&header)?, preamble_len + header_len))
+}
+
+/// Writes the magic string and header of a `.npy` stream for an array of `T`.
+fn write_header<T: NpyType, W: Write>(writer: &mut W, shape: &[usize]) -> Result<(), NpyError> {
+    let shape = match shape {
+        [s] => format!("({s},)"),
+        _ => format!(
+            "({})",
+            shape
+                .iter()
+                .map(|s| s.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+    let mut header = format!(
+        "{{'descr': '{}', 'fortran_order': False, 'shape': {}, }}",
+        T::DESCR,
+        shape
+    );
+
+    // pad the header with spaces so that the data is aligned, the header ends with a newline
+    let (version, preamble_len) = match header.len() + 11 <= u16::MAX as usize {
+        true => (1u8, 10),
+        false => (2u8, 12),
+    };
+    let padding = NPY_ALIGN - (preamble_len + header.len() + 1) % NPY_ALIGN;
+    header.extend(std::iter::repeat(' ').take(padding % NPY_ALIGN));
+    header.push('\n');
+
+    writer.write_all(NPY_MAGIC)?;
+    writer.write_all(&[version, 0])?;
+    match version {
+        1 => writer.write_all(&(header.len() as u16).to_le_bytes())?,
+        _ => writer.write_all(&(header.len() as u32).to_le_bytes())?,
+    }
+    writer.write_all(header.as_bytes())?;
+
+    Ok(())
+}
+
+/// Returns the bytes of a slice of `T`.
+fn as_bytes<T: NpyType>(data: &[T]) -> &[u8] {
+    // Safety: the npy types are plain old data without padding.
+    unsafe { std::slice::from_raw_parts(data.as_ptr() as *const u8, std::mem::size_of_val(data)) }
+}
+
+/// Write a tensor in the `.npy` format.
+///
+/// # Arguments
+///
+/// * `writer` - The writer to write the tensor to.
+/// * `tensor` - The tensor to write.
+///
+/// # Errors
+///
+/// An error is returned if the writer fails.
+pub fn write_npy<T, const N: usize, A, W>(
+    writer: &mut W,
+    tensor: &Tensor<T, N, A>,
+) -> Result<(), NpyError>
+where
+    T: NpyType,
+    A: TensorAllocator + 'static,
+    W: Write,
+{
+    write_header::<T, _>(writer, &tensor.shape)?;
+    writer.write_all(as_bytes(tensor.as_slice()))?;
+    Ok(())
+}
+
+/// Read a tensor in the `.npy` format.
+///
+/// # Arguments
+///
+/// * `reader` - The reader to read the tensor from.
+///
+/// # Returns
+///
+/// A new tensor with the data of the array.
+///
+/// # Errors
+///
+/// An error is returned if the stream is not a valid `.npy` array of `T` with rank `N`.
+pub fn read_npy<T, const N: usize, R>(
+    reader: &mut R,
+) -> Result<Tensor<T, N, CpuAllocator>, NpyError>
+where
+    T: NpyType,
+    R: Read,
+{
+    let (header, _) = read_header(reader)?;
+    let shape = header.shape_of::<T, N>()?;
+
+    let (numel, _) = data_len::<T>(&shape)?;
+
+    // grow the data as it is read, so that a corrupted header can not make us allocate more
+    // than the reader holds
+    let chunk_len = (NPY_READ_CHUNK_BYTES / std::mem::size_of::<T>()).max(1);
+    let mut data = Vec::new();
+    while data.len() < numel {
+        let start = data.len();
+        data.resize(numel.min(start + chunk_len), T::default());
+        // Safety: the npy types are plain old data and the bytes are validated before use.
+        let bytes = unsafe {
+            let chunk = &mut data[start..];
+            std::slice::from_raw_parts_mut(
+                chunk.as_mut_ptr() as *mut u8,
+                std::mem::size_of_val(chunk),
+            )
+        };
+        reader.read_exact(bytes)?;
+        if !T::is_valid(bytes) {
+            return Err(NpyError::InvalidData(T::DESCR));
+        }
+    }
+
+    Ok(Tensor::from_shape_vec(shape, data, CpuAllocator)?)
+}
+
+/// Save a tensor to a `.npy` file.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file.
+/// * `tensor` - The tensor to save.
+///
+/// # Example
+///
+/// ```no_run
+/// use kornia_tensor::{Tensor, CpuAllocator};
+/// use kornia_tensor::npy::{load_npy, save_npy};
+///
+/// let t = Tensor::<f32, 2, _>::from_shape_val([480, 640], 1.0, CpuAllocator);
+/// save_npy("depth.npy", &t).unwrap();
+///
+/// let t2 = load_npy::<f32, 2>("depth.npy").unwrap();
+/// assert_eq!(t2.shape, [480, 640]);
+/// ```
+pub fn save_npy<T, const N: usize, A>(
+    path: impl AsRef<Path>,
+    tensor: &Tensor<T, N, A>,
+) -> Result<(), NpyError>
+where
+    T: NpyType,
+    A: TensorAllocator + 'static,
+{
+    let mut writer = std::io::BufWriter::new(File::create(path)?);
+    write_npy(&mut writer, tensor)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Load a tensor from a `.npy` file.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file.
+///
+/// # Returns
+///
+/// A new tensor with the data of the file.
+pub fn load_npy<T, const N: usize>(
+    path: impl AsRef<Path>,
+) -> Result<Tensor<T, N, CpuAllocator>, NpyError>
+where
+    T: NpyType,
+{
+    read_npy(&mut std::io::BufReader::new(File::open(path)?))
+}
+
+/// The memory map of the data of a `.npy` file viewed as a slice of `T`.
+struct NpyMmap<T> {
+    mmap: memmap2::MmapMut,
+    offset: usize,
+    len: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<T: NpyType> AsMut<[T]> for NpyMmap<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        // Safety: the offset is aligned and the map holds `len` validated values of `T`.
+        unsafe {
+            std::slice::from_raw_parts_mut(
+                self.mmap.as_mut_ptr().add(self.offset) as *mut T,
+                self.len,
+            )
+        }
+    }
+}
+
+/// Load a tensor from a `.npy` file by memory mapping it.
+///
+/// The data is not read into memory but paged in from the file on access. The map is private,
+/// so writing to the tensor does not modify the file.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file.
+///
+/// # Returns
+///
+/// A new tensor backed by the memory map of the file.
+///
+/// # Errors
+///
+/// An error is returned if the file is not a valid `.npy` array of `T` with rank `N` or if
+/// the data is not aligned for `T`.
+///
+/// # Safety
+///
+/// The file must not be modified by other processes while it is mapped.
+pub unsafe fn load_npy_mmap<T, const N: usize>(
+    path: impl AsRef<Path>,
+) -> Result<Tensor<T, N, CpuAllocator>, NpyError>
+where
+    T: NpyType + Send + 'static,
+{
+    let file = File::open(path)?;
+    let (header, offset) = read_header(&mut std::io::BufReader::new(&file))?;
+    let shape = header.shape_of::<T, N>()?;
+
+    let (len, num_bytes) = data_len::<T>(&shape)?;
+    let mmap = memmap2::MmapOptions::new().map_copy(&file)?;
+
+    let Some(bytes) = offset
+        .checked_add(num_bytes)
+        .and_then(|end| mmap.get(offset..end))
+    else {
+        return Err(NpyError::InvalidData(T::DESCR));
+    };
+    if !T::is_valid(bytes) {
+        return Err(NpyError::InvalidData(T::DESCR));
+    }
+    if bytes.as_ptr().align_offset(std::mem::align_of::<T>()) != 0 {
+        return Err(NpyError::InvalidHeader(
+            "the data is not aligned".to_string(),
+        ));
+    }
+
+    let owner = NpyMmap::<T> {
+        mmap,
+        offset,
+        len,
+        _marker: PhantomData,
+    };
+
+    Ok(Tensor::from_owner(shape, owner, CpuAllocator)?)
+}
+
+/// A writer of `.npz` archives of named tensors.
+///
+/// The tensors are stored uncompressed, as done by `numpy.savez`.
+///
+/// # Example
+///
+/// ```no_run
+/// use kornia_tensor::{Tensor, CpuAllocator};
+/// use kornia_tensor::npy::{NpzReader, NpzWriter};
+///
+/// let map_x = Tensor::<f32, 2, _>::from_shape_val([480, 640], 0.0, CpuAllocator);
+/// let valid = Tensor::<u8, 2, _>::from_shape_val([480, 640], 1, CpuAllocator);
+///
+/// let mut npz = NpzWriter::create("calib.npz").unwrap();
+/// npz.add("map_x", &map_x).unwrap();
+/// npz.add("valid", &valid).unwrap();
+/// npz.finish().unwrap();
+///
+/// let mut npz = NpzReader::open("calib.npz").unwrap();
+/// let map_x = npz.read::<f32, 2>("map_x").unwrap();
+/// ```
+pub struct NpzWriter<W: Write + Seek> {
+    zip: zip::ZipWriter<W>,
+}
+
+impl NpzWriter<std::io::BufWriter<File>> {
+    /// Creates a new `.npz` file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the file.
+    pub fn create(path: impl AsRef<Path>) -> Result<Self, NpyError> {
+        Ok(Self::new(std::io::BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<W: Write + Seek> NpzWriter<W> {
+    /// Creates a new `.npz` archive writing to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self {
+            zip: zip::ZipWriter::new(writer),
+        }
+    }
+
+    /// Adds a tensor to the archive.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the array in the archive, without the `.npy` extension.
+    /// * `tensor` - The tensor to add.
+    pub fn add<T, const N: usize, A>(
+        &mut self,
+        name: &str,
+        tensor: &Tensor<T, N, A>,
+    ) -> Result<(), NpyError>
+    where
+        T: NpyType,
+        A: TensorAllocator + 'static,
+    {
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Stored)
+            .large_file(std::mem::size_of_val(tensor.as_slice()) >= u32::MAX as usize);
+        self.zip.start_file(format!("{name}.npy"), options)?;
+        write_npy(&mut self.zip, tensor)
+    }
+
+    /// Finishes the archive and returns the inner writer.
+    pub fn finish(self) -> Result<W, NpyError> {
+        Ok(self.zip.finish()?)
+    }
+}
+
+/// A reader of `.npz` archives of named tensors.
+///
+/// Both stored and deflated archives, as written by `numpy.savez` and
+/// `numpy.savez_compressed`, are supported.
+pub struct NpzReader<R: Read + Seek> {
+    zip: zip::ZipArchive<R>,
+}
+
+impl NpzReader<std::io::BufReader<File>> {
+    /// Opens a `.npz` file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the file.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, NpyError> {
+        Self::new(std::io::BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R: Read + Seek> NpzReader<R> {
+    /// Creates a new `.npz` archive reader from `reader`.
+    pub fn new(reader: R) -> Result<Self, NpyError> {
+        Ok(Self {
+            zip: zip::ZipArchive::new(reader)?,
+        })
+    }
+
+    /// Returns the names of the arrays in the archive.
+    pub fn names(&self) -> Vec<String> {
+        self.zip
+            .file_names()
+            .map(|name| name.strip_suffix(".npy").unwrap_or(name).to_string())
+            .collect()
+    }
+
+    /// Reads an array of the archive.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the array, without the `.npy` extension.
+    ///
+    /// # Returns
+    ///
+    /// A new tensor with the data of the array.
+    pub fn read<T, const N: usize>(
+        &mut self,
+        name: &str,
+    ) -> Result<Tensor<T, N, CpuAllocator>, NpyError>
+    where
+        T: NpyType,
+    {
+        let mut file = self.zip.by_name(&format!("{name}.npy"))?;
+        read_npy(&mut file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_parse_header() -> Result<(), NpyError> {
+        let header = "{'descr': '<f4', 'fortran_order': False, 'shape': (3, 4), }    \n";
+        assert_eq!(
+            parse_header(header)?,
+            NpyHeader {
+                descr: "<f4".to_string(),
+                fortran_order: false,
+                shape: vec![3, 4],
+            }
+        );
+
+        let header = "{'shape': (5,), 'fortran_order': True, 'descr': '|u1'}";
+        let header = parse_header(header)?;
+        assert_eq!(header.shape, [5]);
+        assert!(header.fortran_order);
+
+        let header = parse_header("{'descr': '<f8', 'fortran_order': False, 'shape': ()}")?;
+        assert!(header.shape.is_empty());
+
+        assert!(parse_header("{'descr': '<f8', 'shape': ()}").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_npy_roundtrip() -> Result<(), NpyError> {
+        let t = Tensor::<i16, 3, _>::from_shape_fn([2, 3, 4], CpuAllocator, |[i, j, k]| {
+            (i * 100 + j * 10 + k) as i16 - 50
+        });
+
+        let mut buf = Vec::new();
+        write_npy(&mut buf, &t)?;
+        assert_eq!(&buf[..8], b"\x93NUMPY\x01\x00");
+        assert_eq!(
+            &buf[10..],
+            [
+                b"{'descr': '<i2', 'fortran_order': False, 'shape': (2, 3, 4), }".as_slice(),
+                &[b' '; 55],
+                b"\n",
+                as_bytes(t.as_slice()),
+            ]
+            .concat()
+        );
+
+        let t2 = read_npy::<i16, 3, _>(&mut buf.as_slice())?;
+        assert_eq!(t2.shape, t.shape);
+        assert_eq!(t2.as_slice(), t.as_slice());
+
+        assert!(matches!(
+            read_npy::<u16, 3, _>(&mut buf.as_slice()),
+            Err(NpyError::DtypeMismatch(_, "<u2"))
+        ));
+        assert!(matches!(
+            read_npy::<i16, 2, _>(&mut buf.as_slice()),
+            Err(NpyError::RankMismatch(3, 2))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_npy_1d_bool() -> Result<(), NpyError> {
+        let t = Tensor::<bool, 1, _>::from_shape_vec([3], vec![true, false, true], CpuAllocator)?;
+
+        let mut buf = Vec::new();
+        write_npy(&mut buf, &t)?;
+        let header = std::str::from_utf8(&buf[10..buf.len() - 3]).unwrap_or_default();
+        assert!(header.contains("'shape': (3,)"));
+
+        assert_eq!(
+            read_npy::<bool, 1, _>(&mut buf.as_slice())?.as_slice(),
+            [true, false, true]
+        );
+
+        // booleans must be 0 or 1
+        let n = buf.len();
+        buf[n - 1] = 2;
+        assert!(matches!(
+            read_npy::<bool, 1, _>(&mut buf.as_slice()),
+            Err(NpyError::InvalidData("|b1"))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_npy_huge_shape() -> Result<(), NpyError> {
+        // the header claims more data than the stream holds
+        let mut buf = Vec::new();
+        write_header::<f32, _>(&mut buf, &[1 << 40, 1])?;
+        buf.extend_from_slice(&[0u8; 16]);
+        assert!(matches!(
+            read_npy::<f32, 2, _>(&mut buf.as_slice()),
+            Err(NpyError::Io(_))
+        ));
+
+        // the number of bytes overflows
+        let mut buf = Vec::new();
+        write_header::<f32, _>(&mut buf, &[usize::MAX / 2, 4])?;
+        assert!(matches!(
+            read_npy::<f32, 2, _>(&mut buf.as_slice()),
+            Err(NpyError::InvalidHeader(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_npy_mmap() -> Result<(), NpyError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("depth.npy");
+
+        let t = Tensor::<f32, 2, _>::from_shape_fn([48, 64], CpuAllocator, |[i, j]| {
+            (i * 64 + j) as f32 * 0.5
+        });
+        save_npy(&path, &t)?;
+
+        let mut t2 = unsafe { load_npy_mmap::<f32, 2>(&path)? };
+        assert_eq!(t2.shape, [48, 64]);
+        assert_eq!(t2.as_slice(), t.as_slice());
+
+        // writes to the map do not modify the file
+        t2.as_slice_mut()[0] = -1.0;
+        assert_eq!(load_npy::<f32, 2>(&path)?.as_slice(), t.as_slice());
+
+        assert!(unsafe { load_npy_mmap::<f64, 2>(&path) }.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_npz_roundtrip() -> Result<(), NpyError> {
+        let a = Tensor::<f64, 2, _>::from_shape_fn([3, 2], CpuAllocator, |[i, j]| (i + j) as f64);
+        let b = Tensor::<u8, 1, _>::from_shape_vec([4], vec![1, 2, 3, 4], CpuAllocator)?;
+
+        let mut npz = NpzWriter::new(Cursor::new(Vec::new()));
+        npz.add("a", &a)?;
+        npz.add("b", &b)?;
+        let buf = npz.finish()?.into_inner();
+
+        let mut npz = NpzReader::new(Cursor::new(buf))?;
+        let mut names = npz.names();
+        names.sort();
+        assert_eq!(names, ["a", "b"]);
+
+        assert_eq!(npz.read::<f64, 2>("a")?.as_slice(), a.as_slice());
+        assert_eq!(npz.read::<u8, 1>("b")?.as_slice(), b.as_slice());
+        assert!(matches!(npz.read::<u8, 1>("c"), Err(NpyError::Zip(_))));
+        Ok(())
+    }
+}
@@ -2,6 +2,26 @@ use std::{alloc::Layout, ptr::NonNull};
 
 use crate::allocator::TensorAllocator;
 
+/// Returns true if memory coming from the global allocator with the natural alignment of `T`
+/// can be owned by `alloc`.
+#[inline]
+fn shares_global_memory<T, A: TensorAllocator>(alloc: &A) -> bool {
+    alloc.alignment() <= std::mem::align_of::<T>()
+}
+
+/// The boxed owner of the memory of a tensor buffer created with `TensorStorage::from_owner`.
+struct ExternalOwner<O>(*mut O);
+
+// Safety: the owner is only accessed when it is dropped.
+unsafe impl<O: Send> Send for ExternalOwner<O> {}
+
+impl<O> Drop for ExternalOwner<O> {
+    fn drop(&mut self) {
+        // Safety: the pointer comes from `Box::into_raw` and is dropped only once.
+        drop(unsafe { Box::from_raw(self.0) });
+    }
+}
+
 /// Definition of the buffer for a tensor.
 pub struct TensorStorage<T, A: TensorAllocator> {
     /// The pointer to the tensor memory which must be non null.
@@ -12,6 +32,8 @@ pub struct TensorStorage<T, A: TensorAllocator> {
     pub(crate) layout: Layout,
     /// The allocator used to allocate/deallocate the tensor memory.
     pub(crate) alloc: A,
+    /// The callback releasing externally owned memory instead of the allocator.
+    pub(crate) on_drop: Option<Box<dyn FnOnce() + Send>>,
 }
 
 impl<T, A: TensorAllocator> TensorStorage<T, A> {
@@ -63,9 +85,63 @@ impl<T, A: TensorAllocator> TensorStorage<T, A> {
         &self.alloc
     }
 
-    // TODO: use the allocator somehow
+    /// Creates a new tensor buffer with `len` elements allocated with `alloc`.
+    ///
+    /// The element at position `i` is initialized with `f(i)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - The number of elements of the buffer.
+    /// * `alloc` - The allocator to use.
+    /// * `f` - The function to generate the elements.
+    pub fn from_fn(len: usize, alloc: A, mut f: impl FnMut(usize) -> T) -> Self {
+        let layout = match Layout::array::<T>(len) {
+            Ok(layout) => layout,
+            Err(_) => panic!("tensor buffer of {len} elements exceeds the maximum layout size"),
+        };
+
+        let ptr = if layout.size() == 0 {
+            // a dangling pointer with the alignment of the memory returned by the allocator
+            let align = alloc.alignment().max(std::mem::align_of::<T>());
+            // Safety: the alignment is a power of two, hence not zero.
+            unsafe { NonNull::new_unchecked(align as *mut T) }
+        } else {
+            match alloc.alloc(layout).map(|ptr| NonNull::new(ptr as *mut T)) {
+                Ok(Some(ptr)) => ptr,
+                _ => std::alloc::handle_alloc_error(layout),
+            }
+        };
+
+        for i in 0..len {
+            // Safety: the pointer is valid for `len` elements of `T`.
+            unsafe { ptr.as_ptr().add(i).write(f(i)) };
+        }
+
+        Self {
+            ptr,
+            len: layout.size(),
+            layout,
+            alloc,
+            on_drop: None,
+        }
+    }
+
     /// Creates a new tensor buffer from a vector.
+    ///
+    /// The vector memory is reused if the allocator can take ownership of it, otherwise the
+    /// elements are moved into a new buffer allocated with `alloc`.
     pub fn from_vec(value: Vec<T>, alloc: A) -> Self {
+        if !shares_global_memory::<T, A>(&alloc) {
+            let mut value = std::mem::ManuallyDrop::new(value);
+            let src = value.as_mut_ptr();
+            // Safety: each element is read exactly once and the vector is released without
+            // dropping its elements.
+            let storage = Self::from_fn(value.len(), alloc, |i| unsafe { src.add(i).read() });
+            unsafe { value.set_len(0) };
+            std::mem::ManuallyDrop::into_inner(value);
+            return storage;
+        }
+
         //let buf = arrow_buffer::Buffer::from_vec(value);
         // Safety
         // Vec::as_ptr guaranteed to not be null
@@ -82,6 +158,7 @@ impl<T, A: TensorAllocator> TensorStorage<T, A> {
             len,
             layout,
             alloc,
+            on_drop: None,
         }
     }
 
@@ -98,29 +175,133 @@ impl<T, A: TensorAllocator> TensorStorage<T, A> {
             len,
             layout,
             alloc,
+            on_drop: None,
+        }
+    }
+
+    /// Creates a new tensor buffer from memory owned by `owner`.
+    ///
+    /// The buffer points to the elements of `owner` without copying them, and `owner` is
+    /// dropped together with the buffer. This allows wrapping memory that is not managed by
+    /// the allocator, e.g. a memory mapped file or a buffer from a camera driver.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - The owner of the memory.
+    /// * `alloc` - The allocator used when the buffer is cloned.
+    pub fn from_owner<O>(owner: O, alloc: A) -> Self
+    where
+        O: AsMut<[T]> + Send + 'static,
+    {
+        // box the owner so that the data does not move when the buffer is moved
+        let owner = ExternalOwner(Box::into_raw(Box::new(owner)));
+        // Safety: the owner is valid until `ExternalOwner` is dropped.
+        let data = unsafe { (*owner.0).as_mut() };
+        let len = std::mem::size_of_val(data);
+        let ptr = NonNull::from(data).cast::<T>();
+
+        Self {
+            ptr,
+            len,
+            layout: Layout::new::<()>(),
+            alloc,
+            on_drop: Some(Box::new(move || drop(owner))),
+        }
+    }
+
+    /// Creates a new tensor buffer from externally owned memory and a drop callback.
+    ///
+    /// The memory is never deallocated by the allocator, instead `on_drop` is called once the
+    /// buffer is dropped, e.g. to release a mapped buffer or to call a foreign deleter.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - A pointer to the memory.
+    /// * `len` - The number of elements of type `T`.
+    /// * `alloc` - The allocator used when the buffer is cloned.
+    /// * `on_drop` - The callback releasing the memory.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be non-null, aligned and valid for reads and writes of `len` elements
+    /// until `on_drop` is called. If the memory is read-only, the buffer must not be mutated.
+    pub unsafe fn from_raw_parts_with_drop(
+        data: *mut T,
+        len: usize,
+        alloc: A,
+        on_drop: impl FnOnce() + Send + 'static,
+    ) -> Self {
+        Self {
+            ptr: NonNull::new_unchecked(data),
+            len: len * std::mem::size_of::<T>(),
+            layout: Layout::new::<()>(),
+            alloc,
+            on_drop: Some(Box::new(on_drop)),
         }
     }
 
     /// Converts the `TensorStorage` into a `Vec<T>`.
     ///
-    /// Returns `Err(self)` if the buffer does not have the same layout as the destination Vec.
+    /// The buffer is reused if it comes from memory compatible with the global allocator,
+    /// otherwise the elements are moved into a new vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the memory is externally owned and `T` needs to be dropped, since the owner
+    /// still drops its elements. Use [`TensorStorage::try_into_vec`] or
+    /// [`TensorStorage::to_vec`] instead.
     pub fn into_vec(self) -> Vec<T> {
-        // TODO: check if the buffer is a cpu buffer or comes from a custom allocator
-        let _layout = &self.layout;
+        match self.try_into_vec() {
+            Ok(vec) => vec,
+            Err(_) => panic!("cannot move the elements out of externally owned memory"),
+        }
+    }
 
-        let vec_capacity = self.layout.size() / std::mem::size_of::<T>();
-        //match Layout::array::<T>(vec_capacity) {
-        //    Ok(expected) if layout == &expected => {}
-        //    e => return Err(TensorAllocatorError::LayoutError(e.unwrap_err())),
-        //}
+    /// Converts the `TensorStorage` into a `Vec<T>`, unless the elements are owned by an
+    /// external owner that drops them.
+    ///
+    /// # Returns
+    ///
+    /// The vector of the elements, or the storage itself if its memory is externally owned
+    /// and `T` needs to be dropped.
+    pub fn try_into_vec(self) -> Result<Vec<T>, Self> {
+        let external = self.on_drop.is_some();
+        if external && std::mem::needs_drop::<T>() {
+            return Err(self);
+        }
 
-        let length = self.len;
+        let vec_len = self.len / std::mem::size_of::<T>();
+
+        if external || !shares_global_memory::<T, A>(&self.alloc) {
+            let mut vec = Vec::with_capacity(vec_len);
+            // Safety: the elements are moved into the vector and dropping the buffer only
+            // releases its memory, through the allocator or the owner of elements that are
+            // not dropped.
+            unsafe {
+                std::ptr::copy_nonoverlapping(self.as_ptr(), vec.as_mut_ptr(), vec_len);
+                vec.set_len(vec_len);
+            }
+            return Ok(vec);
+        }
+
+        let vec_capacity = self.layout.size() / std::mem::size_of::<T>();
         let ptr = self.ptr;
-        let vec_len = length / std::mem::size_of::<T>();
 
         // Safety
-        std::mem::forget(self);
-        unsafe { Vec::from_raw_parts(ptr.as_ptr(), vec_len, vec_capacity) }
+        // release the allocator without deallocating the memory now owned by the vector
+        let mut this = std::mem::ManuallyDrop::new(self);
+        unsafe {
+            std::ptr::drop_in_place(&mut this.alloc);
+            Ok(Vec::from_raw_parts(ptr.as_ptr(), vec_len, vec_capacity))
+        }
+    }
+
+    /// Copies the elements of the `TensorStorage` into a new `Vec<T>`.
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.as_slice().to_vec()
     }
 }
 
@@ -131,8 +312,12 @@ unsafe impl<T, A: TensorAllocator> Sync for TensorStorage<T, A> {}
 
 impl<T, A: TensorAllocator> Drop for TensorStorage<T, A> {
     fn drop(&mut self) {
-        self.alloc
-            .dealloc(self.ptr.as_ptr() as *mut u8, self.layout);
+        match self.on_drop.take() {
+            Some(on_drop) => on_drop(),
+            None => self
+                .alloc
+                .dealloc(self.ptr.as_ptr() as *mut u8, self.layout),
+        }
     }
 }
 /// A new `TensorStorage` instance with cloned data if successful, otherwise an error.
@@ -142,13 +327,8 @@ where
     A: TensorAllocator + 'static,
 {
     fn clone(&self) -> Self {
-        let mut new_vec = Vec::<T>::with_capacity(self.len());
-
-        for i in self.as_slice() {
-            new_vec.push(i.clone());
-        }
-
-        Self::from_vec(new_vec, self.alloc.clone())
+        let data = self.as_slice();
+        Self::from_fn(data.len(), self.alloc.clone(), |i| data[i].clone())
     }
 }
 
@@ -156,7 +336,7 @@ where
 mod tests {
 
     use super::TensorStorage;
-    use crate::allocator::{CpuAllocator, TensorAllocatorError};
+    use crate::allocator::{AlignedAllocator, CpuAllocator, PoolAllocator, TensorAllocatorError};
     use crate::TensorAllocator;
     use std::alloc::Layout;
     use std::cell::RefCell;
@@ -177,6 +357,7 @@ mod tests {
             len: size * std::mem::size_of::<u8>(),
             layout,
             ptr,
+            on_drop: None,
         };
 
         assert_eq!(buffer.ptr.as_ptr(), ptr_raw);
@@ -218,6 +399,7 @@ mod tests {
             len: size,
             layout,
             ptr: ptr.cast::<f32>(),
+            on_drop: None,
         };
 
         assert_eq!(buffer.as_ptr(), ptr.as_ptr() as *const f32);
@@ -348,4 +530,104 @@ mod tests {
         assert_eq!(buffer.into_vec(), vec![10, 2, 3, 4, 5]);
         Ok(())
     }
+
+    #[test]
+    fn test_tensor_buffer_aligned() {
+        let vec: Vec<u16> = (0..100).collect();
+        let buffer = TensorStorage::from_vec(vec.clone(), AlignedAllocator::<64>);
+        assert_eq!(buffer.as_ptr() as usize % 64, 0);
+        assert_eq!(buffer.as_slice(), vec);
+
+        let cloned = buffer.clone();
+        assert_eq!(cloned.as_ptr() as usize % 64, 0);
+        assert_eq!(cloned.into_vec(), vec);
+
+        let empty = TensorStorage::<u16, _>::from_fn(0, AlignedAllocator::<64>, |i| i as u16);
+        assert!(empty.is_empty());
+        assert_eq!(empty.as_ptr() as usize % 64, 0);
+
+        let empty = TensorStorage::from_vec(Vec::<u16>::new(), AlignedAllocator::<64>);
+        assert_eq!(empty.as_ptr() as usize % 64, 0);
+    }
+
+    #[test]
+    fn test_tensor_buffer_pool() {
+        let pool = PoolAllocator::default();
+
+        let buffer = TensorStorage::from_fn(16, pool.clone(), |i| i as f32);
+        let ptr = buffer.as_ptr();
+        drop(buffer);
+        assert_eq!(pool.num_cached(), 1);
+
+        let buffer = TensorStorage::from_fn(16, pool.clone(), |_| 1.0f32);
+        assert_eq!(buffer.as_ptr(), ptr);
+        assert_eq!(buffer.as_slice(), [1.0; 16]);
+        assert_eq!(pool.num_cached(), 0);
+
+        // the vector takes the buffer out of the pool
+        assert_eq!(buffer.into_vec(), vec![1.0; 16]);
+        assert_eq!(pool.num_cached(), 0);
+    }
+
+    #[test]
+    fn test_tensor_buffer_from_owner() {
+        let vec: Vec<u32> = vec![1, 2, 3, 4];
+        let vec_ptr = vec.as_ptr();
+
+        let mut buffer = TensorStorage::from_owner(vec, CpuAllocator);
+        assert!(std::ptr::eq(buffer.as_ptr(), vec_ptr));
+        assert_eq!(buffer.len(), 16);
+
+        buffer.as_mut_slice()[0] = 10;
+        assert_eq!(buffer.clone().as_slice(), [10, 2, 3, 4]);
+
+        // the vector is copied out of the external memory
+        let out = buffer.into_vec();
+        assert!(!std::ptr::eq(out.as_ptr(), vec_ptr));
+        assert_eq!(out, vec![10, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_tensor_buffer_from_owner_into_vec_drop() {
+        // the owner drops its own elements, the vector must not free them again
+        let owner = vec![String::from("a"), String::from("b")];
+        let buffer = TensorStorage::from_owner(owner, CpuAllocator);
+        assert_eq!(buffer.to_vec(), ["a", "b"]);
+        let buffer = buffer
+            .try_into_vec()
+            .expect_err("the memory is externally owned");
+        assert_eq!(buffer.as_slice(), ["a", "b"]);
+
+        let owner = vec![vec![1u8, 2], vec![3]];
+        let buffer = TensorStorage::from_owner(owner, CpuAllocator);
+        assert_eq!(buffer.to_vec(), [vec![1u8, 2], vec![3]]);
+        assert!(buffer.try_into_vec().is_err());
+
+        // the owned memory is moved out without a clone bound
+        struct NotClone(u8);
+        let buffer = TensorStorage::from_vec(vec![NotClone(1), NotClone(2)], CpuAllocator);
+        let vec = buffer.into_vec();
+        assert_eq!(vec.iter().map(|v| v.0).collect::<Vec<_>>(), [1, 2]);
+    }
+
+    #[test]
+    fn test_tensor_buffer_on_drop() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let mut data = [1u8, 2, 3];
+        let released = Arc::new(AtomicUsize::new(0));
+
+        let buffer = unsafe {
+            let released = released.clone();
+            TensorStorage::from_raw_parts_with_drop(data.as_mut_ptr(), 3, CpuAllocator, move || {
+                released.fetch_add(1, Ordering::SeqCst);
+            })
+        };
+        assert_eq!(buffer.as_slice(), [1, 2, 3]);
+        assert_eq!(released.load(Ordering::SeqCst), 0);
+
+        drop(buffer);
+        assert_eq!(released.load(Ordering::SeqCst), 1);
+    }
 }
@@ -131,11 +131,29 @@ where
     /// This method destroys the tensor and returns ownership of the underlying data.
     /// The returned vector will have a length equal to the total number of elements in the tensor.
     ///
+    /// # Panics
+    ///
+    /// Panics if the memory of the tensor is externally owned, see [`Tensor::try_into_vec`].
     #[inline]
     pub fn into_vec(self) -> Vec<T> {
         self.storage.into_vec()
     }
 
+    /// Consumes the tensor and returns the underlying vector if its memory is not externally
+    /// owned.
+    ///
+    /// # Returns
+    ///
+    /// The vector of the elements, or the tensor itself if its memory is externally owned.
+    pub fn try_into_vec(self) -> Result<Vec<T>, Self> {
+        let (shape, strides) = (self.shape, self.strides);
+        self.storage.try_into_vec().map_err(|storage| Self {
+            storage,
+            shape,
+            strides,
+        })
+    }
+
     /// Creates a new `Tensor` with the given shape and data.
     ///
     /// # Arguments
@@ -198,7 +216,7 @@ where
         if numel != data.len() {
             return Err(TensorError::InvalidShape(numel));
         }
-        let storage = TensorStorage::from_vec(data.to_vec(), alloc);
+        let storage = TensorStorage::from_fn(numel, alloc, |i| data[i].clone());
         let strides = get_strides_from_shape(shape);
         Ok(Self {
             storage,
@@ -237,6 +255,82 @@ where
         })
     }
 
+    /// Creates a new `Tensor` with the given shape from memory owned by `owner`.
+    ///
+    /// The data is not copied and `owner` is dropped together with the tensor, e.g. a memory
+    /// mapped file or a frame buffer handed over by a camera driver.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - An array containing the shape of the tensor.
+    /// * `owner` - The owner of the tensor data.
+    /// * `alloc` - The allocator used when the tensor is cloned.
+    ///
+    /// # Errors
+    ///
+    /// If the number of elements of the owner does not match the shape of the tensor, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data: Box<[u8]> = vec![1, 2, 3, 4].into_boxed_slice();
+    /// let ptr = data.as_ptr();
+    ///
+    /// let t = Tensor::<u8, 2, _>::from_owner([2, 2], data, CpuAllocator).unwrap();
+    /// assert_eq!(t.as_ptr(), ptr);
+    /// assert_eq!(t.as_slice(), [1, 2, 3, 4]);
+    /// ```
+    pub fn from_owner<O>(shape: [usize; N], mut owner: O, alloc: A) -> Result<Self, TensorError>
+    where
+        O: AsMut<[T]> + Send + 'static,
+    {
+        let numel = shape.iter().product::<usize>();
+        if numel != owner.as_mut().len() {
+            return Err(TensorError::InvalidShape(numel));
+        }
+        let storage = TensorStorage::from_owner(owner, alloc);
+        let strides = get_strides_from_shape(shape);
+        Ok(Self {
+            storage,
+            shape,
+            strides,
+        })
+    }
+
+    /// Creates a new `Tensor` with the given shape from external memory and a drop callback.
+    ///
+    /// The data is not copied and `on_drop` is called once the tensor is dropped, e.g. to call
+    /// the deleter of a foreign buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - An array containing the shape of the tensor.
+    /// * `data` - A pointer to the data of the tensor.
+    /// * `alloc` - The allocator used when the tensor is cloned.
+    /// * `on_drop` - The callback releasing the memory.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be non-null, aligned and valid for the number of elements of `shape`
+    /// until `on_drop` is called. If the memory is read-only, the tensor must not be mutated.
+    pub unsafe fn from_raw_parts_with_drop(
+        shape: [usize; N],
+        data: *mut T,
+        alloc: A,
+        on_drop: impl FnOnce() + Send + 'static,
+    ) -> Self {
+        let numel = shape.iter().product::<usize>();
+        let storage = TensorStorage::from_raw_parts_with_drop(data, numel, alloc, on_drop);
+        let strides = get_strides_from_shape(shape);
+        Self {
+            storage,
+            shape,
+            strides,
+        }
+    }
+
     /// Creates a new `Tensor` with the given shape and a default value.
     /// Creates a new `Tensor` with the given shape and a default value.
     ///
@@ -268,8 +362,7 @@ where
         T: Clone,
     {
         let numel = shape.iter().product::<usize>();
-        let data = vec![value; numel];
-        let storage = TensorStorage::from_vec(data, alloc);
+        let storage = TensorStorage::from_fn(numel, alloc, |_| value.clone());
         let strides = get_strides_from_shape(shape);
         Self {
             storage,
@@ -307,18 +400,15 @@ where
         F: Fn([usize; N]) -> T,
     {
         let numel = shape.iter().product::<usize>();
-        let data: Vec<T> = (0..numel)
-            .map(|i| {
-                let mut index = [0; N];
-                let mut j = i;
-                for k in (0..N).rev() {
-                    index[k] = j % shape[k];
-                    j /= shape[k];
-                }
-                f(index)
-            })
-            .collect();
-        let storage = TensorStorage::from_vec(data, alloc);
+        let storage = TensorStorage::from_fn(numel, alloc, |i| {
+            let mut index = [0; N];
+            let mut j = i;
+            for k in (0..N).rev() {
+                index[k] = j % shape[k];
+                j /= shape[k];
+            }
+            f(index)
+        });
         let strides = get_strides_from_shape(shape);
         Self {
             storage,
@@ -510,7 +600,7 @@ where
     pub fn reshape<const M: usize>(
         &self,
         shape: [usize; M],
-    ) -> Result<TensorView<T, M, A>, TensorError> {
+    ) -> Result<TensorView<'_, T, M, A>, TensorError> {
         let numel = shape.iter().product::<usize>();
         if numel != self.storage.len() {
             return Err(TensorError::DimensionMismatch(format!(
@@ -525,6 +615,7 @@ where
             storage: &self.storage,
             shape,
             strides,
+            offset: 0,
         })
     }
 
@@ -540,7 +631,7 @@ where
     /// # Returns
     ///
     /// A view of the tensor with the dimensions permuted.
-    pub fn permute_axes(&self, axes: [usize; N]) -> TensorView<T, N, A> {
+    pub fn permute_axes(&self, axes: [usize; N]) -> TensorView<'_, T, N, A> {
         let mut new_shape = [0; N];
         let mut new_strides = [0; N];
         for (i, &axis) in axes.iter().enumerate() {
@@ -552,6 +643,7 @@ where
             storage: &self.storage,
             shape: new_shape,
             strides: new_strides,
+            offset: 0,
         }
     }
 
@@ -562,14 +654,114 @@ where
     /// # Returns
     ///
     /// A `TensorView` instance.
-    pub fn view(&self) -> TensorView<T, N, A> {
+    pub fn view(&self) -> TensorView<'_, T, N, A> {
         TensorView {
             storage: &self.storage,
             shape: self.shape,
             strides: self.strides,
+            offset: 0,
         }
     }
 
+    /// Slice the tensor along a dimension with a given range and step.
+    ///
+    /// The data is not copied, the returned view shares the storage of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to slice.
+    /// * `range` - The range of indices to keep along the dimension.
+    /// * `step` - The step between consecutive indices. Must be greater than zero.
+    ///
+    /// # Returns
+    ///
+    /// A `TensorView` covering the selected region.
+    ///
+    /// # Errors
+    ///
+    /// If the dimension or the range is out of bounds, or the step is zero, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data: Vec<u8> = (0..16).collect();
+    /// let t = Tensor::<u8, 2, CpuAllocator>::from_shape_vec([4, 4], data, CpuAllocator).unwrap();
+    ///
+    /// // take the 2x2 region starting at row 1, column 1
+    /// let roi = t.slice(0, 1..3, 1).unwrap().slice(1, 1..3, 1).unwrap();
+    /// assert_eq!(roi.shape, [2, 2]);
+    /// assert_eq!(roi.as_contiguous().as_slice(), vec![5, 6, 9, 10]);
+    /// ```
+    pub fn slice(
+        &self,
+        dim: usize,
+        range: impl std::ops::RangeBounds<usize>,
+        step: usize,
+    ) -> Result<TensorView<'_, T, N, A>, TensorError> {
+        self.view().slice(dim, range, step)
+    }
+
+    /// Narrow the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to narrow.
+    /// * `start` - The first index to keep along the dimension.
+    /// * `length` - The number of indices to keep.
+    ///
+    /// # Returns
+    ///
+    /// A `TensorView` covering the selected region.
+    ///
+    /// # Errors
+    ///
+    /// If the dimension or the range is out of bounds, an error is returned.
+    pub fn narrow(
+        &self,
+        dim: usize,
+        start: usize,
+        length: usize,
+    ) -> Result<TensorView<'_, T, N, A>, TensorError> {
+        self.view().narrow(dim, start, length)
+    }
+
+    /// Select a single index along a dimension, removing that dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to select from.
+    /// * `index` - The index to select along the dimension.
+    ///
+    /// # Returns
+    ///
+    /// A `TensorView` with one dimension less.
+    ///
+    /// # Errors
+    ///
+    /// If `M` is not `N - 1`, or the dimension or index is out of bounds, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data: Vec<u8> = (0..12).collect();
+    /// let t = Tensor::<u8, 3, CpuAllocator>::from_shape_vec([2, 3, 2], data, CpuAllocator).unwrap();
+    ///
+    /// let batch = t.select::<2>(0, 1).unwrap();
+    /// assert_eq!(batch.shape, [3, 2]);
+    /// assert_eq!(batch.as_contiguous().as_slice(), vec![6, 7, 8, 9, 10, 11]);
+    /// ```
+    pub fn select<const M: usize>(
+        &self,
+        dim: usize,
+        index: usize,
+    ) -> Result<TensorView<'_, T, M, A>, TensorError> {
+        self.view().select(dim, index)
+    }
+
     /// Create a new tensor with all elements set to zero.
     ///
     /// # Arguments
@@ -611,8 +803,9 @@ where
     where
         F: Fn(&T) -> U,
     {
-        let data: Vec<U> = self.as_slice().iter().map(f).collect();
-        let storage = TensorStorage::from_vec(data, self.storage.alloc().clone());
+        let data = self.as_slice();
+        let storage =
+            TensorStorage::from_fn(data.len(), self.storage.alloc().clone(), |i| f(&data[i]));
 
         Tensor {
             storage,
@@ -987,6 +1180,85 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn slice_2d() -> Result<(), TensorError> {
+        let data: Vec<u8> = (0..12).collect();
+        let t = Tensor::<u8, 2, _>::from_shape_vec([3, 4], data, CpuAllocator)?;
+
+        let view = t.slice(1, 1..3, 1)?;
+        assert_eq!(view.shape, [3, 2]);
+        assert_eq!(view.strides, [4, 1]);
+        assert_eq!(view.offset, 1);
+        assert_eq!(view.numel(), 6);
+        assert!(!view.is_contiguous());
+        assert_eq!(*view.get_unchecked([2, 1]), 10);
+        assert_eq!(view.get([3, 0]), None);
+        assert_eq!(view.as_contiguous().as_slice(), vec![1, 2, 5, 6, 9, 10]);
+
+        let view = t.slice(0, .., 2)?.slice(1, 3.., 1)?;
+        assert_eq!(view.shape, [2, 1]);
+        assert_eq!(view.strides, [8, 1]);
+        assert_eq!(view.as_contiguous().as_slice(), vec![3, 11]);
+
+        let view = t.slice(1, 0..4, 3)?;
+        assert_eq!(view.shape, [3, 2]);
+        assert_eq!(view.as_contiguous().as_slice(), vec![0, 3, 4, 7, 8, 11]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn slice_errors() -> Result<(), TensorError> {
+        let data: Vec<u8> = (0..12).collect();
+        let t = Tensor::<u8, 2, _>::from_shape_vec([3, 4], data, CpuAllocator)?;
+
+        assert!(t.slice(2, 0..1, 1).is_err());
+        assert!(t.slice(1, 0..1, 0).is_err());
+        assert!(t
+            .slice(1, 0..5, 1)
+            .is_err_and(|e| e == TensorError::IndexOutOfBounds(5)));
+        assert!(t.narrow(0, 2, 2).is_err());
+        assert!(t.select::<1>(0, 3).is_err());
+        assert!(t.select::<2>(0, 0).is_err());
+
+        let empty = t.slice(0, 1..1, 1)?;
+        assert_eq!(empty.shape, [0, 4]);
+        assert_eq!(empty.as_contiguous().numel(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn narrow_select_3d() -> Result<(), TensorError> {
+        let data: Vec<u8> = (0..24).collect();
+        let t = Tensor::<u8, 3, _>::from_shape_vec([2, 3, 4], data, CpuAllocator)?;
+
+        let view = t.narrow(1, 1, 2)?;
+        assert_eq!(view.shape, [2, 2, 4]);
+        assert_eq!(
+            view.as_contiguous().as_slice(),
+            vec![4, 5, 6, 7, 8, 9, 10, 11, 16, 17, 18, 19, 20, 21, 22, 23]
+        );
+
+        let batch = t.select::<2>(0, 1)?;
+        assert_eq!(batch.shape, [3, 4]);
+        assert!(batch.is_contiguous());
+        assert_eq!(batch.as_slice()[0], 12);
+
+        let column = batch.select::<1>(1, 2)?;
+        assert_eq!(column.shape, [3]);
+        assert_eq!(column.strides, [4]);
+        assert_eq!(column.as_contiguous().as_slice(), vec![14, 18, 22]);
+
+        // select on a permuted view
+        let permuted = t.permute_axes([2, 0, 1]);
+        let plane = permuted.select::<2>(0, 3)?;
+        assert_eq!(plane.shape, [2, 3]);
+        assert_eq!(plane.as_contiguous().as_slice(), vec![3, 7, 11, 15, 19, 23]);
+
+        Ok(())
+    }
+
     #[test]
     fn zeros_1d() -> Result<(), TensorError> {
         let t = Tensor::<u8, 1, _>::zeros([4], CpuAllocator);
@@ -1334,4 +1606,18 @@ mod tests {
         assert_eq!(t.as_slice(), &[1, 2, 3, 4]);
         Ok(())
     }
+
+    #[test]
+    fn from_owner() -> Result<(), TensorError> {
+        let data: Vec<f32> = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let ptr = data.as_ptr();
+
+        let t = Tensor::<f32, 2, _>::from_owner([2, 3], data, CpuAllocator)?;
+        assert_eq!(t.as_ptr(), ptr);
+        assert_eq!(t.get([1, 2]), Some(&6.0));
+
+        let res = Tensor::<f32, 2, _>::from_owner([2, 2], vec![0.0; 3], CpuAllocator);
+        assert!(res.is_err_and(|e| e == TensorError::InvalidShape(4)));
+        Ok(())
+    }
 }
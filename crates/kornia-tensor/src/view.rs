@@ -1,8 +1,14 @@
+use std::ops::{Bound, RangeBounds};
+
 use crate::{
     get_strides_from_shape, storage::TensorStorage, CpuAllocator, Tensor, TensorAllocator,
+    TensorError,
 };
 
 /// A view into a tensor.
+///
+/// A view borrows the storage of another tensor and describes a (possibly strided and
+/// non-contiguous) region of it through an element offset, a shape and a set of strides.
 pub struct TensorView<'a, T, const N: usize, A: TensorAllocator> {
     /// Reference to the storage held by the another tensor.
     pub storage: &'a TensorStorage<T, A>,
@@ -12,25 +18,44 @@ pub struct TensorView<'a, T, const N: usize, A: TensorAllocator> {
 
     /// The strides of the tensor.
     pub strides: [usize; N],
+
+    /// The offset of the first element of the view in the storage, in number of elements.
+    pub offset: usize,
 }
 
-impl<T, const N: usize, A: TensorAllocator + 'static> TensorView<'_, T, N, A> {
-    /// Returns the data slice of the tensor.
+impl<'a, T, const N: usize, A: TensorAllocator + 'static> TensorView<'a, T, N, A> {
+    /// Returns the data slice of the tensor starting at the view offset.
+    ///
+    /// NOTE: the slice spans until the end of the storage. Use the strides to index it
+    /// or call `as_contiguous` if the view is not contiguous.
     #[inline]
     pub fn as_slice(&self) -> &[T] {
-        self.storage.as_slice()
+        // an empty view may start at the end of the storage
+        self.storage
+            .as_slice()
+            .get(self.offset..)
+            .unwrap_or_default()
     }
 
-    /// Returns the data pointer of the tensor.
+    /// Returns the data pointer of the first element of the view.
     #[inline]
     pub fn as_ptr(&self) -> *const T {
-        self.storage.as_ptr()
+        unsafe {
+            self.storage
+                .as_ptr()
+                .add(self.offset.min(self.storage.len()))
+        }
     }
 
-    /// Returns the length of the tensor.
+    /// Returns the number of elements covered by the view.
     #[inline]
     pub fn numel(&self) -> usize {
-        self.storage.len() / std::mem::size_of::<T>()
+        self.shape.iter().product()
+    }
+
+    /// Returns true if the view elements are laid out contiguously in row-major order.
+    pub fn is_contiguous(&self) -> bool {
+        self.strides == get_strides_from_shape(self.shape)
     }
 
     /// Get the element at the given index.
@@ -46,12 +71,208 @@ impl<T, const N: usize, A: TensorAllocator + 'static> TensorView<'_, T, N, A> {
         let offset = index
             .iter()
             .zip(self.strides.iter())
-            .fold(0, |acc, (i, s)| acc + i * s);
+            .fold(self.offset, |acc, (i, s)| acc + i * s);
         unsafe { self.storage.as_slice().get_unchecked(offset) }
     }
 
+    /// Get the element at the given index, checking if the index is out of bounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The list of indices to get the element from.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the element at the given index or `None` if out of bounds.
+    pub fn get(&self, index: [usize; N]) -> Option<&T> {
+        if index.iter().zip(self.shape.iter()).any(|(&i, &s)| i >= s) {
+            return None;
+        }
+        Some(self.get_unchecked(index))
+    }
+
+    /// Slice the view along a dimension with a given range and step.
+    ///
+    /// The data is not copied, only the offset, shape and strides of the view are updated.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to slice.
+    /// * `range` - The range of indices to keep along the dimension.
+    /// * `step` - The step between consecutive indices. Must be greater than zero.
+    ///
+    /// # Returns
+    ///
+    /// A new `TensorView` covering the selected region.
+    ///
+    /// # Errors
+    ///
+    /// If the dimension or the range is out of bounds, or the step is zero, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data: Vec<u8> = (0..12).collect();
+    /// let t = Tensor::<u8, 2, CpuAllocator>::from_shape_vec([3, 4], data, CpuAllocator).unwrap();
+    ///
+    /// let view = t.view().slice(1, 1..4, 2).unwrap();
+    /// assert_eq!(view.shape, [3, 2]);
+    /// assert_eq!(view.as_contiguous().as_slice(), vec![1, 3, 5, 7, 9, 11]);
+    /// ```
+    pub fn slice(
+        &self,
+        dim: usize,
+        range: impl RangeBounds<usize>,
+        step: usize,
+    ) -> Result<TensorView<'a, T, N, A>, TensorError> {
+        if dim >= N {
+            return Err(TensorError::DimensionMismatch(format!(
+                "Dimension {dim} is out of bounds for a tensor with {N} dimensions"
+            )));
+        }
+
+        if step == 0 {
+            return Err(TensorError::UnsupportedOperation(
+                "The slice step must be greater than zero".to_string(),
+            ));
+        }
+
+        let start = match range.start_bound() {
+            Bound::Included(&s) => s,
+            Bound::Excluded(&s) => s.checked_add(1).ok_or(TensorError::IndexOutOfBounds(s))?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&e) => e.checked_add(1).ok_or(TensorError::IndexOutOfBounds(e))?,
+            Bound::Excluded(&e) => e,
+            Bound::Unbounded => self.shape[dim],
+        };
+
+        if end > self.shape[dim] {
+            return Err(TensorError::IndexOutOfBounds(end));
+        }
+        if start > end {
+            return Err(TensorError::IndexOutOfBounds(start));
+        }
+
+        let mut shape = self.shape;
+        let mut strides = self.strides;
+        shape[dim] = (end - start).div_ceil(step);
+        strides[dim] = self.strides[dim] * step;
+
+        // an empty view keeps the offset, which may be at the end of the storage already
+        let offset = match shape.contains(&0) {
+            true => self.offset,
+            false => self.offset + start * self.strides[dim],
+        };
+
+        Ok(TensorView {
+            storage: self.storage,
+            shape,
+            strides,
+            offset,
+        })
+    }
+
+    /// Narrow the view along a dimension.
+    ///
+    /// Equivalent to `slice(dim, start..start + length, 1)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to narrow.
+    /// * `start` - The first index to keep along the dimension.
+    /// * `length` - The number of indices to keep.
+    ///
+    /// # Returns
+    ///
+    /// A new `TensorView` covering the selected region.
+    ///
+    /// # Errors
+    ///
+    /// If the dimension or the range is out of bounds, an error is returned.
+    pub fn narrow(
+        &self,
+        dim: usize,
+        start: usize,
+        length: usize,
+    ) -> Result<TensorView<'a, T, N, A>, TensorError> {
+        let end = start
+            .checked_add(length)
+            .ok_or(TensorError::IndexOutOfBounds(start))?;
+        self.slice(dim, start..end, 1)
+    }
+
+    /// Select a single index along a dimension, removing that dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to select from.
+    /// * `index` - The index to select along the dimension.
+    ///
+    /// # Returns
+    ///
+    /// A new `TensorView` with one dimension less.
+    ///
+    /// # Errors
+    ///
+    /// If `M` is not `N - 1`, or the dimension or index is out of bounds, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data: Vec<u8> = (0..12).collect();
+    /// let t = Tensor::<u8, 3, CpuAllocator>::from_shape_vec([2, 3, 2], data, CpuAllocator).unwrap();
+    ///
+    /// let channel = t.view().select::<2>(2, 1).unwrap();
+    /// assert_eq!(channel.shape, [2, 3]);
+    /// assert_eq!(channel.as_contiguous().as_slice(), vec![1, 3, 5, 7, 9, 11]);
+    /// ```
+    pub fn select<const M: usize>(
+        &self,
+        dim: usize,
+        index: usize,
+    ) -> Result<TensorView<'a, T, M, A>, TensorError> {
+        if M + 1 != N {
+            return Err(TensorError::DimensionMismatch(format!(
+                "Cannot select from a tensor with {N} dimensions into a view with {M} dimensions"
+            )));
+        }
+
+        if dim >= N {
+            return Err(TensorError::DimensionMismatch(format!(
+                "Dimension {dim} is out of bounds for a tensor with {N} dimensions"
+            )));
+        }
+
+        if index >= self.shape[dim] {
+            return Err(TensorError::IndexOutOfBounds(index));
+        }
+
+        let mut shape = [0; M];
+        let mut strides = [0; M];
+        for (i, j) in (0..N).filter(|&j| j != dim).enumerate() {
+            shape[i] = self.shape[j];
+            strides[i] = self.strides[j];
+        }
+
+        Ok(TensorView {
+            storage: self.storage,
+            shape,
+            strides,
+            offset: self.offset + index * self.strides[dim],
+        })
+    }
+
     /// Convert the view an owned tensor with contiguous memory.
     ///
+    /// Only the elements covered by the view are copied. Rows along the last dimension
+    /// with unit stride are copied as whole slices.
+    ///
     /// # Returns
     ///
     /// A new `Tensor` instance with contiguous memory.
@@ -59,23 +280,49 @@ impl<T, const N: usize, A: TensorAllocator + 'static> TensorView<'_, T, N, A> {
     where
         T: Clone,
     {
-        let mut data = Vec::<T>::with_capacity(self.numel());
-        let mut index = [0; N];
-
-        loop {
-            let val = self.get_unchecked(index);
-            data.push(val.clone());
-
-            // Increment index
-            let mut i = N - 1;
-            while i > 0 && index[i] == self.shape[i] - 1 {
-                index[i] = 0;
-                i -= 1;
-            }
-            if i == 0 && index[0] == self.shape[0] - 1 {
-                break;
+        let numel = self.numel();
+        let mut data = Vec::<T>::with_capacity(numel);
+        let src = self.storage.as_slice();
+
+        match N.checked_sub(1) {
+            _ if numel == 0 => {}
+            None => data.push(src[self.offset].clone()),
+            Some(last) => {
+                let (row_len, row_stride) = (self.shape[last], self.strides[last]);
+                let mut index = [0; N];
+
+                loop {
+                    // offset of the first element of the current row
+                    let row_offset = index[..last]
+                        .iter()
+                        .zip(self.strides[..last].iter())
+                        .fold(self.offset, |acc, (i, s)| acc + i * s);
+
+                    if row_stride == 1 {
+                        data.extend_from_slice(&src[row_offset..row_offset + row_len]);
+                    } else {
+                        data.extend((0..row_len).map(|i| src[row_offset + i * row_stride].clone()));
+                    }
+
+                    // increment the index over the outer dimensions
+                    let mut i = last;
+                    loop {
+                        if i == 0 {
+                            break;
+                        }
+                        i -= 1;
+                        index[i] += 1;
+                        if index[i] < self.shape[i] {
+                            break;
+                        }
+                        index[i] = 0;
+                    }
+
+                    if index[..last].iter().all(|&i| i == 0) {
+                        break;
+                    }
+                }
             }
-            index[i] += 1;
         }
 
         let strides = get_strides_from_shape(self.shape);
@@ -102,6 +349,7 @@ mod tests {
             storage: &storage,
             shape: [8],
             strides: [1],
+            offset: 0,
         };
 
         assert_eq!(view.numel(), 8);
@@ -131,4 +379,21 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_tensor_view_slice_empty() -> Result<(), TensorError> {
+        let data: Vec<u8> = (0..12).collect();
+        let t = Tensor::<u8, 2, CpuAllocator>::from_shape_vec([3, 4], data, CpuAllocator)?;
+
+        // chaining empty slices does not move the offset past the storage
+        let view = t.view().slice(0, 3..3, 1)?.slice(1, 3..4, 1)?;
+        assert_eq!(view.shape, [0, 1]);
+        assert!(view.offset <= t.storage.len());
+        assert!(view.as_contiguous().as_slice().is_empty());
+
+        assert!(t.view().narrow(1, usize::MAX, 2).is_err());
+        assert!(t.view().slice(1, ..=usize::MAX, 1).is_err());
+
+        Ok(())
+    }
 }
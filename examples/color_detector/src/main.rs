@@ -31,6 +31,14 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut mask = Image::<u8, 1>::from_size_val(hsv.size(), 0)?;
     imgproc::threshold::in_range(&hsv, &mut mask, &[40.0, 110.0, 50.0], &[90.0, 255.0, 255.0])?;
 
+    // extract the blobs of the mask
+    let mut labels = Image::<u32, 1>::from_size_val(mask.size(), 0)?;
+    let blobs = imgproc::connected_components::connected_components(
+        &mask,
+        &mut labels,
+        imgproc::connected_components::Connectivity::Eight,
+    )?;
+
     // apply the mask to the image
     let mut out = Image::<u8, 3>::from_size_val(mask.size(), 0)?;
     imgproc::core::bitwise_and(&rgb, &rgb, &mut out, &mask)?;
@@ -58,5 +66,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         &rerun::Image::from_elements(out.as_slice(), out.size().into(), rerun::ColorModel::RGB),
     )?;
 
+    rec.log(
+        "output/blobs",
+        &rerun::Boxes2D::from_mins_and_sizes(
+            blobs.iter().map(|b| (b.x as f32, b.y as f32)),
+            blobs.iter().map(|b| (b.width as f32, b.height as f32)),
+        ),
+    )?;
+
     Ok(())
 }
@@ -45,8 +45,13 @@ impl<'cl> CuTask<'cl> for Sobel {
         let mut img_sobel = Image::from_size_val(img.size(), 0.0f32)
             .map_err(|e| CuError::new_with_cause("Failed to create image", e))?;
 
-        imgproc::filter::sobel(&img, &mut img_sobel, 3)
-            .map_err(|e| CuError::new_with_cause("Failed to apply sobel", e))?;
+        imgproc::filter::sobel(
+            &img,
+            &mut img_sobel,
+            3,
+            imgproc::border::BorderMode::Reflect101,
+        )
+        .map_err(|e| CuError::new_with_cause("Failed to apply sobel", e))?;
 
         let dst = img_sobel
             .map(|&x| x as u8)
@@ -16,7 +16,12 @@ fn main() -> eyre::Result<()> {
 
                     // compute the sobel edge map
                     let mut out = Image::from_size_val(img.size(), 0f32)?;
-                    imgproc::filter::sobel(&img.cast()?, &mut out, 3)?;
+                    imgproc::filter::sobel(
+                        &img.cast()?,
+                        &mut out,
+                        3,
+                        imgproc::border::BorderMode::Reflect101,
+                    )?;
 
                     // TODO: make this more efficient in kornia-image crate
                     let out_u8 = {
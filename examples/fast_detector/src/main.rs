@@ -50,7 +50,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // log the keypoints
     let points = keypoints
         .iter()
-        .map(|k| (k[0] as f32, k[1] as f32))
+        .map(|k| (k.position[0], k.position[1]))
         .collect::<Vec<_>>();
 
     rec.log_static(
@@ -2,6 +2,7 @@ use argh::FromArgs;
 use std::path::PathBuf;
 
 use kornia::io::functional as F;
+use kornia::tensor::{CpuAllocator, Tensor};
 use kornia::tensor_ops::TensorOps;
 use kornia::{
     image::{ops, Image},
@@ -39,7 +40,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("PSNR error: {:?}", psnr);
 
     // or, alternatively, compute the mse using the built-in functions
-    let mse_map = image_f32.sub(&image_dirty)?.powi(2);
+    let diff: Tensor<f32, 3, CpuAllocator> = image_f32.sub(&image_dirty)?;
+    let mse_map = diff.powi(2);
     // let mse_ii = mse_map_ii.mean();
 
     // create a Rerun recording stream
@@ -71,6 +71,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         &map_x,
         &map_y,
         imgproc::interpolation::InterpolationMode::Bilinear,
+        imgproc::border::BorderMode::Constant(0.0),
     )?;
 
     // create a Rerun recording stream
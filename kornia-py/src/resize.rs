@@ -17,6 +17,8 @@ pub fn resize(image: PyImage, new_size: (usize, usize), interpolation: &str) ->
     let interpolation = match interpolation.to_lowercase().as_str() {
         "nearest" => InterpolationMode::Nearest,
         "bilinear" => InterpolationMode::Bilinear,
+        "bicubic" => InterpolationMode::Bicubic,
+        "lanczos" => InterpolationMode::Lanczos3,
         _ => {
             return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
                 "Invalid interpolation mode",
@@ -2,15 +2,32 @@ use pyo3::prelude::*;
 
 use crate::image::{FromPyImage, PyImage, ToPyImage};
 use kornia_image::{Image, ImageSize};
+use kornia_imgproc::border::BorderMode;
 use kornia_imgproc::interpolation::InterpolationMode;
 use kornia_imgproc::warp;
 
+/// Parse the border mode of the warps, filling with zeros for the constant mode.
+fn parse_border_mode(border: &str) -> PyResult<BorderMode> {
+    match border.to_lowercase().as_str() {
+        "constant" => Ok(BorderMode::Constant(0.0)),
+        "replicate" => Ok(BorderMode::Replicate),
+        "reflect" => Ok(BorderMode::Reflect),
+        "reflect101" => Ok(BorderMode::Reflect101),
+        "wrap" => Ok(BorderMode::Wrap),
+        _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "Invalid border mode",
+        )),
+    }
+}
+
 #[pyfunction]
+#[pyo3(signature = (image, m, new_size, interpolation, border = "constant"))]
 pub fn warp_affine(
     image: PyImage,
     m: [f32; 6],
     new_size: (usize, usize),
     interpolation: &str,
+    border: &str,
 ) -> PyResult<PyImage> {
     // have to add annotation Image<u8, 3>, otherwise the compiler will complain
     // NOTE: do we support images with channels != 3?
@@ -25,6 +42,8 @@ pub fn warp_affine(
     let interpolation = match interpolation.to_lowercase().as_str() {
         "nearest" => InterpolationMode::Nearest,
         "bilinear" => InterpolationMode::Bilinear,
+        "bicubic" => InterpolationMode::Bicubic,
+        "lanczos" => InterpolationMode::Lanczos3,
         _ => {
             return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
                 "Invalid interpolation mode",
@@ -32,6 +51,8 @@ pub fn warp_affine(
         }
     };
 
+    let border = parse_border_mode(border)?;
+
     // we need to cast to f32 for now since kornia-rs interpolation function only works with f32
     let image = image
         .cast::<f32>()
@@ -40,23 +61,25 @@ pub fn warp_affine(
     let mut image_warped = Image::from_size_val(new_size, 0f32)
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?;
 
-    warp::warp_affine(&image, &mut image_warped, &m, interpolation)
+    warp::warp_affine(&image, &mut image_warped, &m, interpolation, border)
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?;
 
-    // NOTE: for bicubic interpolation (not implemented yet), f32 may overshoot 255
+    // bicubic and lanczos interpolation may overshoot the range of u8
     let image_warped = image_warped
-        .cast::<u8>()
+        .map(|&v| v.clamp(0.0, 255.0) as u8)
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?;
 
     Ok(image_warped.to_pyimage())
 }
 
 #[pyfunction]
+#[pyo3(signature = (image, m, new_size, interpolation, border = "constant"))]
 pub fn warp_perspective(
     image: PyImage,
     m: [f32; 9],
     new_size: (usize, usize),
     interpolation: &str,
+    border: &str,
 ) -> PyResult<PyImage> {
     let image: Image<u8, 3> = Image::from_pyimage(image)
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?;
@@ -69,6 +92,8 @@ pub fn warp_perspective(
     let interpolation = match interpolation.to_lowercase().as_str() {
         "nearest" => InterpolationMode::Nearest,
         "bilinear" => InterpolationMode::Bilinear,
+        "bicubic" => InterpolationMode::Bicubic,
+        "lanczos" => InterpolationMode::Lanczos3,
         _ => {
             return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
                 "Invalid interpolation mode",
@@ -76,6 +101,8 @@ pub fn warp_perspective(
         }
     };
 
+    let border = parse_border_mode(border)?;
+
     let image = image
         .cast::<f32>()
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?;
@@ -83,11 +110,12 @@ pub fn warp_perspective(
     let mut image_warped = Image::from_size_val(new_size, 0f32)
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?;
 
-    warp::warp_perspective(&image, &mut image_warped, &m, interpolation)
+    warp::warp_perspective(&image, &mut image_warped, &m, interpolation, border)
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?;
 
+    // bicubic and lanczos interpolation may overshoot the range of u8
     let image_warped = image_warped
-        .cast::<u8>()
+        .map(|&v| v.clamp(0.0, 255.0) as u8)
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?;
 
     Ok(image_warped.to_pyimage())
@@ -16,5 +16,6 @@ def test_resize():
     # check the image properties
     assert img.shape == (195, 258, 3)
 
-    img_resized: np.ndarray = K.resize(img, (43, 34), "bilinear")
-    assert img_resized.shape == (43, 34, 3)
+    for interpolation in ("nearest", "bilinear", "bicubic", "lanczos"):
+        img_resized: np.ndarray = K.resize(img, (43, 34), interpolation)
+        assert img_resized.shape == (43, 34, 3)
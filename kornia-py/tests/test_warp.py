@@ -35,3 +35,41 @@ def test_warp_perspective():
         img, perspective_matrix, img.shape[:2], "bilinear"
     )
     assert (img_transformed == img).all()
+
+
+def test_warp_affine_bicubic():
+    img_path: Path = DATA_DIR / "dog.jpeg"
+    img: np.ndarray = K.read_image_jpeg(str(img_path.absolute()))
+
+    # a downscale with a sharp kernel overshoots before the clamp to u8
+    affine_matrix = (0.5, 0.0, 0.0, 0.0, 0.5, 0.0)
+
+    for interpolation in ("bicubic", "lanczos"):
+        img_transformed: np.ndarray = K.warp_affine(
+            img, affine_matrix, (97, 129), interpolation
+        )
+        assert img_transformed.shape == (97, 129, 3)
+        assert img_transformed.dtype == np.uint8
+
+
+def test_warp_affine_border():
+    img_path: Path = DATA_DIR / "dog.jpeg"
+    img: np.ndarray = K.read_image_jpeg(str(img_path.absolute()))
+
+    # a translation samples outside the image on one side
+    affine_matrix = (1.0, 0.0, 10.0, 0.0, 1.0, 0.0)
+
+    img_constant: np.ndarray = K.warp_affine(
+        img, affine_matrix, img.shape[:2], "bilinear"
+    )
+    img_replicate: np.ndarray = K.warp_affine(
+        img, affine_matrix, img.shape[:2], "bilinear", "replicate"
+    )
+    assert img_replicate.shape == img_constant.shape
+    assert (img_replicate != img_constant).any()
+
+    try:
+        K.warp_affine(img, affine_matrix, img.shape[:2], "bilinear", "mirror")
+        assert False, "expected an invalid border mode error"
+    except ValueError:
+        pass